@@ -1,7 +1,11 @@
 use std::time::Duration;
 use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+use std::fs;
 use std::num::NonZeroU16;
 use std::str::FromStr;
+use regex::Regex;
+use tokio_rustls::rustls::pki_types::ServerName;
 
 #[cfg(test)]
 #[path = "./configuration_test.rs"]
@@ -47,6 +51,17 @@ impl Default for Port {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub(crate) struct SocketPath(String);
+
+impl From<SocketPath> for String {
+
+    fn from(value: SocketPath) -> Self {
+        value.0
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[repr(transparent)]
 pub(crate) struct Path(String);
@@ -99,11 +114,557 @@ impl Default for StatusCode {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub(crate) struct Username(String);
+
+impl From<Username> for String {
+
+    fn from(value: Username) -> Self {
+        value.0
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub(crate) struct Password(String);
+
+impl From<Password> for String {
+
+    fn from(value: Password) -> Self {
+        value.0
+    }
+}
+
+impl Debug for Password {
+
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Password(***)")
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub(crate) struct Token(String);
+
+impl From<Token> for String {
+
+    fn from(value: Token) -> Self {
+        value.0
+    }
+}
+
+impl Debug for Token {
+
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Token(***)")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub(crate) struct TlsServerName(String);
+
+impl From<TlsServerName> for String {
+
+    fn from(value: TlsServerName) -> Self {
+        value.0
+    }
+}
+
+impl Default for TlsServerName {
+
+    fn default() -> Self {
+        TlsServerName(String::from("localhost"))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub(crate) struct Database(String);
+
+impl From<Database> for String {
+
+    fn from(value: Database) -> Self {
+        value.0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub(crate) struct RedisCommand(Vec<String>);
+
+impl RedisCommand {
+
+    pub(crate) fn name(&self) -> &str {
+        &self.0[0]
+    }
+
+    pub(crate) fn args(&self) -> &[String] {
+        &self.0[1..]
+    }
+}
+
+impl Default for RedisCommand {
+
+    fn default() -> Self {
+        RedisCommand(vec![String::from("PING")])
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum RedisReply {
+    String(String),
+    Integer(i64),
+    Regex(Regex),
+}
+
+impl PartialEq for RedisReply {
+
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (RedisReply::String(a), RedisReply::String(b)) => a == b,
+            (RedisReply::Integer(a), RedisReply::Integer(b)) => a == b,
+            (RedisReply::Regex(a), RedisReply::Regex(b)) => a.as_str() == b.as_str(),
+            _ => false,
+        }
+    }
+}
+
+impl Default for RedisReply {
+
+    fn default() -> Self {
+        RedisReply::String(String::from("PONG"))
+    }
+}
+
+impl FromStr for RedisReply {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(value) = s.strip_prefix("string:") {
+            Ok(RedisReply::String(value.to_string()))
+        } else if let Some(value) = s.strip_prefix("integer:") {
+            value.trim().parse::<i64>()
+                .map(RedisReply::Integer)
+                .map_err(|_| ())
+        } else if let Some(value) = s.strip_prefix("regex:") {
+            Regex::new(value)
+                .map(RedisReply::Regex)
+                .map_err(|_| ())
+        } else {
+            Ok(RedisReply::String(s.to_string()))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub(crate) enum RedisCheck {
+    #[default]
+    Command,
+    RoundTrip,
+    PubSub,
+}
+
+impl FromStr for RedisCheck {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "command" => Ok(RedisCheck::Command),
+            "round-trip" => Ok(RedisCheck::RoundTrip),
+            "pub-sub" => Ok(RedisCheck::PubSub),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub(crate) struct RedisKeyPrefix(String);
+
+impl From<RedisKeyPrefix> for String {
+
+    fn from(value: RedisKeyPrefix) -> Self {
+        value.0
+    }
+}
+
+impl Default for RedisKeyPrefix {
+
+    fn default() -> Self {
+        RedisKeyPrefix(String::from("dockteur:"))
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub(crate) struct RedisDatabase(u32);
+
+impl From<RedisDatabase> for u32 {
+
+    fn from(value: RedisDatabase) -> Self {
+        value.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub(crate) enum RedisProtocol {
+    #[default]
+    Resp2,
+    Resp3,
+}
+
+impl FromStr for RedisProtocol {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "resp2" => Ok(RedisProtocol::Resp2),
+            "resp3" => Ok(RedisProtocol::Resp3),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub(crate) struct RedisMasterName(String);
+
+impl From<RedisMasterName> for String {
+
+    fn from(value: RedisMasterName) -> Self {
+        value.0
+    }
+}
+
+impl Default for RedisMasterName {
+
+    fn default() -> Self {
+        RedisMasterName(String::from("mymaster"))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub(crate) struct SqlQuery(String);
+
+impl From<SqlQuery> for String {
+
+    fn from(value: SqlQuery) -> Self {
+        value.0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum SqlExpectation {
+    Equal(String),
+    NotEqual(String),
+    LessThan(f64),
+    LessOrEqual(f64),
+    GreaterThan(f64),
+    GreaterOrEqual(f64),
+}
+
+impl FromStr for SqlExpectation {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let number = |value: &str| value.trim().parse::<f64>()
+            .ok()
+            .filter(|number| number.is_finite())
+            .ok_or(());
+
+        if let Some(value) = s.strip_prefix(">=") {
+            number(value).map(SqlExpectation::GreaterOrEqual)
+        } else if let Some(value) = s.strip_prefix("<=") {
+            number(value).map(SqlExpectation::LessOrEqual)
+        } else if let Some(value) = s.strip_prefix("!=") {
+            Ok(SqlExpectation::NotEqual(value.trim().to_string()))
+        } else if let Some(value) = s.strip_prefix('>') {
+            number(value).map(SqlExpectation::GreaterThan)
+        } else if let Some(value) = s.strip_prefix('<') {
+            number(value).map(SqlExpectation::LessThan)
+        } else if let Some(value) = s.strip_prefix('=') {
+            Ok(SqlExpectation::Equal(value.trim().to_string()))
+        } else {
+            Ok(SqlExpectation::Equal(s.to_string()))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub(crate) enum MemcachedCheck {
+    #[default]
+    Version,
+    RoundTrip,
+}
+
+impl FromStr for MemcachedCheck {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "version" => Ok(MemcachedCheck::Version),
+            "round-trip" => Ok(MemcachedCheck::RoundTrip),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub(crate) struct MemcachedKeyPrefix(String);
+
+impl From<MemcachedKeyPrefix> for String {
+
+    fn from(value: MemcachedKeyPrefix) -> Self {
+        value.0
+    }
+}
+
+impl Default for MemcachedKeyPrefix {
+
+    fn default() -> Self {
+        MemcachedKeyPrefix(String::from("dockteur:"))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub(crate) struct AmqpVhost(String);
+
+impl From<AmqpVhost> for String {
+
+    fn from(value: AmqpVhost) -> Self {
+        value.0
+    }
+}
+
+impl Default for AmqpVhost {
+
+    fn default() -> Self {
+        AmqpVhost(String::from("/"))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub(crate) struct KafkaBrokerId(i32);
+
+impl From<KafkaBrokerId> for i32 {
+
+    fn from(value: KafkaBrokerId) -> Self {
+        value.0
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub(crate) struct KafkaTopics(Vec<String>);
+
+impl KafkaTopics {
+
+    pub(crate) fn names(&self) -> &[String] {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub(crate) enum MqttVersion {
+    #[default]
+    V311,
+    V5,
+}
+
+impl FromStr for MqttVersion {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "3.1.1" => Ok(MqttVersion::V311),
+            "5" | "5.0" => Ok(MqttVersion::V5),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub(crate) struct GrpcService(String);
+
+impl From<GrpcService> for String {
+
+    fn from(value: GrpcService) -> Self {
+        value.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub(crate) enum ElasticsearchStatus {
+    Green,
+    #[default]
+    Yellow,
+}
+
+impl FromStr for ElasticsearchStatus {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "green" => Ok(ElasticsearchStatus::Green),
+            "yellow" => Ok(ElasticsearchStatus::Yellow),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub(crate) struct DnsName(String);
+
+impl From<DnsName> for String {
+
+    fn from(value: DnsName) -> Self {
+        value.0
+    }
+}
+
+impl Default for DnsName {
+
+    fn default() -> Self {
+        DnsName(String::from("."))
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub(crate) enum DnsRecordType {
+    A,
+    Aaaa,
+    Cname,
+    Mx,
+    #[default]
+    Ns,
+    Ptr,
+    Soa,
+    Srv,
+    Txt,
+}
+
+impl FromStr for DnsRecordType {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "a" => Ok(DnsRecordType::A),
+            "aaaa" => Ok(DnsRecordType::Aaaa),
+            "cname" => Ok(DnsRecordType::Cname),
+            "mx" => Ok(DnsRecordType::Mx),
+            "ns" => Ok(DnsRecordType::Ns),
+            "ptr" => Ok(DnsRecordType::Ptr),
+            "soa" => Ok(DnsRecordType::Soa),
+            "srv" => Ok(DnsRecordType::Srv),
+            "txt" => Ok(DnsRecordType::Txt),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub(crate) enum DnsTransport {
+    #[default]
+    Udp,
+    Tcp,
+}
+
+impl FromStr for DnsTransport {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "udp" => Ok(DnsTransport::Udp),
+            "tcp" => Ok(DnsTransport::Tcp),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub(crate) struct DnsExpected(String);
+
+impl From<DnsExpected> for String {
+
+    fn from(value: DnsExpected) -> Self {
+        value.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub(crate) enum ClickhouseInterface {
+    #[default]
+    Http,
+    Native,
+}
+
+impl FromStr for ClickhouseInterface {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "http" => Ok(ClickhouseInterface::Http),
+            "native" => Ok(ClickhouseInterface::Native),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub(crate) enum MongodbState {
+    #[default]
+    Any,
+    WritablePrimary,
+    Secondary,
+}
+
+impl FromStr for MongodbState {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "any" => Ok(MongodbState::Any),
+            "writable-primary" => Ok(MongodbState::WritablePrimary),
+            "secondary" => Ok(MongodbState::Secondary),
+            _ => Err(()),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub(crate) enum Protocol {
     #[default]
     Http,
     Redis,
+    RedisSentinel,
+    RedisCluster,
+    Postgres,
+    Mysql,
+    Mongodb,
+    Memcached,
+    Amqp,
+    Kafka,
+    Nats,
+    Mqtt,
+    Grpc,
+    Elasticsearch,
+    Smtp,
+    Imap,
+    Pop3,
+    Ftp,
+    Dns,
+    Ldap,
+    Cql,
+    Clickhouse,
 }
 
 impl FromStr for Protocol {
@@ -113,6 +674,26 @@ impl FromStr for Protocol {
         match s.to_lowercase().as_str() {
             "http" => Ok(Protocol::Http),
             "redis" => Ok(Protocol::Redis),
+            "redis-sentinel" => Ok(Protocol::RedisSentinel),
+            "redis-cluster" => Ok(Protocol::RedisCluster),
+            "postgres" => Ok(Protocol::Postgres),
+            "mysql" => Ok(Protocol::Mysql),
+            "mongodb" => Ok(Protocol::Mongodb),
+            "memcached" => Ok(Protocol::Memcached),
+            "amqp" => Ok(Protocol::Amqp),
+            "kafka" => Ok(Protocol::Kafka),
+            "nats" => Ok(Protocol::Nats),
+            "mqtt" => Ok(Protocol::Mqtt),
+            "grpc" => Ok(Protocol::Grpc),
+            "elasticsearch" | "opensearch" => Ok(Protocol::Elasticsearch),
+            "smtp" => Ok(Protocol::Smtp),
+            "imap" => Ok(Protocol::Imap),
+            "pop3" => Ok(Protocol::Pop3),
+            "ftp" => Ok(Protocol::Ftp),
+            "dns" => Ok(Protocol::Dns),
+            "ldap" => Ok(Protocol::Ldap),
+            "cql" => Ok(Protocol::Cql),
+            "clickhouse" => Ok(Protocol::Clickhouse),
             _ => Err(()),
         }
     }
@@ -123,20 +704,109 @@ pub(crate) struct Configuration {
     pub(crate) protocol: Protocol,
     pub(crate) method: Method,
     pub(crate) port: Port,
+    pub(crate) socket_path: Option<SocketPath>,
     pub(crate) path: Path,
     pub(crate) timeout: Timeout,
+    pub(crate) username: Option<Username>,
+    pub(crate) password: Option<Password>,
+    pub(crate) token: Option<Token>,
+    pub(crate) tls: bool,
+    pub(crate) tls_server_name: TlsServerName,
+    pub(crate) tls_skip_verify: bool,
+    pub(crate) database: Option<Database>,
+    pub(crate) sql_query: Option<SqlQuery>,
+    pub(crate) sql_expectation: Option<SqlExpectation>,
     pub(crate) status_code: StatusCode,
+    pub(crate) redis_command: RedisCommand,
+    pub(crate) redis_reply: RedisReply,
+    pub(crate) redis_check: RedisCheck,
+    pub(crate) redis_key_prefix: RedisKeyPrefix,
+    pub(crate) redis_database: RedisDatabase,
+    pub(crate) redis_protocol: RedisProtocol,
+    pub(crate) redis_master_name: RedisMasterName,
+    pub(crate) redis_cluster_check_slots: bool,
+    pub(crate) redis_cluster_check_node: bool,
+    pub(crate) mongodb_state: MongodbState,
+    pub(crate) memcached_check: MemcachedCheck,
+    pub(crate) memcached_key_prefix: MemcachedKeyPrefix,
+    pub(crate) amqp_vhost: AmqpVhost,
+    pub(crate) kafka_check_metadata: bool,
+    pub(crate) kafka_broker_id: Option<KafkaBrokerId>,
+    pub(crate) kafka_topics: KafkaTopics,
+    pub(crate) mqtt_version: MqttVersion,
+    pub(crate) mqtt_check_delivery: bool,
+    pub(crate) grpc_service: GrpcService,
+    pub(crate) elasticsearch_minimum_status: ElasticsearchStatus,
+    pub(crate) elasticsearch_wait_for_status: Option<ElasticsearchStatus>,
+    pub(crate) smtp_starttls: bool,
+    pub(crate) ftp_login: bool,
+    pub(crate) dns_name: DnsName,
+    pub(crate) dns_record_type: DnsRecordType,
+    pub(crate) dns_transport: DnsTransport,
+    pub(crate) dns_expected: Option<DnsExpected>,
+    pub(crate) cql_query: bool,
+    pub(crate) clickhouse_interface: ClickhouseInterface,
+    pub(crate) clickhouse_query: bool,
 }
 
 #[derive(Debug, PartialEq)]
 pub(crate) enum InvalidConfiguration {
     Protocol(String),
     Port(String),
+    PortWithSocketPath(String),
+    SocketPath(String),
+    UnsupportedSocketPath(String),
     Timeout(String),
+    UsernameFile(String),
+    PasswordFile(String),
+    TokenFile(String),
+    Tls(String),
+    TlsServerName(String),
+    TlsSkipVerify(String),
     StatusCode(String),
     Method(String),
+    RedisReply(String),
+    RedisCheck(String),
+    RedisDatabase(String),
+    UnsupportedRedisDatabase(String),
+    RedisProtocol(String),
+    RedisClusterCheckSlots(String),
+    RedisClusterCheckNode(String),
+    UnsupportedSqlQuery(String),
+    SqlExpectation(String),
+    SqlExpectationWithoutQuery(String),
+    MongodbState(String),
+    MemcachedCheck(String),
+    MemcachedKeyPrefix(String),
+    AmqpVhost(String),
+    KafkaCheckMetadata(String),
+    KafkaBrokerId(String),
+    KafkaMetadataWithoutBrokerId(String),
+    KafkaTopics(String),
+    MqttVersion(String),
+    MqttCheckDelivery(String),
+    ElasticsearchMinimumStatus(String),
+    ElasticsearchWaitForStatus(String),
+    SmtpStarttls(String),
+    FtpLogin(String),
+    DnsName(String),
+    DnsRecordType(String),
+    DnsTransport(String),
+    LdapBindWithoutPassword(String),
+    CqlQuery(String),
+    ClickhouseInterface(String),
+    ClickhouseQuery(String),
 }
 
+// memcached keys are limited to 250 bytes, leave room for the unique suffix
+const MEMCACHED_KEY_PREFIX_MAX_LENGTH: usize = 200;
+
+const KAFKA_TOPIC_MAX_LENGTH: usize = 249;
+
+const DNS_NAME_MAX_LENGTH: usize = 253;
+
+const DNS_LABEL_MAX_LENGTH: usize = 63;
+
 #[macro_export]
 macro_rules! env {
     ( $x:expr ) => {
@@ -149,6 +819,20 @@ pub fn sanitize(value: &str) -> Option<String> {
         .filter(|s| !s.is_empty())
 }
 
+fn load_flag_from(vars: &HashMap<String, String>, name: &str, invalid: fn(String) -> InvalidConfiguration) -> Result<bool, InvalidConfiguration> {
+    match vars.get(env!(name)) {
+        None => Ok(false),
+        Some(value) => match sanitize(value) {
+            None => Ok(false),
+            Some(value) => match value.to_lowercase().as_str() {
+                "true" => Ok(true),
+                "false" => Ok(false),
+                _ => Err(invalid(value)),
+            },
+        },
+    }
+}
+
 fn load_protocol_from(vars: &HashMap<String, String>) -> Result<Protocol, InvalidConfiguration> {
     match vars.get(env!("PROTOCOL")) {
         None => Ok(Protocol::default()),
@@ -172,22 +856,70 @@ fn load_method_from(vars: &HashMap<String, String>) -> Result<Method, InvalidCon
     }
 }
 
-fn default_port_for(protocol: &Protocol) -> Port {
+fn default_port_for(protocol: &Protocol, tls: bool, clickhouse_interface: ClickhouseInterface) -> Port {
     let value = match protocol {
         Protocol::Http => 80,
         Protocol::Redis => 6379,
+        Protocol::RedisSentinel => 26379,
+        Protocol::RedisCluster => 6379,
+        Protocol::Postgres => 5432,
+        Protocol::Mysql => 3306,
+        Protocol::Mongodb => 27017,
+        Protocol::Memcached => 11211,
+        Protocol::Amqp => 5672,
+        Protocol::Kafka => 9092,
+        Protocol::Nats => 4222,
+        Protocol::Mqtt => 1883,
+        Protocol::Grpc => 50051,
+        Protocol::Elasticsearch => 9200,
+        Protocol::Smtp if tls => 465,
+        Protocol::Smtp => 25,
+        Protocol::Imap if tls => 993,
+        Protocol::Imap => 143,
+        Protocol::Pop3 if tls => 995,
+        Protocol::Pop3 => 110,
+        Protocol::Ftp => 21,
+        Protocol::Dns => 53,
+        Protocol::Ldap if tls => 636,
+        Protocol::Ldap => 389,
+        Protocol::Cql => 9042,
+        Protocol::Clickhouse => match clickhouse_interface {
+            ClickhouseInterface::Http => 8123,
+            ClickhouseInterface::Native => 9000,
+        },
     };
     Port(NonZeroU16::new(value).unwrap())
 }
 
-fn load_port_from(vars: &HashMap<String, String>, protocol: &Protocol) -> Result<Port, InvalidConfiguration> {
+fn load_socket_path_from(vars: &HashMap<String, String>, protocol: &Protocol) -> Result<Option<SocketPath>, InvalidConfiguration> {
+    match vars.get(env!("SOCKET_PATH")) {
+        None => Ok(None),
+        Some(value) => match sanitize(value) {
+            None => Ok(None),
+            Some(value) if !value.starts_with('/') => Err(InvalidConfiguration::SocketPath(value)),
+            Some(value) => match protocol {
+                Protocol::Redis | Protocol::RedisCluster => Ok(Some(SocketPath(value))),
+                _ => Err(InvalidConfiguration::UnsupportedSocketPath(value)),
+            },
+        },
+    }
+}
+
+fn load_port_from(vars: &HashMap<String, String>, protocol: &Protocol, tls: bool, clickhouse_interface: ClickhouseInterface, socket_path: Option<&SocketPath>) -> Result<Port, InvalidConfiguration> {
+    if socket_path.is_some() {
+        return match vars.get(env!("PORT")).and_then(|value| sanitize(value)) {
+            None => Ok(default_port_for(protocol, tls, clickhouse_interface)),
+            Some(value) => Err(InvalidConfiguration::PortWithSocketPath(value)),
+        };
+    }
+
     let env_var = vars.get(env!("PORT"))
         .or(vars.get("PORT"));
 
     match env_var {
-        None => Ok(default_port_for(protocol)),
+        None => Ok(default_port_for(protocol, tls, clickhouse_interface)),
         Some(value) => match sanitize(value) {
-            None => Ok(default_port_for(protocol)),
+            None => Ok(default_port_for(protocol, tls, clickhouse_interface)),
             Some(value) => match value.parse::<u16>() {
                 Ok(number) => match NonZeroU16::new(number) {
                     None => Err(InvalidConfiguration::Port(value.clone())),
@@ -222,6 +954,116 @@ fn load_timeout_from(vars: &HashMap<String, String>) -> Result<Timeout, InvalidC
     }
 }
 
+fn read_secret(path: &str) -> std::io::Result<String> {
+    fs::read_to_string(path)
+        .map(|content| content.trim_end_matches(['\r', '\n']).to_string())
+}
+
+fn load_username_file_from(vars: &HashMap<String, String>) -> Result<Option<Username>, InvalidConfiguration> {
+    match vars.get(env!("USERNAME_FILE")) {
+        None => Ok(None),
+        Some(value) => match sanitize(value) {
+            None => Ok(None),
+            Some(path) => read_secret(&path)
+                .map(|secret| Some(Username(secret)))
+                .map_err(|_| InvalidConfiguration::UsernameFile(path)),
+        },
+    }
+}
+
+fn load_username_from(vars: &HashMap<String, String>) -> Result<Option<Username>, InvalidConfiguration> {
+    match vars.get(env!("USERNAME")) {
+        None => load_username_file_from(vars),
+        Some(value) => match sanitize(value) {
+            None => load_username_file_from(vars),
+            Some(value) => Ok(Some(Username(value))),
+        },
+    }
+}
+
+fn load_password_from(vars: &HashMap<String, String>, protocol: &Protocol, username: Option<&Username>) -> Result<Option<Password>, InvalidConfiguration> {
+    let password = match vars.get(env!("PASSWORD_FILE")) {
+        None => None,
+        Some(value) => match sanitize(value) {
+            None => None,
+            Some(path) => read_secret(&path)
+                .map(|secret| Some(Password(secret)))
+                .map_err(|_| InvalidConfiguration::PasswordFile(path))?,
+        },
+    };
+
+    // servers take a simple bind with a DN and an empty password for an anonymous one, hiding wrong credentials
+    if let (Protocol::Ldap, Some(username)) = (protocol, username) {
+        if password.as_ref().is_none_or(|password| password.0.is_empty()) {
+            return Err(InvalidConfiguration::LdapBindWithoutPassword(username.0.clone()));
+        }
+    }
+
+    Ok(password)
+}
+
+fn load_token_from(vars: &HashMap<String, String>) -> Result<Option<Token>, InvalidConfiguration> {
+    match vars.get(env!("TOKEN_FILE")) {
+        None => Ok(None),
+        Some(value) => match sanitize(value) {
+            None => Ok(None),
+            Some(path) => read_secret(&path)
+                .map(|secret| Some(Token(secret)))
+                .map_err(|_| InvalidConfiguration::TokenFile(path)),
+        },
+    }
+}
+
+fn load_tls_server_name_from(vars: &HashMap<String, String>) -> Result<TlsServerName, InvalidConfiguration> {
+    match vars.get(env!("TLS_SERVER_NAME")) {
+        None => Ok(TlsServerName::default()),
+        Some(value) => match sanitize(value) {
+            None => Ok(TlsServerName::default()),
+            Some(value) => match ServerName::try_from(value.as_str()) {
+                Ok(_) => Ok(TlsServerName(value)),
+                Err(_) => Err(InvalidConfiguration::TlsServerName(value)),
+            },
+        },
+    }
+}
+
+fn load_database_from(vars: &HashMap<String, String>) -> Result<Option<Database>, InvalidConfiguration> {
+    match vars.get(env!("DATABASE")) {
+        None => Ok(None),
+        Some(value) => match sanitize(value) {
+            None => Ok(None),
+            Some(value) => Ok(Some(Database(value))),
+        },
+    }
+}
+
+fn load_sql_query_from(vars: &HashMap<String, String>, protocol: &Protocol) -> Result<Option<SqlQuery>, InvalidConfiguration> {
+    match vars.get(env!("SQL_QUERY")) {
+        None => Ok(None),
+        Some(value) => match sanitize(value) {
+            None => Ok(None),
+            Some(value) => match protocol {
+                Protocol::Postgres | Protocol::Mysql => Ok(Some(SqlQuery(value))),
+                _ => Err(InvalidConfiguration::UnsupportedSqlQuery(value)),
+            },
+        },
+    }
+}
+
+fn load_sql_expectation_from(vars: &HashMap<String, String>, sql_query: Option<&SqlQuery>) -> Result<Option<SqlExpectation>, InvalidConfiguration> {
+    match vars.get(env!("SQL_EXPECTED")) {
+        None => Ok(None),
+        Some(value) => match sanitize(value) {
+            None => Ok(None),
+            Some(value) => match SqlExpectation::from_str(&value) {
+                Err(_) => Err(InvalidConfiguration::SqlExpectation(value)),
+                Ok(_) if sql_query.is_none() => Err(InvalidConfiguration::SqlExpectationWithoutQuery(value)),
+                Ok(expectation) => Ok(Some(expectation)),
+            },
+        },
+    }
+}
+
 fn load_status_code_from(vars: &HashMap<String, String>) -> Result<StatusCode, InvalidConfiguration> {
     match vars.get(env!("STATUS_CODE")) {
         None => Ok(StatusCode::default()),
@@ -238,12 +1080,386 @@ fn load_status_code_from(vars: &HashMap<String, String>) -> Result<StatusCode, I
     }
 }
 
+fn load_redis_command_from(vars: &HashMap<String, String>) -> Result<RedisCommand, InvalidConfiguration> {
+    match vars.get(env!("REDIS_COMMAND")) {
+        None => Ok(RedisCommand::default()),
+        Some(value) => match sanitize(value) {
+            None => Ok(RedisCommand::default()),
+            Some(value) => Ok(RedisCommand(value.split_whitespace().map(String::from).collect())),
+        },
+    }
+}
+
+fn load_redis_reply_from(vars: &HashMap<String, String>) -> Result<RedisReply, InvalidConfiguration> {
+    match vars.get(env!("REDIS_REPLY")) {
+        None => Ok(RedisReply::default()),
+        Some(value) => match sanitize(value) {
+            None => Ok(RedisReply::default()),
+            Some(value) => RedisReply::from_str(&value)
+                .map_err(|_| InvalidConfiguration::RedisReply(value)),
+        },
+    }
+}
+
+fn load_redis_check_from(vars: &HashMap<String, String>) -> Result<RedisCheck, InvalidConfiguration> {
+    match vars.get(env!("REDIS_CHECK")) {
+        None => Ok(RedisCheck::default()),
+        Some(value) => match sanitize(value) {
+            None => Ok(RedisCheck::default()),
+            Some(value) => RedisCheck::from_str(&value)
+                .map_err(|_| InvalidConfiguration::RedisCheck(value)),
+        },
+    }
+}
+
+fn load_redis_key_prefix_from(vars: &HashMap<String, String>) -> Result<RedisKeyPrefix, InvalidConfiguration> {
+    match vars.get(env!("REDIS_KEY_PREFIX")) {
+        None => Ok(RedisKeyPrefix::default()),
+        Some(value) => match sanitize(value) {
+            None => Ok(RedisKeyPrefix::default()),
+            Some(value) => Ok(RedisKeyPrefix(value)),
+        },
+    }
+}
+
+fn parse_redis_database(value: String) -> Result<RedisDatabase, InvalidConfiguration> {
+    value.parse::<u32>()
+        .map(RedisDatabase)
+        .map_err(|_| InvalidConfiguration::RedisDatabase(value))
+}
+
+fn load_redis_database_from(vars: &HashMap<String, String>, protocol: &Protocol, path: &Path) -> Result<RedisDatabase, InvalidConfiguration> {
+    let database_from_path = || match protocol {
+        Protocol::Redis => match path.0.trim_matches('/') {
+            "" => Ok(RedisDatabase::default()),
+            value => parse_redis_database(value.to_string()),
+        },
+        _ => Ok(RedisDatabase::default()),
+    };
+
+    match vars.get(env!("REDIS_DATABASE")) {
+        None => database_from_path(),
+        Some(value) => match sanitize(value) {
+            None => database_from_path(),
+            Some(value) => match protocol {
+                Protocol::RedisSentinel | Protocol::RedisCluster => Err(InvalidConfiguration::UnsupportedRedisDatabase(value)),
+                _ => parse_redis_database(value),
+            },
+        },
+    }
+}
+
+fn load_redis_protocol_from(vars: &HashMap<String, String>) -> Result<RedisProtocol, InvalidConfiguration> {
+    match vars.get(env!("REDIS_PROTOCOL")) {
+        None => Ok(RedisProtocol::default()),
+        Some(value) => match sanitize(value) {
+            None => Ok(RedisProtocol::default()),
+            Some(value) => RedisProtocol::from_str(&value)
+                .map_err(|_| InvalidConfiguration::RedisProtocol(value)),
+        },
+    }
+}
+
+fn load_redis_master_name_from(vars: &HashMap<String, String>) -> Result<RedisMasterName, InvalidConfiguration> {
+    match vars.get(env!("REDIS_MASTER_NAME")) {
+        None => Ok(RedisMasterName::default()),
+        Some(value) => match sanitize(value) {
+            None => Ok(RedisMasterName::default()),
+            Some(value) => Ok(RedisMasterName(value)),
+        },
+    }
+}
+
+fn load_mongodb_state_from(vars: &HashMap<String, String>) -> Result<MongodbState, InvalidConfiguration> {
+    match vars.get(env!("MONGODB_STATE")) {
+        None => Ok(MongodbState::default()),
+        Some(value) => match sanitize(value) {
+            None => Ok(MongodbState::default()),
+            Some(value) => MongodbState::from_str(&value)
+                .map_err(|_| InvalidConfiguration::MongodbState(value)),
+        },
+    }
+}
+
+fn load_memcached_check_from(vars: &HashMap<String, String>) -> Result<MemcachedCheck, InvalidConfiguration> {
+    match vars.get(env!("MEMCACHED_CHECK")) {
+        None => Ok(MemcachedCheck::default()),
+        Some(value) => match sanitize(value) {
+            None => Ok(MemcachedCheck::default()),
+            Some(value) => MemcachedCheck::from_str(&value)
+                .map_err(|_| InvalidConfiguration::MemcachedCheck(value)),
+        },
+    }
+}
+
+fn load_memcached_key_prefix_from(vars: &HashMap<String, String>) -> Result<MemcachedKeyPrefix, InvalidConfiguration> {
+    match vars.get(env!("MEMCACHED_KEY_PREFIX")) {
+        None => Ok(MemcachedKeyPrefix::default()),
+        Some(value) => match sanitize(value) {
+            None => Ok(MemcachedKeyPrefix::default()),
+            Some(value) if value.len() > MEMCACHED_KEY_PREFIX_MAX_LENGTH || value.chars().any(|c| c.is_whitespace() || c.is_control()) => {
+                Err(InvalidConfiguration::MemcachedKeyPrefix(value))
+            }
+            Some(value) => Ok(MemcachedKeyPrefix(value)),
+        },
+    }
+}
+
+fn load_amqp_vhost_from(vars: &HashMap<String, String>) -> Result<AmqpVhost, InvalidConfiguration> {
+    match vars.get(env!("AMQP_VHOST")) {
+        None => Ok(AmqpVhost::default()),
+        Some(value) => match sanitize(value) {
+            None => Ok(AmqpVhost::default()),
+            Some(value) if value.len() > u8::MAX as usize => Err(InvalidConfiguration::AmqpVhost(value)),
+            Some(value) => Ok(AmqpVhost(value)),
+        },
+    }
+}
+
+fn load_kafka_broker_id_from(vars: &HashMap<String, String>, check_metadata: bool) -> Result<Option<KafkaBrokerId>, InvalidConfiguration> {
+    let broker_id = match vars.get(env!("KAFKA_BROKER_ID")).and_then(|value| sanitize(value)) {
+        None => None,
+        Some(value) => match value.parse::<i32>().ok().filter(|id| *id >= 0) {
+            Some(id) => Some(KafkaBrokerId(id)),
+            None => return Err(InvalidConfiguration::KafkaBrokerId(value)),
+        },
+    };
+
+    // the brokers of a cluster usually advertise the same port, only the id tells the local one apart
+    if check_metadata && broker_id.is_none() {
+        let value = vars.get(env!("KAFKA_CHECK_METADATA")).and_then(|value| sanitize(value)).unwrap_or_default();
+        return Err(InvalidConfiguration::KafkaMetadataWithoutBrokerId(value));
+    }
+
+    Ok(broker_id)
+}
+
+fn is_valid_kafka_topic(name: &str) -> bool {
+    name.len() <= KAFKA_TOPIC_MAX_LENGTH
+        && name != "."
+        && name != ".."
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '_' || c == '-')
+}
+
+fn load_kafka_topics_from(vars: &HashMap<String, String>) -> Result<KafkaTopics, InvalidConfiguration> {
+    match vars.get(env!("KAFKA_TOPICS")) {
+        None => Ok(KafkaTopics::default()),
+        Some(value) => match sanitize(value) {
+            None => Ok(KafkaTopics::default()),
+            Some(value) => {
+                let topics: Vec<String> = value.split(',')
+                    .map(|topic| topic.trim().to_string())
+                    .collect();
+
+                if topics.iter().all(|topic| !topic.is_empty() && is_valid_kafka_topic(topic)) {
+                    Ok(KafkaTopics(topics))
+                } else {
+                    Err(InvalidConfiguration::KafkaTopics(value))
+                }
+            }
+        },
+    }
+}
+
+fn load_mqtt_version_from(vars: &HashMap<String, String>) -> Result<MqttVersion, InvalidConfiguration> {
+    match vars.get(env!("MQTT_VERSION")) {
+        None => Ok(MqttVersion::default()),
+        Some(value) => match sanitize(value) {
+            None => Ok(MqttVersion::default()),
+            Some(value) => MqttVersion::from_str(&value)
+                .map_err(|_| InvalidConfiguration::MqttVersion(value)),
+        },
+    }
+}
+
+fn load_grpc_service_from(vars: &HashMap<String, String>) -> Result<GrpcService, InvalidConfiguration> {
+    match vars.get(env!("GRPC_SERVICE")) {
+        None => Ok(GrpcService::default()),
+        Some(value) => match sanitize(value) {
+            None => Ok(GrpcService::default()),
+            Some(value) => Ok(GrpcService(value)),
+        },
+    }
+}
+
+fn load_elasticsearch_minimum_status_from(vars: &HashMap<String, String>) -> Result<ElasticsearchStatus, InvalidConfiguration> {
+    match vars.get(env!("ELASTICSEARCH_MINIMUM_STATUS")) {
+        None => Ok(ElasticsearchStatus::default()),
+        Some(value) => match sanitize(value) {
+            None => Ok(ElasticsearchStatus::default()),
+            Some(value) => ElasticsearchStatus::from_str(&value)
+                .map_err(|_| InvalidConfiguration::ElasticsearchMinimumStatus(value)),
+        },
+    }
+}
+
+fn load_elasticsearch_wait_for_status_from(vars: &HashMap<String, String>) -> Result<Option<ElasticsearchStatus>, InvalidConfiguration> {
+    match vars.get(env!("ELASTICSEARCH_WAIT_FOR_STATUS")) {
+        None => Ok(None),
+        Some(value) => match sanitize(value) {
+            None => Ok(None),
+            Some(value) => ElasticsearchStatus::from_str(&value)
+                .map(Some)
+                .map_err(|_| InvalidConfiguration::ElasticsearchWaitForStatus(value)),
+        },
+    }
+}
+
+fn is_valid_dns_name(name: &str) -> bool {
+    let name = name.strip_suffix('.').unwrap_or(name);
+
+    name.is_empty() || (name.len() <= DNS_NAME_MAX_LENGTH
+        && name.split('.').all(|label| !label.is_empty() && label.len() <= DNS_LABEL_MAX_LENGTH)
+        && name.chars().all(|c| c.is_ascii_graphic()))
+}
+
+fn load_dns_name_from(vars: &HashMap<String, String>) -> Result<DnsName, InvalidConfiguration> {
+    match vars.get(env!("DNS_NAME")) {
+        None => Ok(DnsName::default()),
+        Some(value) => match sanitize(value) {
+            None => Ok(DnsName::default()),
+            Some(value) if is_valid_dns_name(&value) => Ok(DnsName(value)),
+            Some(value) => Err(InvalidConfiguration::DnsName(value)),
+        },
+    }
+}
+
+fn load_dns_record_type_from(vars: &HashMap<String, String>) -> Result<DnsRecordType, InvalidConfiguration> {
+    match vars.get(env!("DNS_TYPE")) {
+        None => Ok(DnsRecordType::default()),
+        Some(value) => match sanitize(value) {
+            None => Ok(DnsRecordType::default()),
+            Some(value) => DnsRecordType::from_str(&value)
+                .map_err(|_| InvalidConfiguration::DnsRecordType(value)),
+        },
+    }
+}
+
+fn load_dns_transport_from(vars: &HashMap<String, String>) -> Result<DnsTransport, InvalidConfiguration> {
+    match vars.get(env!("DNS_TRANSPORT")) {
+        None => Ok(DnsTransport::default()),
+        Some(value) => match sanitize(value) {
+            None => Ok(DnsTransport::default()),
+            Some(value) => DnsTransport::from_str(&value)
+                .map_err(|_| InvalidConfiguration::DnsTransport(value)),
+        },
+    }
+}
+
+fn load_dns_expected_from(vars: &HashMap<String, String>) -> Result<Option<DnsExpected>, InvalidConfiguration> {
+    match vars.get(env!("DNS_EXPECTED")) {
+        None => Ok(None),
+        Some(value) => match sanitize(value) {
+            None => Ok(None),
+            Some(value) => Ok(Some(DnsExpected(value))),
+        },
+    }
+}
+
+fn load_clickhouse_interface_from(vars: &HashMap<String, String>) -> Result<ClickhouseInterface, InvalidConfiguration> {
+    match vars.get(env!("CLICKHOUSE_INTERFACE")) {
+        None => Ok(ClickhouseInterface::default()),
+        Some(value) => match sanitize(value) {
+            None => Ok(ClickhouseInterface::default()),
+            Some(value) => ClickhouseInterface::from_str(&value)
+                .map_err(|_| InvalidConfiguration::ClickhouseInterface(value)),
+        },
+    }
+}
+
 pub(crate) fn load_configuration_from(vars: HashMap<String, String>) -> Result<Configuration, InvalidConfiguration> {
     let protocol = load_protocol_from(&vars)?;
     let method = load_method_from(&vars)?;
-    let port = load_port_from(&vars, &protocol)?;
+    let socket_path = load_socket_path_from(&vars, &protocol)?;
+    let tls = load_flag_from(&vars, "TLS", InvalidConfiguration::Tls)?;
+    let clickhouse_interface = load_clickhouse_interface_from(&vars)?;
+    let port = load_port_from(&vars, &protocol, tls, clickhouse_interface, socket_path.as_ref())?;
     let path = load_path_from(&vars)?;
     let timeout = load_timeout_from(&vars)?;
+    let username = load_username_from(&vars)?;
+    let password = load_password_from(&vars, &protocol, username.as_ref())?;
+    let token = load_token_from(&vars)?;
+    let tls_server_name = load_tls_server_name_from(&vars)?;
+    let tls_skip_verify = load_flag_from(&vars, "TLS_SKIP_VERIFY", InvalidConfiguration::TlsSkipVerify)?;
+    let database = load_database_from(&vars)?;
+    let sql_query = load_sql_query_from(&vars, &protocol)?;
+    let sql_expectation = load_sql_expectation_from(&vars, sql_query.as_ref())?;
     let status_code = load_status_code_from(&vars)?;
-    Ok(Configuration { protocol, method, port, path, timeout, status_code })
+    let redis_command = load_redis_command_from(&vars)?;
+    let redis_reply = load_redis_reply_from(&vars)?;
+    let redis_check = load_redis_check_from(&vars)?;
+    let redis_key_prefix = load_redis_key_prefix_from(&vars)?;
+    let redis_database = load_redis_database_from(&vars, &protocol, &path)?;
+    let redis_protocol = load_redis_protocol_from(&vars)?;
+    let redis_master_name = load_redis_master_name_from(&vars)?;
+    let redis_cluster_check_slots = load_flag_from(&vars, "REDIS_CLUSTER_CHECK_SLOTS", InvalidConfiguration::RedisClusterCheckSlots)?;
+    let redis_cluster_check_node = load_flag_from(&vars, "REDIS_CLUSTER_CHECK_NODE", InvalidConfiguration::RedisClusterCheckNode)?;
+    let mongodb_state = load_mongodb_state_from(&vars)?;
+    let memcached_check = load_memcached_check_from(&vars)?;
+    let memcached_key_prefix = load_memcached_key_prefix_from(&vars)?;
+    let amqp_vhost = load_amqp_vhost_from(&vars)?;
+    let kafka_check_metadata = load_flag_from(&vars, "KAFKA_CHECK_METADATA", InvalidConfiguration::KafkaCheckMetadata)?;
+    let kafka_broker_id = load_kafka_broker_id_from(&vars, kafka_check_metadata)?;
+    let kafka_topics = load_kafka_topics_from(&vars)?;
+    let mqtt_version = load_mqtt_version_from(&vars)?;
+    let mqtt_check_delivery = load_flag_from(&vars, "MQTT_CHECK_DELIVERY", InvalidConfiguration::MqttCheckDelivery)?;
+    let grpc_service = load_grpc_service_from(&vars)?;
+    let elasticsearch_minimum_status = load_elasticsearch_minimum_status_from(&vars)?;
+    let elasticsearch_wait_for_status = load_elasticsearch_wait_for_status_from(&vars)?;
+    let smtp_starttls = load_flag_from(&vars, "SMTP_STARTTLS", InvalidConfiguration::SmtpStarttls)?;
+    let ftp_login = load_flag_from(&vars, "FTP_LOGIN", InvalidConfiguration::FtpLogin)?;
+    let dns_name = load_dns_name_from(&vars)?;
+    let dns_record_type = load_dns_record_type_from(&vars)?;
+    let dns_transport = load_dns_transport_from(&vars)?;
+    let dns_expected = load_dns_expected_from(&vars)?;
+    let cql_query = load_flag_from(&vars, "CQL_QUERY", InvalidConfiguration::CqlQuery)?;
+    let clickhouse_query = load_flag_from(&vars, "CLICKHOUSE_QUERY", InvalidConfiguration::ClickhouseQuery)?;
+    Ok(Configuration {
+        protocol,
+        method,
+        port,
+        socket_path,
+        path,
+        timeout,
+        username,
+        password,
+        token,
+        tls,
+        tls_server_name,
+        tls_skip_verify,
+        database,
+        sql_query,
+        sql_expectation,
+        status_code,
+        redis_command,
+        redis_reply,
+        redis_check,
+        redis_key_prefix,
+        redis_database,
+        redis_protocol,
+        redis_master_name,
+        redis_cluster_check_slots,
+        redis_cluster_check_node,
+        mongodb_state,
+        memcached_check,
+        memcached_key_prefix,
+        amqp_vhost,
+        kafka_check_metadata,
+        kafka_broker_id,
+        kafka_topics,
+        mqtt_version,
+        mqtt_check_delivery,
+        grpc_service,
+        elasticsearch_minimum_status,
+        elasticsearch_wait_for_status,
+        smtp_starttls,
+        ftp_login,
+        dns_name,
+        dns_record_type,
+        dns_transport,
+        dns_expected,
+        cql_query,
+        clickhouse_interface,
+        clickhouse_query,
+    })
 }
@@ -1,4 +1,5 @@
-use crate::configuration::{Configuration, Port, Protocol, StatusCode, Timeout};
+use crate::configuration::{AmqpVhost, ClickhouseInterface, Configuration, Database, DnsExpected, DnsName, DnsRecordType, DnsTransport, ElasticsearchStatus, GrpcService, KafkaBrokerId, KafkaTopics, MemcachedCheck, MongodbState, MqttVersion, Password, Port, Protocol, RedisCheck, RedisCommand, RedisDatabase, RedisMasterName, RedisProtocol, RedisReply, SocketPath, SqlExpectation, SqlQuery, StatusCode, Timeout, Username};
+use std::str::FromStr;
 use std::num::NonZeroU16;
 use std::time::Duration;
 use crate::u16nz;
@@ -42,3 +43,355 @@ pub(crate) fn a_redis_configuration_with_timeout(port: u16, timeout: u64) -> Con
         ..Default::default()
     }
 }
+
+pub(crate) fn a_redis_configuration_with_command(port: u16, command: &str, reply: &str) -> Configuration {
+    Configuration {
+        protocol: Protocol::Redis,
+        port: Port(u16nz!(port)),
+        redis_command: RedisCommand(command.split_whitespace().map(String::from).collect()),
+        redis_reply: RedisReply::from_str(reply).unwrap(),
+        ..Default::default()
+    }
+}
+
+pub(crate) fn a_redis_configuration_with_check(port: u16, check: RedisCheck) -> Configuration {
+    Configuration {
+        protocol: Protocol::Redis,
+        port: Port(u16nz!(port)),
+        redis_check: check,
+        ..Default::default()
+    }
+}
+
+pub(crate) fn a_redis_configuration_with_database(port: u16, database: u32, command: &str, reply: &str) -> Configuration {
+    Configuration {
+        redis_database: RedisDatabase(database),
+        ..a_redis_configuration_with_command(port, command, reply)
+    }
+}
+
+pub(crate) fn a_redis_configuration_with_protocol(port: u16, protocol: RedisProtocol) -> Configuration {
+    Configuration {
+        protocol: Protocol::Redis,
+        port: Port(u16nz!(port)),
+        redis_protocol: protocol,
+        ..Default::default()
+    }
+}
+
+pub(crate) fn a_redis_configuration_with_socket_path(socket_path: &str) -> Configuration {
+    Configuration {
+        protocol: Protocol::Redis,
+        socket_path: Some(SocketPath(socket_path.to_string())),
+        ..Default::default()
+    }
+}
+
+pub(crate) fn a_redis_sentinel_configuration(port: u16) -> Configuration {
+    Configuration {
+        protocol: Protocol::RedisSentinel,
+        port: Port(u16nz!(port)),
+        ..Default::default()
+    }
+}
+
+pub(crate) fn a_redis_sentinel_configuration_with_master_name(port: u16, master_name: &str) -> Configuration {
+    Configuration {
+        redis_master_name: RedisMasterName(master_name.to_string()),
+        ..a_redis_sentinel_configuration(port)
+    }
+}
+
+pub(crate) fn a_redis_cluster_configuration(port: u16) -> Configuration {
+    Configuration {
+        protocol: Protocol::RedisCluster,
+        port: Port(u16nz!(port)),
+        ..Default::default()
+    }
+}
+
+pub(crate) fn a_redis_cluster_configuration_with_checks(port: u16, check_slots: bool, check_node: bool) -> Configuration {
+    Configuration {
+        redis_cluster_check_slots: check_slots,
+        redis_cluster_check_node: check_node,
+        ..a_redis_cluster_configuration(port)
+    }
+}
+
+pub(crate) fn a_postgres_configuration(port: u16, username: &str, password: &str, database: &str) -> Configuration {
+    Configuration {
+        protocol: Protocol::Postgres,
+        port: Port(u16nz!(port)),
+        username: Some(Username(username.to_string())),
+        password: Some(Password(password.to_string())),
+        database: Some(Database(database.to_string())),
+        ..Default::default()
+    }
+}
+
+pub(crate) fn a_postgres_configuration_with_query(port: u16, query: &str, expectation: &str) -> Configuration {
+    Configuration {
+        sql_query: Some(SqlQuery(query.to_string())),
+        sql_expectation: Some(SqlExpectation::from_str(expectation).unwrap()),
+        ..a_postgres_configuration(port, "postgres", "postgres", "postgres")
+    }
+}
+
+pub(crate) fn a_mysql_configuration(port: u16, username: &str, password: Option<&str>, database: Option<&str>) -> Configuration {
+    Configuration {
+        protocol: Protocol::Mysql,
+        port: Port(u16nz!(port)),
+        username: Some(Username(username.to_string())),
+        password: password.map(|password| Password(password.to_string())),
+        database: database.map(|database| Database(database.to_string())),
+        ..Default::default()
+    }
+}
+
+pub(crate) fn a_mysql_configuration_with_query(port: u16, query: &str, expectation: &str) -> Configuration {
+    Configuration {
+        sql_query: Some(SqlQuery(query.to_string())),
+        sql_expectation: Some(SqlExpectation::from_str(expectation).unwrap()),
+        ..a_mysql_configuration(port, "root", None, Some("test"))
+    }
+}
+
+pub(crate) fn a_mongodb_configuration(port: u16, state: MongodbState) -> Configuration {
+    Configuration {
+        protocol: Protocol::Mongodb,
+        port: Port(u16nz!(port)),
+        mongodb_state: state,
+        ..Default::default()
+    }
+}
+
+pub(crate) fn a_mongodb_configuration_with_credentials(port: u16, username: &str, password: &str) -> Configuration {
+    Configuration {
+        username: Some(Username(username.to_string())),
+        password: Some(Password(password.to_string())),
+        ..a_mongodb_configuration(port, MongodbState::Any)
+    }
+}
+
+pub(crate) fn a_memcached_configuration(port: u16, check: MemcachedCheck) -> Configuration {
+    Configuration {
+        protocol: Protocol::Memcached,
+        port: Port(u16nz!(port)),
+        memcached_check: check,
+        ..Default::default()
+    }
+}
+
+pub(crate) fn an_amqp_configuration(port: u16, vhost: &str) -> Configuration {
+    Configuration {
+        protocol: Protocol::Amqp,
+        port: Port(u16nz!(port)),
+        amqp_vhost: AmqpVhost(vhost.to_string()),
+        ..Default::default()
+    }
+}
+
+pub(crate) fn an_amqp_configuration_with_credentials(port: u16, username: &str, password: &str) -> Configuration {
+    Configuration {
+        username: Some(Username(username.to_string())),
+        password: Some(Password(password.to_string())),
+        ..an_amqp_configuration(port, "/")
+    }
+}
+
+pub(crate) fn a_kafka_configuration(port: u16) -> Configuration {
+    Configuration {
+        protocol: Protocol::Kafka,
+        port: Port(u16nz!(port)),
+        ..Default::default()
+    }
+}
+
+pub(crate) fn a_kafka_configuration_with_metadata(port: u16, broker_id: Option<i32>, topics: &[&str]) -> Configuration {
+    Configuration {
+        kafka_check_metadata: true,
+        kafka_broker_id: broker_id.map(KafkaBrokerId),
+        kafka_topics: KafkaTopics(topics.iter().map(|topic| topic.to_string()).collect()),
+        ..a_kafka_configuration(port)
+    }
+}
+
+pub(crate) fn a_nats_configuration(port: u16) -> Configuration {
+    Configuration {
+        protocol: Protocol::Nats,
+        port: Port(u16nz!(port)),
+        ..Default::default()
+    }
+}
+
+pub(crate) fn a_nats_configuration_with_credentials(port: u16, username: &str, password: &str) -> Configuration {
+    Configuration {
+        username: Some(Username(username.to_string())),
+        password: Some(Password(password.to_string())),
+        ..a_nats_configuration(port)
+    }
+}
+
+pub(crate) fn a_mqtt_configuration(port: u16, version: MqttVersion, check_delivery: bool) -> Configuration {
+    Configuration {
+        protocol: Protocol::Mqtt,
+        port: Port(u16nz!(port)),
+        mqtt_version: version,
+        mqtt_check_delivery: check_delivery,
+        ..Default::default()
+    }
+}
+
+pub(crate) fn a_grpc_configuration(port: u16, service: &str) -> Configuration {
+    Configuration {
+        protocol: Protocol::Grpc,
+        port: Port(u16nz!(port)),
+        grpc_service: GrpcService(service.to_string()),
+        ..Default::default()
+    }
+}
+
+pub(crate) fn an_elasticsearch_configuration(port: u16, minimum_status: ElasticsearchStatus) -> Configuration {
+    Configuration {
+        protocol: Protocol::Elasticsearch,
+        port: Port(u16nz!(port)),
+        elasticsearch_minimum_status: minimum_status,
+        ..Default::default()
+    }
+}
+
+pub(crate) fn an_elasticsearch_configuration_with_credentials(port: u16, username: &str, password: &str) -> Configuration {
+    Configuration {
+        username: Some(Username(username.to_string())),
+        password: Some(Password(password.to_string())),
+        ..an_elasticsearch_configuration(port, ElasticsearchStatus::Yellow)
+    }
+}
+
+pub(crate) fn an_smtp_configuration(port: u16, starttls: bool) -> Configuration {
+    Configuration {
+        protocol: Protocol::Smtp,
+        port: Port(u16nz!(port)),
+        smtp_starttls: starttls,
+        ..Default::default()
+    }
+}
+
+pub(crate) fn an_imap_configuration(port: u16) -> Configuration {
+    Configuration {
+        protocol: Protocol::Imap,
+        port: Port(u16nz!(port)),
+        ..Default::default()
+    }
+}
+
+pub(crate) fn an_imap_configuration_with_credentials(port: u16, username: &str, password: &str) -> Configuration {
+    Configuration {
+        username: Some(Username(username.to_string())),
+        password: Some(Password(password.to_string())),
+        ..an_imap_configuration(port)
+    }
+}
+
+pub(crate) fn a_pop3_configuration(port: u16) -> Configuration {
+    Configuration {
+        protocol: Protocol::Pop3,
+        port: Port(u16nz!(port)),
+        ..Default::default()
+    }
+}
+
+pub(crate) fn a_pop3_configuration_with_credentials(port: u16, username: &str, password: &str) -> Configuration {
+    Configuration {
+        username: Some(Username(username.to_string())),
+        password: Some(Password(password.to_string())),
+        ..a_pop3_configuration(port)
+    }
+}
+
+pub(crate) fn an_ftp_configuration(port: u16, login: bool) -> Configuration {
+    Configuration {
+        protocol: Protocol::Ftp,
+        port: Port(u16nz!(port)),
+        ftp_login: login,
+        ..Default::default()
+    }
+}
+
+pub(crate) fn an_ftp_configuration_with_credentials(port: u16, username: &str, password: &str) -> Configuration {
+    Configuration {
+        username: Some(Username(username.to_string())),
+        password: Some(Password(password.to_string())),
+        ..an_ftp_configuration(port, true)
+    }
+}
+
+pub(crate) fn a_dns_configuration(port: u16, transport: DnsTransport) -> Configuration {
+    Configuration {
+        protocol: Protocol::Dns,
+        port: Port(u16nz!(port)),
+        dns_name: DnsName(String::from("db.internal")),
+        dns_record_type: DnsRecordType::A,
+        dns_transport: transport,
+        ..Default::default()
+    }
+}
+
+pub(crate) fn a_dns_configuration_expecting(port: u16, record_type: DnsRecordType, expected: &str) -> Configuration {
+    Configuration {
+        dns_record_type: record_type,
+        dns_expected: Some(DnsExpected(expected.to_string())),
+        ..a_dns_configuration(port, DnsTransport::Udp)
+    }
+}
+
+pub(crate) fn an_ldap_configuration(port: u16) -> Configuration {
+    Configuration {
+        protocol: Protocol::Ldap,
+        port: Port(u16nz!(port)),
+        ..Default::default()
+    }
+}
+
+pub(crate) fn an_ldap_configuration_with_credentials(port: u16, name: &str, password: &str) -> Configuration {
+    Configuration {
+        username: Some(Username(name.to_string())),
+        password: Some(Password(password.to_string())),
+        ..an_ldap_configuration(port)
+    }
+}
+
+pub(crate) fn a_cql_configuration(port: u16, query: bool) -> Configuration {
+    Configuration {
+        protocol: Protocol::Cql,
+        port: Port(u16nz!(port)),
+        cql_query: query,
+        ..Default::default()
+    }
+}
+
+pub(crate) fn a_cql_configuration_with_credentials(port: u16, username: &str, password: &str) -> Configuration {
+    Configuration {
+        username: Some(Username(username.to_string())),
+        password: Some(Password(password.to_string())),
+        ..a_cql_configuration(port, true)
+    }
+}
+
+pub(crate) fn a_clickhouse_configuration(port: u16, interface: ClickhouseInterface) -> Configuration {
+    Configuration {
+        protocol: Protocol::Clickhouse,
+        port: Port(u16nz!(port)),
+        clickhouse_interface: interface,
+        ..Default::default()
+    }
+}
+
+pub(crate) fn a_clickhouse_configuration_with_credentials(port: u16, interface: ClickhouseInterface, username: &str, password: &str) -> Configuration {
+    Configuration {
+        username: Some(Username(username.to_string())),
+        password: Some(Password(password.to_string())),
+        clickhouse_query: true,
+        ..a_clickhouse_configuration(port, interface)
+    }
+}
@@ -2,7 +2,7 @@ use std::num::NonZeroU16;
 use crate::{map, u16nz};
 use assert2::{check, assert};
 use std::time::Duration;
-use crate::configuration::{sanitize, InvalidConfiguration, Method, Path, Port, Protocol, StatusCode, Timeout};
+use crate::configuration::{sanitize, AmqpVhost, ClickhouseInterface, Database, DnsExpected, DnsName, DnsRecordType, DnsTransport, ElasticsearchStatus, GrpcService, InvalidConfiguration, KafkaBrokerId, KafkaTopics, MemcachedCheck, MemcachedKeyPrefix, Method, MongodbState, MqttVersion, Password, Path, Port, Protocol, RedisCheck, RedisCommand, RedisDatabase, RedisKeyPrefix, RedisMasterName, RedisProtocol, RedisReply, SocketPath, SqlExpectation, SqlQuery, StatusCode, Timeout, TlsServerName, Token, Username};
 
 #[test]
 fn non_empty_string_sanitization() {
@@ -396,11 +396,11 @@ fn protocol_should_be_trimmed() {
 #[test]
 fn malformed_protocol_should_be_reported() {
     let result = crate::configuration::load_configuration_from(map! {
-        "DOCKTEUR_PROTOCOL" => "ftp",
+        "DOCKTEUR_PROTOCOL" => "gopher",
     });
 
     assert!(let Err(error) = result);
-    check!(error == InvalidConfiguration::Protocol("ftp".to_string()));
+    check!(error == InvalidConfiguration::Protocol("gopher".to_string()));
 }
 
 #[test]
@@ -433,8 +433,2288 @@ fn redis_protocol_should_use_default_redis_port() {
     check!(configuration.port == Port(u16nz!(6379)));
 }
 
-impl From<&str> for Path {
-    fn from(value: &str) -> Self {
-        Path(String::from(value))
+#[test]
+fn redis_command_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_REDIS_COMMAND" => "ECHO hello",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_command == RedisCommand::from("ECHO hello"));
+}
+
+#[test]
+fn redis_command_should_fallback_on_default() {
+    let result = crate::configuration::load_configuration_from(map! {});
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_command == RedisCommand::from("PING"));
+}
+
+#[test]
+fn empty_redis_command_should_fallback_on_default() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_REDIS_COMMAND" => "",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_command == RedisCommand::from("PING"));
+}
+
+#[test]
+fn blank_redis_command_should_fallback_on_default() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_REDIS_COMMAND" => " ",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_command == RedisCommand::from("PING"));
+}
+
+#[test]
+fn redis_command_arguments_should_be_split_on_whitespace() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_REDIS_COMMAND" => " INFO   replication ",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_command.name() == "INFO");
+    check!(configuration.redis_command.args() == ["replication"]);
+}
+
+#[test]
+fn redis_reply_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_REDIS_REPLY" => "hello",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_reply == RedisReply::String("hello".to_string()));
+}
+
+#[test]
+fn redis_reply_should_fallback_on_default() {
+    let result = crate::configuration::load_configuration_from(map! {});
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_reply == RedisReply::String("PONG".to_string()));
+}
+
+#[test]
+fn empty_redis_reply_should_fallback_on_default() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_REDIS_REPLY" => "",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_reply == RedisReply::String("PONG".to_string()));
+}
+
+#[test]
+fn blank_redis_reply_should_fallback_on_default() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_REDIS_REPLY" => " ",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_reply == RedisReply::String("PONG".to_string()));
+}
+
+#[test]
+fn explicit_string_redis_reply_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_REDIS_REPLY" => "string:integer:1",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_reply == RedisReply::String("integer:1".to_string()));
+}
+
+#[test]
+fn integer_redis_reply_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_REDIS_REPLY" => "integer:1",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_reply == RedisReply::Integer(1));
+}
+
+#[test]
+fn malformed_integer_redis_reply_should_be_reported() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_REDIS_REPLY" => "integer:one",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::RedisReply("integer:one".to_string()));
+}
+
+#[test]
+fn regex_redis_reply_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_REDIS_REPLY" => "regex:^role:master$",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_reply == RedisReply::Regex(regex::Regex::new("^role:master$").unwrap()));
+}
+
+#[test]
+fn malformed_regex_redis_reply_should_be_reported() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_REDIS_REPLY" => "regex:(",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::RedisReply("regex:(".to_string()));
+}
+
+#[test]
+fn redis_check_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_REDIS_CHECK" => "round-trip",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_check == RedisCheck::RoundTrip);
+}
+
+#[test]
+fn redis_pub_sub_check_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_REDIS_CHECK" => "pub-sub",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_check == RedisCheck::PubSub);
+}
+
+#[test]
+fn redis_check_should_fallback_on_default() {
+    let result = crate::configuration::load_configuration_from(map! {});
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_check == RedisCheck::Command);
+}
+
+#[test]
+fn empty_redis_check_should_fallback_on_default() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_REDIS_CHECK" => "",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_check == RedisCheck::Command);
+}
+
+#[test]
+fn blank_redis_check_should_fallback_on_default() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_REDIS_CHECK" => " ",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_check == RedisCheck::Command);
+}
+
+#[test]
+fn redis_check_should_be_trimmed() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_REDIS_CHECK" => " round-trip ",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_check == RedisCheck::RoundTrip);
+}
+
+#[test]
+fn malformed_redis_check_should_be_reported() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_REDIS_CHECK" => "roundtrip",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::RedisCheck("roundtrip".to_string()));
+}
+
+#[test]
+fn redis_key_prefix_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_REDIS_KEY_PREFIX" => "healthcheck:",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_key_prefix == RedisKeyPrefix::from("healthcheck:"));
+}
+
+#[test]
+fn redis_key_prefix_should_fallback_on_default() {
+    let result = crate::configuration::load_configuration_from(map! {});
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_key_prefix == RedisKeyPrefix::from("dockteur:"));
+}
+
+#[test]
+fn empty_redis_key_prefix_should_fallback_on_default() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_REDIS_KEY_PREFIX" => "",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_key_prefix == RedisKeyPrefix::from("dockteur:"));
+}
+
+#[test]
+fn blank_redis_key_prefix_should_fallback_on_default() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_REDIS_KEY_PREFIX" => " ",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_key_prefix == RedisKeyPrefix::from("dockteur:"));
+}
+
+#[test]
+fn redis_database_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_REDIS_DATABASE" => "2",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_database == RedisDatabase(2));
+}
+
+#[test]
+fn redis_database_should_fallback_on_default() {
+    let result = crate::configuration::load_configuration_from(map! {});
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_database == RedisDatabase(0));
+}
+
+#[test]
+fn empty_redis_database_should_fallback_on_default() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_REDIS_DATABASE" => "",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_database == RedisDatabase(0));
+}
+
+#[test]
+fn blank_redis_database_should_fallback_on_default() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_REDIS_DATABASE" => " ",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_database == RedisDatabase(0));
+}
+
+#[test]
+fn malformed_redis_database_should_be_reported() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_REDIS_DATABASE" => "-1",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::RedisDatabase("-1".to_string()));
+}
+
+#[test]
+fn redis_database_should_be_read_from_the_path() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "redis",
+        "DOCKTEUR_PATH" => "/3",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_database == RedisDatabase(3));
+}
+
+#[test]
+fn redis_database_variable_should_have_precedence_on_the_path() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "redis",
+        "DOCKTEUR_PATH" => "/3",
+        "DOCKTEUR_REDIS_DATABASE" => "2",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_database == RedisDatabase(2));
+}
+
+#[test]
+fn malformed_redis_database_in_the_path_should_be_reported() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "redis",
+        "DOCKTEUR_PATH" => "/health",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::RedisDatabase("health".to_string()));
+}
+
+#[test]
+fn redis_database_should_not_be_accepted_for_redis_sentinel() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "redis-sentinel",
+        "DOCKTEUR_REDIS_DATABASE" => "2",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::UnsupportedRedisDatabase("2".to_string()));
+}
+
+#[test]
+fn redis_database_should_not_be_accepted_for_redis_cluster() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "redis-cluster",
+        "DOCKTEUR_REDIS_DATABASE" => "2",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::UnsupportedRedisDatabase("2".to_string()));
+}
+
+#[test]
+fn path_should_not_select_a_redis_database_for_other_protocols() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "http",
+        "DOCKTEUR_PATH" => "/3",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_database == RedisDatabase(0));
+}
+
+#[test]
+fn redis_protocol_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_REDIS_PROTOCOL" => "resp3",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_protocol == RedisProtocol::Resp3);
+}
+
+#[test]
+fn redis_protocol_should_fallback_on_default() {
+    let result = crate::configuration::load_configuration_from(map! {});
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_protocol == RedisProtocol::Resp2);
+}
+
+#[test]
+fn empty_redis_protocol_should_fallback_on_default() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_REDIS_PROTOCOL" => "",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_protocol == RedisProtocol::Resp2);
+}
+
+#[test]
+fn blank_redis_protocol_should_fallback_on_default() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_REDIS_PROTOCOL" => " ",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_protocol == RedisProtocol::Resp2);
+}
+
+#[test]
+fn malformed_redis_protocol_should_be_reported() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_REDIS_PROTOCOL" => "resp4",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::RedisProtocol("resp4".to_string()));
+}
+
+#[test]
+fn socket_path_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "redis",
+        "DOCKTEUR_SOCKET_PATH" => "/run/redis.sock",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.socket_path == Some(SocketPath::from("/run/redis.sock")));
+}
+
+#[test]
+fn socket_path_should_not_be_set_by_default() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "redis",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.socket_path == None);
+}
+
+#[test]
+fn empty_socket_path_should_not_be_set() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "redis",
+        "DOCKTEUR_SOCKET_PATH" => "",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.socket_path == None);
+}
+
+#[test]
+fn blank_socket_path_should_not_be_set() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "redis",
+        "DOCKTEUR_SOCKET_PATH" => " ",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.socket_path == None);
+}
+
+#[test]
+fn socket_path_should_be_trimmed() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "redis",
+        "DOCKTEUR_SOCKET_PATH" => " /run/redis.sock ",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.socket_path == Some(SocketPath::from("/run/redis.sock")));
+}
+
+#[test]
+fn relative_socket_path_should_not_be_accepted() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "redis",
+        "DOCKTEUR_SOCKET_PATH" => "redis.sock",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::SocketPath("redis.sock".to_string()));
+}
+
+#[test]
+fn socket_path_should_not_be_accepted_for_protocols_not_supporting_it() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "http",
+        "DOCKTEUR_SOCKET_PATH" => "/run/http.sock",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::UnsupportedSocketPath("/run/http.sock".to_string()));
+}
+
+#[test]
+fn service_port_should_not_be_accepted_together_with_a_socket_path() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "redis",
+        "DOCKTEUR_SOCKET_PATH" => "/run/redis.sock",
+        "DOCKTEUR_PORT" => "0",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::PortWithSocketPath("0".to_string()));
+}
+
+#[test]
+fn common_port_variable_should_be_ignored_when_a_socket_path_is_set() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "redis",
+        "DOCKTEUR_SOCKET_PATH" => "/run/redis.sock",
+        "PORT" => "MALFORMED",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.socket_path == Some(SocketPath::from("/run/redis.sock")));
+}
+
+#[test]
+fn protocol_redis_sentinel_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "redis-sentinel",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.protocol == Protocol::RedisSentinel);
+}
+
+#[test]
+fn redis_sentinel_protocol_should_use_default_redis_sentinel_port() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "redis-sentinel",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.port == Port(u16nz!(26379)));
+}
+
+#[test]
+fn redis_master_name_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_REDIS_MASTER_NAME" => "primary",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_master_name == RedisMasterName::from("primary"));
+}
+
+#[test]
+fn redis_master_name_should_fallback_on_default() {
+    let result = crate::configuration::load_configuration_from(map! {});
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_master_name == RedisMasterName::from("mymaster"));
+}
+
+#[test]
+fn empty_redis_master_name_should_fallback_on_default() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_REDIS_MASTER_NAME" => "",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_master_name == RedisMasterName::from("mymaster"));
+}
+
+#[test]
+fn blank_redis_master_name_should_fallback_on_default() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_REDIS_MASTER_NAME" => " ",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_master_name == RedisMasterName::from("mymaster"));
+}
+
+#[test]
+fn redis_master_name_should_be_trimmed() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_REDIS_MASTER_NAME" => " primary ",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_master_name == RedisMasterName::from("primary"));
+}
+
+#[test]
+fn protocol_redis_cluster_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "redis-cluster",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.protocol == Protocol::RedisCluster);
+}
+
+#[test]
+fn redis_cluster_protocol_should_use_default_redis_port() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "redis-cluster",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.port == Port(u16nz!(6379)));
+}
+
+#[test]
+fn redis_cluster_slots_check_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_REDIS_CLUSTER_CHECK_SLOTS" => "true",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_cluster_check_slots);
+}
+
+#[test]
+fn redis_cluster_slots_check_should_be_disabled_by_default() {
+    let result = crate::configuration::load_configuration_from(map! {});
+
+    assert!(let Ok(configuration) = result);
+    check!(!configuration.redis_cluster_check_slots);
+}
+
+#[test]
+fn blank_redis_cluster_slots_check_should_be_disabled() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_REDIS_CLUSTER_CHECK_SLOTS" => " ",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(!configuration.redis_cluster_check_slots);
+}
+
+#[test]
+fn redis_cluster_slots_check_should_be_case_insensitive() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_REDIS_CLUSTER_CHECK_SLOTS" => " TRUE ",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_cluster_check_slots);
+}
+
+#[test]
+fn malformed_redis_cluster_slots_check_should_be_reported() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_REDIS_CLUSTER_CHECK_SLOTS" => "yes",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::RedisClusterCheckSlots("yes".to_string()));
+}
+
+#[test]
+fn redis_cluster_node_check_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_REDIS_CLUSTER_CHECK_NODE" => "true",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.redis_cluster_check_node);
+}
+
+#[test]
+fn redis_cluster_node_check_should_be_disabled_by_default() {
+    let result = crate::configuration::load_configuration_from(map! {});
+
+    assert!(let Ok(configuration) = result);
+    check!(!configuration.redis_cluster_check_node);
+}
+
+#[test]
+fn malformed_redis_cluster_node_check_should_be_reported() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_REDIS_CLUSTER_CHECK_NODE" => "1",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::RedisClusterCheckNode("1".to_string()));
+}
+
+#[test]
+fn socket_path_should_be_accepted_for_redis_cluster() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "redis-cluster",
+        "DOCKTEUR_SOCKET_PATH" => "/run/redis.sock",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.socket_path == Some(SocketPath::from("/run/redis.sock")));
+}
+
+#[test]
+fn protocol_postgres_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "postgres",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.protocol == Protocol::Postgres);
+}
+
+#[test]
+fn postgres_protocol_should_use_default_postgres_port() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "postgres",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.port == Port(u16nz!(5432)));
+}
+
+#[test]
+fn username_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_USERNAME" => "monitor",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.username == Some(Username::from("monitor")));
+}
+
+#[test]
+fn username_should_not_be_set_by_default() {
+    let result = crate::configuration::load_configuration_from(map! {});
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.username == None);
+}
+
+#[test]
+fn blank_username_should_not_be_set() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_USERNAME" => " ",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.username == None);
+}
+
+#[test]
+fn username_should_be_trimmed() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_USERNAME" => " monitor ",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.username == Some(Username::from("monitor")));
+}
+
+#[test]
+fn password_should_be_read_from_file() {
+    let path = a_secret_file("password_should_be_read_from_file", "s3cr3t");
+
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PASSWORD_FILE" => path,
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.password == Some(Password::from("s3cr3t")));
+}
+
+#[test]
+fn password_read_from_file_should_not_include_the_trailing_newline() {
+    let path = a_secret_file("password_read_from_file_should_not_include_the_trailing_newline", " s3cr3t \n");
+
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PASSWORD_FILE" => path,
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.password == Some(Password::from(" s3cr3t ")));
+}
+
+#[test]
+fn password_should_not_be_set_by_default() {
+    let result = crate::configuration::load_configuration_from(map! {});
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.password == None);
+}
+
+#[test]
+fn blank_password_file_should_not_be_set() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PASSWORD_FILE" => " ",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.password == None);
+}
+
+#[test]
+fn missing_password_file_should_be_reported() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PASSWORD_FILE" => "/this/file/does/not/exist",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::PasswordFile("/this/file/does/not/exist".to_string()));
+}
+
+#[test]
+fn password_should_not_be_printed_in_debug_output() {
+    let password = Password::from("s3cr3t");
+
+    check!(format!("{:?}", password) == "Password(***)");
+}
+
+#[test]
+fn database_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_DATABASE" => "app",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.database == Some(Database::from("app")));
+}
+
+#[test]
+fn database_should_not_be_set_by_default() {
+    let result = crate::configuration::load_configuration_from(map! {});
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.database == None);
+}
+
+#[test]
+fn blank_database_should_not_be_set() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_DATABASE" => " ",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.database == None);
+}
+
+#[test]
+fn protocol_mysql_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "mysql",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.protocol == Protocol::Mysql);
+}
+
+#[test]
+fn mysql_protocol_should_use_default_mysql_port() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "mysql",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.port == Port(u16nz!(3306)));
+}
+
+#[test]
+fn username_should_be_read_from_file() {
+    let path = a_secret_file("username_should_be_read_from_file", "monitor\n");
+
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_USERNAME_FILE" => path,
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.username == Some(Username::from("monitor")));
+}
+
+#[test]
+fn username_variable_should_have_precedence_on_username_file() {
+    let path = a_secret_file("username_variable_should_have_precedence_on_username_file", "monitor");
+
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_USERNAME" => "admin",
+        "DOCKTEUR_USERNAME_FILE" => path,
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.username == Some(Username::from("admin")));
+}
+
+#[test]
+fn missing_username_file_should_be_reported() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_USERNAME_FILE" => "/this/file/does/not/exist",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::UsernameFile("/this/file/does/not/exist".to_string()));
+}
+
+#[test]
+fn sql_query_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "postgres",
+        "DOCKTEUR_SQL_QUERY" => " SELECT pg_is_in_recovery() ",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.sql_query == Some(SqlQuery::from("SELECT pg_is_in_recovery()")));
+}
+
+#[test]
+fn sql_query_should_be_missing_by_default() {
+    let result = crate::configuration::load_configuration_from(map! {});
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.sql_query == None);
+}
+
+#[test]
+fn blank_sql_query_should_be_missing() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "postgres",
+        "DOCKTEUR_SQL_QUERY" => " ",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.sql_query == None);
+}
+
+#[test]
+fn sql_query_should_be_rejected_for_other_protocols() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "mongodb",
+        "DOCKTEUR_SQL_QUERY" => "SELECT 1",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::UnsupportedSqlQuery("SELECT 1".to_string()));
+}
+
+#[test]
+fn sql_expectation_should_be_missing_by_default() {
+    let result = crate::configuration::load_configuration_from(map! {});
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.sql_expectation == None);
+}
+
+#[test]
+fn sql_expectation_without_operator_should_be_an_equality() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "postgres",
+        "DOCKTEUR_SQL_QUERY" => "SELECT 1",
+        "DOCKTEUR_SQL_EXPECTED" => "false",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.sql_expectation == Some(SqlExpectation::Equal("false".to_string())));
+}
+
+#[test]
+fn equal_sql_expectation_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "postgres",
+        "DOCKTEUR_SQL_QUERY" => "SELECT 1",
+        "DOCKTEUR_SQL_EXPECTED" => "= primary",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.sql_expectation == Some(SqlExpectation::Equal("primary".to_string())));
+}
+
+#[test]
+fn not_equal_sql_expectation_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "postgres",
+        "DOCKTEUR_SQL_QUERY" => "SELECT 1",
+        "DOCKTEUR_SQL_EXPECTED" => "!=0",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.sql_expectation == Some(SqlExpectation::NotEqual("0".to_string())));
+}
+
+#[test]
+fn less_than_sql_expectation_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "postgres",
+        "DOCKTEUR_SQL_QUERY" => "SELECT 1",
+        "DOCKTEUR_SQL_EXPECTED" => "< 10",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.sql_expectation == Some(SqlExpectation::LessThan(10.0)));
+}
+
+#[test]
+fn less_or_equal_sql_expectation_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "postgres",
+        "DOCKTEUR_SQL_QUERY" => "SELECT 1",
+        "DOCKTEUR_SQL_EXPECTED" => "<=2.5",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.sql_expectation == Some(SqlExpectation::LessOrEqual(2.5)));
+}
+
+#[test]
+fn greater_than_sql_expectation_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "postgres",
+        "DOCKTEUR_SQL_QUERY" => "SELECT 1",
+        "DOCKTEUR_SQL_EXPECTED" => ">0",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.sql_expectation == Some(SqlExpectation::GreaterThan(0.0)));
+}
+
+#[test]
+fn greater_or_equal_sql_expectation_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "postgres",
+        "DOCKTEUR_SQL_QUERY" => "SELECT 1",
+        "DOCKTEUR_SQL_EXPECTED" => ">= -1",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.sql_expectation == Some(SqlExpectation::GreaterOrEqual(-1.0)));
+}
+
+#[test]
+fn non_numeric_sql_comparison_should_be_reported() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "postgres",
+        "DOCKTEUR_SQL_QUERY" => "SELECT 1",
+        "DOCKTEUR_SQL_EXPECTED" => "< ten",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::SqlExpectation("< ten".to_string()));
+}
+
+#[test]
+fn sql_expectation_without_query_should_be_reported() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "mysql",
+        "DOCKTEUR_SQL_EXPECTED" => "> 0",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::SqlExpectationWithoutQuery("> 0".to_string()));
+}
+
+#[test]
+fn protocol_mongodb_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "mongodb",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.protocol == Protocol::Mongodb);
+}
+
+#[test]
+fn mongodb_protocol_should_use_default_mongodb_port() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "mongodb",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.port == Port(u16nz!(27017)));
+}
+
+#[test]
+fn mongodb_state_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_MONGODB_STATE" => "Writable-Primary",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.mongodb_state == MongodbState::WritablePrimary);
+}
+
+#[test]
+fn mongodb_state_should_fallback_on_default() {
+    let result = crate::configuration::load_configuration_from(map! {});
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.mongodb_state == MongodbState::Any);
+}
+
+#[test]
+fn blank_mongodb_state_should_fallback_on_default() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_MONGODB_STATE" => " ",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.mongodb_state == MongodbState::Any);
+}
+
+#[test]
+fn malformed_mongodb_state_should_be_reported() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_MONGODB_STATE" => "arbiter",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::MongodbState("arbiter".to_string()));
+}
+
+#[test]
+fn protocol_memcached_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "memcached",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.protocol == Protocol::Memcached);
+}
+
+#[test]
+fn memcached_protocol_should_use_default_memcached_port() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "memcached",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.port == Port(u16nz!(11211)));
+}
+
+#[test]
+fn memcached_check_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_MEMCACHED_CHECK" => "round-trip",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.memcached_check == MemcachedCheck::RoundTrip);
+}
+
+#[test]
+fn memcached_check_should_fallback_on_default() {
+    let result = crate::configuration::load_configuration_from(map! {});
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.memcached_check == MemcachedCheck::Version);
+}
+
+#[test]
+fn malformed_memcached_check_should_be_reported() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_MEMCACHED_CHECK" => "stats",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::MemcachedCheck("stats".to_string()));
+}
+
+#[test]
+fn memcached_key_prefix_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_MEMCACHED_KEY_PREFIX" => "healthcheck:",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.memcached_key_prefix == MemcachedKeyPrefix::from("healthcheck:"));
+}
+
+#[test]
+fn memcached_key_prefix_should_fallback_on_default() {
+    let result = crate::configuration::load_configuration_from(map! {});
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.memcached_key_prefix == MemcachedKeyPrefix::from("dockteur:"));
+}
+
+#[test]
+fn memcached_key_prefix_with_spaces_should_be_reported() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_MEMCACHED_KEY_PREFIX" => "health check:",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::MemcachedKeyPrefix("health check:".to_string()));
+}
+
+#[test]
+fn too_long_memcached_key_prefix_should_be_reported() {
+    let prefix = "x".repeat(201);
+
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_MEMCACHED_KEY_PREFIX" => prefix.as_str(),
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::MemcachedKeyPrefix(prefix));
+}
+
+#[test]
+fn protocol_amqp_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "amqp",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.protocol == Protocol::Amqp);
+}
+
+#[test]
+fn amqp_protocol_should_use_default_amqp_port() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "amqp",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.port == Port(u16nz!(5672)));
+}
+
+#[test]
+fn amqp_vhost_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_AMQP_VHOST" => "orders",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.amqp_vhost == AmqpVhost::from("orders"));
+}
+
+#[test]
+fn amqp_vhost_should_fallback_on_default() {
+    let result = crate::configuration::load_configuration_from(map! {});
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.amqp_vhost == AmqpVhost::from("/"));
+}
+
+#[test]
+fn blank_amqp_vhost_should_fallback_on_default() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_AMQP_VHOST" => " ",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.amqp_vhost == AmqpVhost::from("/"));
+}
+
+#[test]
+fn too_long_amqp_vhost_should_be_reported() {
+    let vhost = "x".repeat(256);
+
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_AMQP_VHOST" => vhost.as_str(),
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::AmqpVhost(vhost));
+}
+
+#[test]
+fn protocol_kafka_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "kafka",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.protocol == Protocol::Kafka);
+}
+
+#[test]
+fn kafka_protocol_should_use_default_kafka_port() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "kafka",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.port == Port(u16nz!(9092)));
+}
+
+#[test]
+fn kafka_metadata_check_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_KAFKA_CHECK_METADATA" => "true",
+        "DOCKTEUR_KAFKA_BROKER_ID" => "1",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.kafka_check_metadata);
+}
+
+#[test]
+fn kafka_metadata_check_without_broker_id_should_be_reported() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_KAFKA_CHECK_METADATA" => "true",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::KafkaMetadataWithoutBrokerId("true".to_string()));
+}
+
+#[test]
+fn kafka_metadata_check_should_be_disabled_by_default() {
+    let result = crate::configuration::load_configuration_from(map! {});
+
+    assert!(let Ok(configuration) = result);
+    check!(!configuration.kafka_check_metadata);
+}
+
+#[test]
+fn malformed_kafka_metadata_check_should_be_reported() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_KAFKA_CHECK_METADATA" => "yes",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::KafkaCheckMetadata("yes".to_string()));
+}
+
+#[test]
+fn kafka_broker_id_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_KAFKA_BROKER_ID" => "0",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.kafka_broker_id == Some(KafkaBrokerId::from(0)));
+}
+
+#[test]
+fn kafka_broker_id_should_be_missing_by_default() {
+    let result = crate::configuration::load_configuration_from(map! {});
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.kafka_broker_id == None);
+}
+
+#[test]
+fn negative_kafka_broker_id_should_be_reported() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_KAFKA_BROKER_ID" => "-1",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::KafkaBrokerId("-1".to_string()));
+}
+
+#[test]
+fn malformed_kafka_broker_id_should_be_reported() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_KAFKA_BROKER_ID" => "one",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::KafkaBrokerId("one".to_string()));
+}
+
+#[test]
+fn kafka_topics_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_KAFKA_TOPICS" => "orders, payments.v1",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.kafka_topics == KafkaTopics::from(vec!["orders", "payments.v1"]));
+}
+
+#[test]
+fn kafka_topics_should_be_empty_by_default() {
+    let result = crate::configuration::load_configuration_from(map! {});
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.kafka_topics == KafkaTopics::default());
+}
+
+#[test]
+fn invalid_kafka_topic_should_be_reported() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_KAFKA_TOPICS" => "orders,pay ments",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::KafkaTopics("orders,pay ments".to_string()));
+}
+
+#[test]
+fn empty_kafka_topic_should_be_reported() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_KAFKA_TOPICS" => "orders,,payments",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::KafkaTopics("orders,,payments".to_string()));
+}
+
+#[test]
+fn protocol_nats_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "nats",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.protocol == Protocol::Nats);
+}
+
+#[test]
+fn nats_protocol_should_use_default_nats_port() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "nats",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.port == Port(u16nz!(4222)));
+}
+
+#[test]
+fn token_should_be_read_from_file() {
+    let path = a_secret_file("token_should_be_read_from_file", "s3cr3t\n");
+
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_TOKEN_FILE" => path,
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.token == Some(Token::from("s3cr3t")));
+}
+
+#[test]
+fn token_should_be_missing_by_default() {
+    let result = crate::configuration::load_configuration_from(map! {});
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.token == None);
+}
+
+#[test]
+fn missing_token_file_should_be_reported() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_TOKEN_FILE" => "/this/file/does/not/exist",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::TokenFile("/this/file/does/not/exist".to_string()));
+}
+
+#[test]
+fn token_should_not_be_printed_in_debug_output() {
+    let token = Token::from("s3cr3t");
+
+    check!(format!("{:?}", token) == "Token(***)");
+}
+
+#[test]
+fn protocol_mqtt_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "mqtt",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.protocol == Protocol::Mqtt);
+}
+
+#[test]
+fn mqtt_protocol_should_use_default_mqtt_port() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "mqtt",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.port == Port(u16nz!(1883)));
+}
+
+#[test]
+fn mqtt_version_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_MQTT_VERSION" => "5.0",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.mqtt_version == MqttVersion::V5);
+}
+
+#[test]
+fn mqtt_version_should_fallback_on_default() {
+    let result = crate::configuration::load_configuration_from(map! {});
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.mqtt_version == MqttVersion::V311);
+}
+
+#[test]
+fn malformed_mqtt_version_should_be_reported() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_MQTT_VERSION" => "3.1",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::MqttVersion("3.1".to_string()));
+}
+
+#[test]
+fn mqtt_delivery_check_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_MQTT_CHECK_DELIVERY" => "TRUE",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.mqtt_check_delivery);
+}
+
+#[test]
+fn mqtt_delivery_check_should_be_disabled_by_default() {
+    let result = crate::configuration::load_configuration_from(map! {});
+
+    assert!(let Ok(configuration) = result);
+    check!(!configuration.mqtt_check_delivery);
+}
+
+#[test]
+fn malformed_mqtt_delivery_check_should_be_reported() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_MQTT_CHECK_DELIVERY" => "1",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::MqttCheckDelivery("1".to_string()));
+}
+
+#[test]
+fn tls_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_TLS" => "true",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.tls);
+}
+
+#[test]
+fn tls_should_be_disabled_by_default() {
+    let result = crate::configuration::load_configuration_from(map! {});
+
+    assert!(let Ok(configuration) = result);
+    check!(!configuration.tls);
+}
+
+#[test]
+fn malformed_tls_should_be_reported() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_TLS" => "yes",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::Tls("yes".to_string()));
+}
+
+#[test]
+fn tls_server_name_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_TLS_SERVER_NAME" => "api.example.com",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.tls_server_name == TlsServerName::from("api.example.com"));
+}
+
+#[test]
+fn tls_server_name_should_accept_ip_address() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_TLS_SERVER_NAME" => "127.0.0.1",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.tls_server_name == TlsServerName::from("127.0.0.1"));
+}
+
+#[test]
+fn tls_server_name_should_fallback_on_localhost() {
+    let result = crate::configuration::load_configuration_from(map! {});
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.tls_server_name == TlsServerName::from("localhost"));
+}
+
+#[test]
+fn malformed_tls_server_name_should_be_reported() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_TLS_SERVER_NAME" => "not a host",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::TlsServerName("not a host".to_string()));
+}
+
+#[test]
+fn tls_skip_verify_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_TLS_SKIP_VERIFY" => "true",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.tls_skip_verify);
+}
+
+#[test]
+fn malformed_tls_skip_verify_should_be_reported() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_TLS_SKIP_VERIFY" => "no",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::TlsSkipVerify("no".to_string()));
+}
+
+#[test]
+fn protocol_grpc_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "grpc",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.protocol == Protocol::Grpc);
+}
+
+#[test]
+fn grpc_protocol_should_use_default_grpc_port() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "grpc",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.port == Port(u16nz!(50051)));
+}
+
+#[test]
+fn grpc_service_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_GRPC_SERVICE" => "grpc.health.v1.Health",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.grpc_service == GrpcService::from("grpc.health.v1.Health"));
+}
+
+#[test]
+fn grpc_service_should_fallback_on_server() {
+    let result = crate::configuration::load_configuration_from(map! {});
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.grpc_service == GrpcService::from(""));
+}
+
+#[test]
+fn protocol_elasticsearch_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "elasticsearch",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.protocol == Protocol::Elasticsearch);
+}
+
+#[test]
+fn protocol_opensearch_should_be_read_as_elasticsearch() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "opensearch",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.protocol == Protocol::Elasticsearch);
+}
+
+#[test]
+fn elasticsearch_protocol_should_use_default_elasticsearch_port() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "elasticsearch",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.port == Port(u16nz!(9200)));
+}
+
+#[test]
+fn elasticsearch_minimum_status_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_ELASTICSEARCH_MINIMUM_STATUS" => "Green",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.elasticsearch_minimum_status == ElasticsearchStatus::Green);
+}
+
+#[test]
+fn elasticsearch_minimum_status_should_fallback_on_yellow() {
+    let result = crate::configuration::load_configuration_from(map! {});
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.elasticsearch_minimum_status == ElasticsearchStatus::Yellow);
+}
+
+#[test]
+fn malformed_elasticsearch_minimum_status_should_be_reported() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_ELASTICSEARCH_MINIMUM_STATUS" => "red",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::ElasticsearchMinimumStatus("red".to_string()));
+}
+
+#[test]
+fn elasticsearch_wait_for_status_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_ELASTICSEARCH_WAIT_FOR_STATUS" => "yellow",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.elasticsearch_wait_for_status == Some(ElasticsearchStatus::Yellow));
+}
+
+#[test]
+fn elasticsearch_wait_for_status_should_be_disabled_by_default() {
+    let result = crate::configuration::load_configuration_from(map! {});
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.elasticsearch_wait_for_status == None);
+}
+
+#[test]
+fn malformed_elasticsearch_wait_for_status_should_be_reported() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_ELASTICSEARCH_WAIT_FOR_STATUS" => "blue",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::ElasticsearchWaitForStatus("blue".to_string()));
+}
+
+#[test]
+fn protocol_smtp_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "smtp",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.protocol == Protocol::Smtp);
+}
+
+#[test]
+fn smtp_protocol_should_use_default_smtp_port() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "smtp",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.port == Port(u16nz!(25)));
+}
+
+#[test]
+fn smtp_protocol_over_tls_should_use_default_smtps_port() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "smtp",
+        "DOCKTEUR_TLS" => "true",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.port == Port(u16nz!(465)));
+}
+
+#[test]
+fn smtp_starttls_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_SMTP_STARTTLS" => "true",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.smtp_starttls);
+}
+
+#[test]
+fn smtp_starttls_should_be_disabled_by_default() {
+    let result = crate::configuration::load_configuration_from(map! {});
+
+    assert!(let Ok(configuration) = result);
+    check!(!configuration.smtp_starttls);
+}
+
+#[test]
+fn malformed_smtp_starttls_should_be_reported() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_SMTP_STARTTLS" => "on",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::SmtpStarttls("on".to_string()));
+}
+
+#[test]
+fn protocol_imap_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "imap",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.protocol == Protocol::Imap);
+}
+
+#[test]
+fn imap_protocol_should_use_default_imap_port() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "imap",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.port == Port(u16nz!(143)));
+}
+
+#[test]
+fn imap_protocol_over_tls_should_use_default_imaps_port() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "imap",
+        "DOCKTEUR_TLS" => "true",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.port == Port(u16nz!(993)));
+}
+
+#[test]
+fn protocol_pop3_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "pop3",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.protocol == Protocol::Pop3);
+}
+
+#[test]
+fn pop3_protocol_should_use_default_pop3_port() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "pop3",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.port == Port(u16nz!(110)));
+}
+
+#[test]
+fn pop3_protocol_over_tls_should_use_default_pop3s_port() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "pop3",
+        "DOCKTEUR_TLS" => "true",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.port == Port(u16nz!(995)));
+}
+
+#[test]
+fn protocol_ftp_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "ftp",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.protocol == Protocol::Ftp);
+}
+
+#[test]
+fn ftp_protocol_should_use_default_ftp_port() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "ftp",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.port == Port(u16nz!(21)));
+}
+
+#[test]
+fn ftp_login_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_FTP_LOGIN" => "true",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.ftp_login);
+}
+
+#[test]
+fn ftp_login_should_be_disabled_by_default() {
+    let result = crate::configuration::load_configuration_from(map! {});
+
+    assert!(let Ok(configuration) = result);
+    check!(!configuration.ftp_login);
+}
+
+#[test]
+fn malformed_ftp_login_should_be_reported() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_FTP_LOGIN" => "yes",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::FtpLogin("yes".to_string()));
+}
+
+#[test]
+fn protocol_dns_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "dns",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.protocol == Protocol::Dns);
+}
+
+#[test]
+fn dns_protocol_should_use_default_dns_port() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "dns",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.port == Port(u16nz!(53)));
+}
+
+#[test]
+fn dns_query_should_be_read_from_environment_variables() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_DNS_NAME" => "db.internal",
+        "DOCKTEUR_DNS_TYPE" => "AAAA",
+        "DOCKTEUR_DNS_TRANSPORT" => "TCP",
+        "DOCKTEUR_DNS_EXPECTED" => "fd00::1",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.dns_name == DnsName::from("db.internal"));
+    check!(configuration.dns_record_type == DnsRecordType::Aaaa);
+    check!(configuration.dns_transport == DnsTransport::Tcp);
+    check!(configuration.dns_expected == Some(DnsExpected::from("fd00::1")));
+}
+
+#[test]
+fn dns_query_should_fallback_on_root_name_servers_over_udp() {
+    let result = crate::configuration::load_configuration_from(map! {});
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.dns_name == DnsName::from("."));
+    check!(configuration.dns_record_type == DnsRecordType::Ns);
+    check!(configuration.dns_transport == DnsTransport::Udp);
+    check!(configuration.dns_expected == None);
+}
+
+#[test]
+fn malformed_dns_name_should_be_reported() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_DNS_NAME" => "db..internal",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::DnsName("db..internal".to_string()));
+}
+
+#[test]
+fn malformed_dns_record_type_should_be_reported() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_DNS_TYPE" => "ANY",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::DnsRecordType("ANY".to_string()));
+}
+
+#[test]
+fn malformed_dns_transport_should_be_reported() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_DNS_TRANSPORT" => "quic",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::DnsTransport("quic".to_string()));
+}
+
+#[test]
+fn protocol_ldap_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "ldap",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.protocol == Protocol::Ldap);
+}
+
+#[test]
+fn ldap_protocol_should_use_default_ldap_port() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "ldap",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.port == Port(u16nz!(389)));
+}
+
+#[test]
+fn ldap_protocol_over_tls_should_use_default_ldaps_port() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "ldap",
+        "DOCKTEUR_TLS" => "true",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.port == Port(u16nz!(636)));
+}
+
+#[test]
+fn ldap_bind_with_a_password_should_be_accepted() {
+    let path = a_secret_file("ldap_bind_with_a_password_should_be_accepted", "s3cr3t");
+
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "ldap",
+        "DOCKTEUR_USERNAME" => "cn=admin,dc=example,dc=org",
+        "DOCKTEUR_PASSWORD_FILE" => path,
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.password == Some(Password::from("s3cr3t")));
+}
+
+#[test]
+fn ldap_bind_without_password_should_be_reported() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "ldap",
+        "DOCKTEUR_USERNAME" => "cn=admin,dc=example,dc=org",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::LdapBindWithoutPassword("cn=admin,dc=example,dc=org".to_string()));
+}
+
+#[test]
+fn ldap_bind_with_an_empty_password_should_be_reported() {
+    let path = a_secret_file("ldap_bind_with_an_empty_password_should_be_reported", "\n");
+
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "ldap",
+        "DOCKTEUR_USERNAME" => "cn=admin,dc=example,dc=org",
+        "DOCKTEUR_PASSWORD_FILE" => path,
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::LdapBindWithoutPassword("cn=admin,dc=example,dc=org".to_string()));
+}
+
+#[test]
+fn protocol_cql_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "cql",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.protocol == Protocol::Cql);
+}
+
+#[test]
+fn cql_protocol_should_use_default_cql_port() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "cql",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.port == Port(u16nz!(9042)));
+}
+
+#[test]
+fn cql_query_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_CQL_QUERY" => "true",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.cql_query);
+}
+
+#[test]
+fn cql_query_should_be_disabled_by_default() {
+    let result = crate::configuration::load_configuration_from(map! {});
+
+    assert!(let Ok(configuration) = result);
+    check!(!configuration.cql_query);
+}
+
+#[test]
+fn malformed_cql_query_should_be_reported() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_CQL_QUERY" => "yes",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::CqlQuery("yes".to_string()));
+}
+
+#[test]
+fn protocol_clickhouse_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "clickhouse",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.protocol == Protocol::Clickhouse);
+}
+
+#[test]
+fn clickhouse_protocol_should_use_default_clickhouse_http_port() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "clickhouse",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.port == Port(u16nz!(8123)));
+}
+
+#[test]
+fn clickhouse_protocol_over_native_interface_should_use_default_clickhouse_native_port() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_PROTOCOL" => "clickhouse",
+        "DOCKTEUR_CLICKHOUSE_INTERFACE" => "native",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.port == Port(u16nz!(9000)));
+}
+
+#[test]
+fn clickhouse_interface_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_CLICKHOUSE_INTERFACE" => "Native",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.clickhouse_interface == ClickhouseInterface::Native);
+}
+
+#[test]
+fn clickhouse_interface_should_fallback_on_http() {
+    let result = crate::configuration::load_configuration_from(map! {});
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.clickhouse_interface == ClickhouseInterface::Http);
+}
+
+#[test]
+fn malformed_clickhouse_interface_should_be_reported() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_CLICKHOUSE_INTERFACE" => "grpc",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::ClickhouseInterface("grpc".to_string()));
+}
+
+#[test]
+fn clickhouse_query_should_be_read_from_environment_variable() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_CLICKHOUSE_QUERY" => "true",
+    });
+
+    assert!(let Ok(configuration) = result);
+    check!(configuration.clickhouse_query);
+}
+
+#[test]
+fn clickhouse_query_should_be_disabled_by_default() {
+    let result = crate::configuration::load_configuration_from(map! {});
+
+    assert!(let Ok(configuration) = result);
+    check!(!configuration.clickhouse_query);
+}
+
+#[test]
+fn malformed_clickhouse_query_should_be_reported() {
+    let result = crate::configuration::load_configuration_from(map! {
+        "DOCKTEUR_CLICKHOUSE_QUERY" => "yes",
+    });
+
+    assert!(let Err(error) = result);
+    check!(error == InvalidConfiguration::ClickhouseQuery("yes".to_string()));
+}
+
+fn a_secret_file(name: &str, content: &str) -> String {
+    let path = std::env::temp_dir().join(format!("dockteur-{}-{}", std::process::id(), name));
+    std::fs::write(&path, content).unwrap();
+    path.to_str().unwrap().to_string()
+}
+
+impl From<&str> for Path {
+    fn from(value: &str) -> Self {
+        Path(String::from(value))
+    }
+}
+
+impl From<&str> for RedisCommand {
+    fn from(value: &str) -> Self {
+        RedisCommand(value.split_whitespace().map(String::from).collect())
+    }
+}
+
+impl From<&str> for RedisKeyPrefix {
+    fn from(value: &str) -> Self {
+        RedisKeyPrefix(String::from(value))
+    }
+}
+
+impl From<&str> for SocketPath {
+    fn from(value: &str) -> Self {
+        SocketPath(String::from(value))
+    }
+}
+
+impl From<&str> for RedisMasterName {
+    fn from(value: &str) -> Self {
+        RedisMasterName(String::from(value))
+    }
+}
+
+impl From<&str> for Username {
+    fn from(value: &str) -> Self {
+        Username(String::from(value))
+    }
+}
+
+impl From<&str> for Password {
+    fn from(value: &str) -> Self {
+        Password(String::from(value))
+    }
+}
+
+impl From<&str> for Database {
+    fn from(value: &str) -> Self {
+        Database(String::from(value))
+    }
+}
+
+impl From<&str> for SqlQuery {
+    fn from(value: &str) -> Self {
+        SqlQuery(String::from(value))
+    }
+}
+
+impl From<&str> for MemcachedKeyPrefix {
+    fn from(value: &str) -> Self {
+        MemcachedKeyPrefix(String::from(value))
+    }
+}
+
+impl From<&str> for AmqpVhost {
+    fn from(value: &str) -> Self {
+        AmqpVhost(String::from(value))
+    }
+}
+
+impl From<i32> for KafkaBrokerId {
+    fn from(value: i32) -> Self {
+        KafkaBrokerId(value)
+    }
+}
+
+impl From<Vec<&str>> for KafkaTopics {
+    fn from(value: Vec<&str>) -> Self {
+        KafkaTopics(value.into_iter().map(String::from).collect())
+    }
+}
+
+impl From<&str> for Token {
+    fn from(value: &str) -> Self {
+        Token(String::from(value))
+    }
+}
+
+impl From<&str> for TlsServerName {
+    fn from(value: &str) -> Self {
+        TlsServerName(String::from(value))
+    }
+}
+
+impl From<&str> for GrpcService {
+    fn from(value: &str) -> Self {
+        GrpcService(String::from(value))
+    }
+}
+
+impl From<&str> for DnsName {
+    fn from(value: &str) -> Self {
+        DnsName(String::from(value))
+    }
+}
+
+impl From<&str> for DnsExpected {
+    fn from(value: &str) -> Self {
+        DnsExpected(String::from(value))
     }
 }
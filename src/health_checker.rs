@@ -1,17 +1,113 @@
 use std::collections::HashMap;
 use std::env;
-use std::time::Duration;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use async_trait::async_trait;
 use crate::configuration;
 use crate::configuration::Configuration;
 use crate::configuration::Protocol;
+use crate::health_checker::amqp::Amqp;
+use crate::health_checker::clickhouse::Clickhouse;
+use crate::health_checker::cql::Cql;
+use crate::health_checker::dns::Dns;
+use crate::health_checker::elasticsearch::Elasticsearch;
+use crate::health_checker::ftp::Ftp;
+use crate::health_checker::grpc::Grpc;
 use crate::health_checker::http::Http;
+use crate::health_checker::imap::Imap;
+use crate::health_checker::kafka::Kafka;
+use crate::health_checker::ldap::Ldap;
+use crate::health_checker::memcached::Memcached;
+use crate::health_checker::mongodb::Mongodb;
+use crate::health_checker::nats::Nats;
+use crate::health_checker::mqtt::Mqtt;
+use crate::health_checker::mysql::Mysql;
+use crate::health_checker::pop3::Pop3;
+use crate::health_checker::postgres::Postgres;
 use crate::health_checker::redis::Redis;
+use crate::health_checker::redis_cluster::RedisCluster;
+use crate::health_checker::redis_sentinel::RedisSentinel;
+use crate::health_checker::smtp::Smtp;
 
 pub(crate) mod http;
 
 pub(crate) mod redis;
 
+pub(crate) mod redis_sentinel;
+
+pub(crate) mod redis_cluster;
+
+pub(crate) mod postgres;
+
+pub(crate) mod mysql;
+
+pub(crate) mod mongodb;
+
+pub(crate) mod memcached;
+
+pub(crate) mod amqp;
+
+pub(crate) mod kafka;
+
+pub(crate) mod nats;
+
+pub(crate) mod mqtt;
+
+pub(crate) mod grpc;
+
+pub(crate) mod elasticsearch;
+
+pub(crate) mod smtp;
+
+pub(crate) mod imap;
+
+pub(crate) mod pop3;
+
+pub(crate) mod ftp;
+
+pub(crate) mod dns;
+
+pub(crate) mod ldap;
+
+pub(crate) mod cql;
+
+pub(crate) mod clickhouse;
+
+mod sql;
+
+mod tls;
+
+mod wire;
+
+#[cfg(test)]
+pub(crate) mod cassandra_container;
+
+#[cfg(test)]
+pub(crate) mod clickhouse_container;
+
+#[cfg(test)]
+pub(crate) mod dovecot_container;
+
+#[cfg(test)]
+pub(crate) mod mailpit_container;
+
+#[cfg(test)]
+pub(crate) mod openldap_container;
+
+#[cfg(test)]
+pub(crate) mod memcached_container;
+
+#[cfg(test)]
+pub(crate) mod pure_ftpd_container;
+
+#[cfg(test)]
+pub(crate) mod coredns_container;
+
+#[cfg(test)]
+pub(crate) mod etcd_container;
+
+#[cfg(test)]
+pub(crate) mod redis_sentinel_container;
+
 #[cfg(test)]
 pub(crate) mod toxiproxy;
 
@@ -45,6 +141,18 @@ trait HealthCheck {
     async fn get_health(&self, configuration: &Configuration) -> Result<State, NetworkError>;
 }
 
+/// Returns a token unique to this process and instant, short enough to fit in the keys, channels and client
+/// identifiers the checks create.
+fn unique_token() -> String {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos() as u64;
+
+    // 15 hexadecimal digits, as MQTT client identifiers up to 23 characters are accepted by every broker
+    format!("{:015x}", (nanos ^ ((std::process::id() as u64) << 40)) & 0xFFF_FFFF_FFFF_FFFF)
+}
+
 pub async fn run_health_check() -> Result<State, HeathcheckFailure> {
     let vars: HashMap<String, String> = env::vars().collect();
 
@@ -58,6 +166,26 @@ pub async fn run_health_check() -> Result<State, HeathcheckFailure> {
     let checker: Box<dyn HealthCheck> = match configuration.protocol {
         Protocol::Http => Box::new(Http),
         Protocol::Redis => Box::new(Redis),
+        Protocol::RedisSentinel => Box::new(RedisSentinel),
+        Protocol::RedisCluster => Box::new(RedisCluster),
+        Protocol::Postgres => Box::new(Postgres),
+        Protocol::Mysql => Box::new(Mysql),
+        Protocol::Mongodb => Box::new(Mongodb),
+        Protocol::Memcached => Box::new(Memcached),
+        Protocol::Amqp => Box::new(Amqp),
+        Protocol::Kafka => Box::new(Kafka),
+        Protocol::Nats => Box::new(Nats),
+        Protocol::Mqtt => Box::new(Mqtt),
+        Protocol::Grpc => Box::new(Grpc),
+        Protocol::Elasticsearch => Box::new(Elasticsearch),
+        Protocol::Smtp => Box::new(Smtp),
+        Protocol::Imap => Box::new(Imap),
+        Protocol::Pop3 => Box::new(Pop3),
+        Protocol::Ftp => Box::new(Ftp),
+        Protocol::Dns => Box::new(Dns),
+        Protocol::Ldap => Box::new(Ldap),
+        Protocol::Cql => Box::new(Cql),
+        Protocol::Clickhouse => Box::new(Clickhouse),
     };
 
     checker.get_health(&configuration).await.map_err(|err| {
@@ -0,0 +1,329 @@
+use async_trait::async_trait;
+use log::debug;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use crate::configuration::Configuration;
+use crate::health_checker::Reason::Other;
+use crate::health_checker::wire::{malformed, network_error, run_with_timeout, Reader};
+use crate::health_checker::{HealthCheck, NetworkError, State};
+
+#[cfg(test)]
+#[path = "./amqp_test.rs"]
+mod test;
+
+const DEFAULT_USERNAME: &str = "guest";
+
+const DEFAULT_PASSWORD: &str = "guest";
+
+const PROTOCOL_HEADER: &[u8] = b"AMQP\x00\x00\x09\x01";
+
+const FRAME_METHOD: u8 = 1;
+const FRAME_HEADER: u8 = 2;
+
+const FRAME_END: u8 = 0xCE;
+
+const FRAME_MAX_SIZE: usize = 1 << 20;
+
+const CONNECTION_CLASS: u16 = 10;
+const CHANNEL_CLASS: u16 = 20;
+const BASIC_CLASS: u16 = 60;
+
+const CONNECTION_CHANNEL: u16 = 0;
+const PUBLISH_CHANNEL: u16 = 1;
+
+const START: u16 = 10;
+const START_OK: u16 = 11;
+const TUNE: u16 = 30;
+const TUNE_OK: u16 = 31;
+const OPEN: u16 = 40;
+const OPEN_OK: u16 = 41;
+const CLOSE: u16 = 50;
+const CLOSE_OK: u16 = 51;
+const BLOCKED: u16 = 60;
+
+const CHANNEL_OPEN: u16 = 10;
+const CHANNEL_OPEN_OK: u16 = 11;
+const CHANNEL_CLOSE: u16 = 40;
+const CHANNEL_CLOSE_OK: u16 = 41;
+
+const PUBLISH: u16 = 40;
+
+const REPLY_SUCCESS: u16 = 200;
+
+pub(crate) struct Amqp;
+
+#[async_trait]
+impl HealthCheck for Amqp {
+    async fn get_health(&self, configuration: &Configuration) -> Result<State, NetworkError> {
+        run_with_timeout(configuration, handshake(configuration)).await
+    }
+}
+
+struct Method {
+    class: u16,
+    id: u16,
+    arguments: Vec<u8>,
+}
+
+async fn handshake(configuration: &Configuration) -> Result<State, Result<State, NetworkError>> {
+    let port: u16 = configuration.port.into();
+    let username = configuration.username.clone()
+        .map(String::from)
+        .unwrap_or_else(|| DEFAULT_USERNAME.to_string());
+    let password = configuration.password.clone()
+        .map(String::from)
+        .unwrap_or_else(|| DEFAULT_PASSWORD.to_string());
+    let vhost = String::from(configuration.amqp_vhost.clone());
+
+    let mut stream = TcpStream::connect(("localhost", port)).await
+        .map_err(network_error)?;
+
+    stream.write_all(PROTOCOL_HEADER).await
+        .map_err(network_error)?;
+
+    let start = expect(&mut stream, CONNECTION_CLASS, START).await?;
+    let mechanisms = start_mechanisms(&start.arguments)?;
+
+    if !mechanisms.split(' ').any(|mechanism| mechanism == "PLAIN") {
+        return Err(Ok(State::Unhealthy(Other(format!("unsupported authentication mechanisms '{}'", mechanisms)))));
+    }
+
+    debug!("authenticating as '{}'", username);
+
+    send(&mut stream, CONNECTION_CHANNEL, CONNECTION_CLASS, START_OK, &start_ok_arguments(&username, &password)).await?;
+
+    let tune = expect(&mut stream, CONNECTION_CLASS, TUNE).await?;
+    let mut arguments = Reader::new(&tune.arguments, "frame");
+    let channel_max = arguments.u16()?;
+    let frame_max = arguments.u32()?;
+
+    let mut tune_ok = Vec::new();
+    tune_ok.extend_from_slice(&channel_max.to_be_bytes());
+    tune_ok.extend_from_slice(&frame_max.to_be_bytes());
+    tune_ok.extend_from_slice(&0u16.to_be_bytes());
+
+    send(&mut stream, CONNECTION_CHANNEL, CONNECTION_CLASS, TUNE_OK, &tune_ok).await?;
+
+    debug!("opening vhost '{}'", vhost);
+
+    let mut open = Vec::new();
+    short_string(&mut open, &vhost);
+    short_string(&mut open, "");
+    open.push(0);
+
+    send(&mut stream, CONNECTION_CHANNEL, CONNECTION_CLASS, OPEN, &open).await?;
+
+    expect(&mut stream, CONNECTION_CLASS, OPEN_OK).await?;
+
+    publish(&mut stream).await?;
+
+    send(&mut stream, CONNECTION_CHANNEL, CONNECTION_CLASS, CLOSE, &close_arguments()).await?;
+
+    expect(&mut stream, CONNECTION_CLASS, CLOSE_OK).await?;
+
+    Ok(State::Healthy)
+}
+
+/// Publishes an empty message nobody receives, the broker blocking only the connections that publish while a resource
+/// alarm is raised, then closes the channel to wait for the broker to have handled the message.
+async fn publish(stream: &mut TcpStream) -> Result<(), Result<State, NetworkError>> {
+    let mut open = Vec::new();
+    short_string(&mut open, "");
+
+    send(stream, PUBLISH_CHANNEL, CHANNEL_CLASS, CHANNEL_OPEN, &open).await?;
+
+    expect(stream, CHANNEL_CLASS, CHANNEL_OPEN_OK).await?;
+
+    // the default exchange routes an empty routing key to no queue, so the message is dropped
+    let mut publish = Vec::new();
+    publish.extend_from_slice(&0u16.to_be_bytes());
+    short_string(&mut publish, "");
+    short_string(&mut publish, "");
+    publish.push(0);
+
+    let mut header = Vec::new();
+    header.extend_from_slice(&BASIC_CLASS.to_be_bytes());
+    header.extend_from_slice(&0u16.to_be_bytes());
+    header.extend_from_slice(&0u64.to_be_bytes());
+    header.extend_from_slice(&0u16.to_be_bytes());
+
+    let frames = [
+        method_frame(PUBLISH_CHANNEL, BASIC_CLASS, PUBLISH, &publish),
+        frame(FRAME_HEADER, PUBLISH_CHANNEL, &header),
+        method_frame(PUBLISH_CHANNEL, CHANNEL_CLASS, CHANNEL_CLOSE, &close_arguments()),
+    ].concat();
+
+    stream.write_all(&frames).await
+        .map_err(network_error)?;
+
+    expect(stream, CHANNEL_CLASS, CHANNEL_CLOSE_OK).await?;
+
+    Ok(())
+}
+
+/// Reads the next method, which must be the expected one, reporting the refusals and blocking sent by the broker.
+async fn expect(stream: &mut TcpStream, class: u16, expected: u16) -> Result<Method, Result<State, NetworkError>> {
+    let method = read_method(stream).await?;
+
+    match (method.class, method.id) {
+        (method_class, id) if method_class == class && id == expected => Ok(method),
+        (CONNECTION_CLASS, CLOSE) => {
+            let mut arguments = Reader::new(&method.arguments, "frame");
+            let code = arguments.u16()?;
+            let text = read_short_string(&mut arguments)?;
+
+            if let Err(e) = send(stream, CONNECTION_CHANNEL, CONNECTION_CLASS, CLOSE_OK, &[]).await {
+                debug!("close acknowledgement failed: {:?}", e);
+            }
+
+            Err(Ok(State::Unhealthy(Other(format!("connection refused '{} {}'", code, text)))))
+        }
+        (CONNECTION_CLASS, BLOCKED) => {
+            let reason = read_short_string(&mut Reader::new(&method.arguments, "frame"))?;
+            Err(Ok(State::Unhealthy(Other(format!("connection blocked '{}'", reason)))))
+        }
+        (CHANNEL_CLASS, CHANNEL_CLOSE) => {
+            let mut arguments = Reader::new(&method.arguments, "frame");
+            let code = arguments.u16()?;
+            let text = read_short_string(&mut arguments)?;
+            Err(Ok(State::Unhealthy(Other(format!("channel refused '{} {}'", code, text)))))
+        }
+        (class, id) => Err(Ok(State::Unhealthy(Other(format!("unexpected method '{}.{}'", class, id))))),
+    }
+}
+
+async fn read_method(stream: &mut TcpStream) -> Result<Method, Result<State, NetworkError>> {
+    loop {
+        let mut header = [0u8; 7];
+        stream.read_exact(&mut header).await
+            .map_err(network_error)?;
+
+        if header.starts_with(b"AMQP") {
+            let mut revision = [0u8; 1];
+            stream.read_exact(&mut revision).await
+                .map_err(network_error)?;
+
+            return Err(Ok(State::Unhealthy(Other(format!(
+                "incompatible protocol '{}.{}.{}'",
+                header[5], header[6], revision[0],
+            )))));
+        }
+
+        let size = u32::from_be_bytes([header[3], header[4], header[5], header[6]]) as usize;
+
+        if size > FRAME_MAX_SIZE {
+            return Err(malformed("frame"));
+        }
+
+        let mut payload = vec![0u8; size + 1];
+        stream.read_exact(&mut payload).await
+            .map_err(network_error)?;
+
+        if payload.pop() != Some(FRAME_END) {
+            return Err(malformed("frame"));
+        }
+
+        if header[0] != FRAME_METHOD {
+            continue;
+        }
+
+        let mut arguments = Reader::new(&payload, "frame");
+        let class = arguments.u16()?;
+        let id = arguments.u16()?;
+
+        return Ok(Method { class, id, arguments: arguments.remaining().to_vec() });
+    }
+}
+
+async fn send(stream: &mut TcpStream, channel: u16, class: u16, id: u16, arguments: &[u8]) -> Result<(), Result<State, NetworkError>> {
+    stream.write_all(&method_frame(channel, class, id, arguments)).await
+        .map_err(network_error)
+}
+
+fn method_frame(channel: u16, class: u16, id: u16, arguments: &[u8]) -> Vec<u8> {
+    let mut payload = Vec::new();
+    payload.extend_from_slice(&class.to_be_bytes());
+    payload.extend_from_slice(&id.to_be_bytes());
+    payload.extend_from_slice(arguments);
+    frame(FRAME_METHOD, channel, &payload)
+}
+
+fn frame(kind: u8, channel: u16, payload: &[u8]) -> Vec<u8> {
+    let mut frame = vec![kind];
+    frame.extend_from_slice(&channel.to_be_bytes());
+    frame.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+    frame.extend_from_slice(payload);
+    frame.push(FRAME_END);
+    frame
+}
+
+fn start_mechanisms(arguments: &[u8]) -> Result<String, Result<State, NetworkError>> {
+    let mut arguments = Reader::new(arguments, "frame");
+    let major = arguments.u8()?;
+    let minor = arguments.u8()?;
+    read_long_string(&mut arguments)?;
+    let mechanisms = read_long_string(&mut arguments)?;
+
+    debug!("broker speaks AMQP {}-{}", major, minor);
+
+    Ok(mechanisms)
+}
+
+fn start_ok_arguments(username: &str, password: &str) -> Vec<u8> {
+    let mut capabilities = Vec::new();
+    table_boolean(&mut capabilities, "authentication_failure_close");
+    table_boolean(&mut capabilities, "connection.blocked");
+
+    let mut properties = Vec::new();
+    short_string(&mut properties, "product");
+    properties.push(b'S');
+    long_string(&mut properties, b"dockteur");
+    short_string(&mut properties, "capabilities");
+    properties.push(b'F');
+    long_string(&mut properties, &capabilities);
+
+    let response = format!("\0{}\0{}", username, password);
+
+    let mut arguments = Vec::new();
+    long_string(&mut arguments, &properties);
+    short_string(&mut arguments, "PLAIN");
+    long_string(&mut arguments, response.as_bytes());
+    short_string(&mut arguments, "en_US");
+    arguments
+}
+
+fn close_arguments() -> Vec<u8> {
+    let mut arguments = Vec::new();
+    arguments.extend_from_slice(&REPLY_SUCCESS.to_be_bytes());
+    short_string(&mut arguments, "Goodbye");
+    arguments.extend_from_slice(&0u16.to_be_bytes());
+    arguments.extend_from_slice(&0u16.to_be_bytes());
+    arguments
+}
+
+fn table_boolean(table: &mut Vec<u8>, name: &str) {
+    short_string(table, name);
+    table.push(b't');
+    table.push(1);
+}
+
+fn short_string(buffer: &mut Vec<u8>, value: &str) {
+    let bytes = &value.as_bytes()[..value.len().min(u8::MAX as usize)];
+    buffer.push(bytes.len() as u8);
+    buffer.extend_from_slice(bytes);
+}
+
+fn long_string(buffer: &mut Vec<u8>, value: &[u8]) {
+    buffer.extend_from_slice(&(value.len() as u32).to_be_bytes());
+    buffer.extend_from_slice(value);
+}
+
+fn read_short_string(arguments: &mut Reader) -> Result<String, Result<State, NetworkError>> {
+    let length = arguments.u8()? as usize;
+    arguments.string(length)
+}
+
+fn read_long_string(arguments: &mut Reader) -> Result<String, Result<State, NetworkError>> {
+    let length = arguments.u32()? as usize;
+    arguments.string(length)
+}
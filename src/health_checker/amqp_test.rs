@@ -0,0 +1,224 @@
+use crate::health_checker::Reason::Other;
+use crate::health_checker::State::Healthy;
+use crate::health_checker::State::Unhealthy;
+use assert2::{check, assert};
+use std::net::TcpListener;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use testcontainers_modules::rabbitmq::RabbitMq as RabbitMqContainer;
+use testcontainers_modules::testcontainers::core::{CmdWaitFor, ExecCommand};
+use testcontainers_modules::testcontainers::runners::AsyncRunner;
+use crate::configuration::fixtures::{an_amqp_configuration, an_amqp_configuration_with_credentials};
+use crate::health_checker::amqp::{long_string, method_frame, short_string, Amqp, BLOCKED, CHANNEL_CLASS, CHANNEL_CLOSE_OK, CHANNEL_OPEN_OK, CLOSE, CLOSE_OK, CONNECTION_CLASS, OPEN_OK, START, TUNE};
+use crate::health_checker::HealthCheck;
+
+const AMQP_PORT: u16 = 5672;
+
+#[tokio::test]
+async fn a_healthy_broker_should_be_reported() {
+    let rabbitmq_container = RabbitMqContainer::default()
+        .start()
+        .await
+        .unwrap();
+
+    let port = rabbitmq_container.get_host_port_ipv4(AMQP_PORT).await.unwrap();
+    let configuration = an_amqp_configuration(port, "/");
+
+    let result = Amqp.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[tokio::test]
+async fn an_unknown_vhost_should_be_reported_as_unhealthy() {
+    let rabbitmq_container = RabbitMqContainer::default()
+        .start()
+        .await
+        .unwrap();
+
+    let port = rabbitmq_container.get_host_port_ipv4(AMQP_PORT).await.unwrap();
+    let configuration = an_amqp_configuration(port, "unknown");
+
+    let result = Amqp.get_health(&configuration).await;
+
+    assert!(let Ok(Unhealthy(Other(reason))) = result);
+    check!(reason.starts_with("connection refused '530 NOT_ALLOWED"));
+}
+
+#[tokio::test]
+async fn a_failed_authentication_should_be_reported_as_unhealthy() {
+    let rabbitmq_container = RabbitMqContainer::default()
+        .start()
+        .await
+        .unwrap();
+
+    let port = rabbitmq_container.get_host_port_ipv4(AMQP_PORT).await.unwrap();
+    let configuration = an_amqp_configuration_with_credentials(port, "guest", "wrong");
+
+    let result = Amqp.get_health(&configuration).await;
+
+    assert!(let Ok(Unhealthy(Other(reason))) = result);
+    check!(reason.starts_with("connection refused '403 ACCESS_REFUSED"));
+}
+
+#[tokio::test]
+async fn a_broker_under_a_resource_alarm_should_be_reported_as_unhealthy() {
+    let rabbitmq_container = RabbitMqContainer::default()
+        .start()
+        .await
+        .unwrap();
+
+    // a watermark of zero raises the memory alarm straight away
+    rabbitmq_container
+        .exec(ExecCommand::new(["rabbitmqctl", "set_vm_memory_high_watermark", "0"]).with_cmd_ready_condition(CmdWaitFor::exit_code(0)))
+        .await
+        .unwrap();
+
+    let port = rabbitmq_container.get_host_port_ipv4(AMQP_PORT).await.unwrap();
+    let configuration = an_amqp_configuration(port, "/");
+
+    let result = Amqp.get_health(&configuration).await;
+
+    assert!(let Ok(Unhealthy(Other(reason))) = result);
+    check!(reason.starts_with("connection blocked"));
+}
+
+#[tokio::test]
+async fn a_broker_accepting_the_publication_should_be_reported_as_healthy() {
+    let port = a_broker_replying(vec![
+        a_start(),
+        a_tune(),
+        a_connection_method(OPEN_OK, &a_short_string("")),
+        a_channel_method(CHANNEL_OPEN_OK, &[0, 0, 0, 0]),
+        a_channel_method(CHANNEL_CLOSE_OK, &[]),
+        a_connection_method(CLOSE_OK, &[]),
+    ]).await;
+    let configuration = an_amqp_configuration(port, "/");
+
+    let result = Amqp.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[tokio::test]
+async fn a_blocked_connection_should_be_reported_as_unhealthy() {
+    let port = a_broker_replying(vec![
+        a_start(),
+        a_tune(),
+        a_connection_method(OPEN_OK, &a_short_string("")),
+        a_channel_method(CHANNEL_OPEN_OK, &[0, 0, 0, 0]),
+        a_connection_method(BLOCKED, &a_short_string("low on memory")),
+    ]).await;
+    let configuration = an_amqp_configuration(port, "/");
+
+    let result = Amqp.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other(String::from("connection blocked 'low on memory'"))));
+}
+
+#[tokio::test]
+async fn a_closed_connection_should_be_reported_as_unhealthy() {
+    let mut close = Vec::new();
+    close.extend_from_slice(&320u16.to_be_bytes());
+    short_string(&mut close, "CONNECTION_FORCED - broker forced connection closure with reason 'shutdown'");
+    close.extend_from_slice(&[0, 0, 0, 0]);
+
+    let port = a_broker_replying(vec![a_connection_method(CLOSE, &close)]).await;
+    let configuration = an_amqp_configuration(port, "/");
+
+    let result = Amqp.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other(String::from(
+        "connection refused '320 CONNECTION_FORCED - broker forced connection closure with reason 'shutdown''"
+    ))));
+}
+
+#[tokio::test]
+async fn an_unexpected_method_should_be_reported_as_unhealthy() {
+    let port = a_broker_replying(vec![a_start(), a_tune(), a_start()]).await;
+    let configuration = an_amqp_configuration(port, "/");
+
+    let result = Amqp.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other(String::from("unexpected method '10.10'"))));
+}
+
+#[tokio::test]
+async fn an_incompatible_protocol_should_be_reported_as_unhealthy() {
+    let port = a_broker_replying(vec![b"AMQP\x00\x00\x09\x00".to_vec()]).await;
+    let configuration = an_amqp_configuration(port, "/");
+
+    let result = Amqp.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other(String::from("incompatible protocol '0.9.0'"))));
+}
+
+#[tokio::test]
+async fn unreachable_broker_should_be_reported_as_error() {
+    let unused_port = TcpListener::bind("localhost:0").unwrap()
+        .local_addr().unwrap()
+        .port();
+    let configuration = an_amqp_configuration(unused_port, "/");
+
+    let result = Amqp.get_health(&configuration).await;
+
+    assert!(let Err(error) = result);
+    check!(error.message.starts_with("network error"));
+}
+
+fn a_start() -> Vec<u8> {
+    let mut arguments = vec![0, 9];
+    long_string(&mut arguments, &[]);
+    long_string(&mut arguments, b"AMQPLAIN PLAIN");
+    long_string(&mut arguments, b"en_US");
+    a_connection_method(START, &arguments)
+}
+
+fn a_tune() -> Vec<u8> {
+    let mut arguments = Vec::new();
+    arguments.extend_from_slice(&2047u16.to_be_bytes());
+    arguments.extend_from_slice(&131072u32.to_be_bytes());
+    arguments.extend_from_slice(&60u16.to_be_bytes());
+    a_connection_method(TUNE, &arguments)
+}
+
+fn a_short_string(value: &str) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    short_string(&mut bytes, value);
+    bytes
+}
+
+fn a_connection_method(id: u16, arguments: &[u8]) -> Vec<u8> {
+    method_frame(0, CONNECTION_CLASS, id, arguments)
+}
+
+fn a_channel_method(id: u16, arguments: &[u8]) -> Vec<u8> {
+    method_frame(1, CHANNEL_CLASS, id, arguments)
+}
+
+/// Starts a broker sending the given frames after the protocol header, while ignoring what the client sends.
+async fn a_broker_replying(frames: Vec<Vec<u8>>) -> u16 {
+    let listener = tokio::net::TcpListener::bind("localhost:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+
+        let mut header = [0u8; 8];
+        stream.read_exact(&mut header).await.unwrap();
+
+        for frame in frames {
+            stream.write_all(&frame).await.unwrap();
+        }
+
+        let mut ignored = Vec::new();
+        let _ = stream.read_to_end(&mut ignored).await;
+    });
+
+    port
+}
@@ -0,0 +1,36 @@
+use std::borrow::Cow;
+use testcontainers_modules::testcontainers::core::wait::LogWaitStrategy;
+use testcontainers_modules::testcontainers::core::ContainerPort::Tcp;
+use testcontainers_modules::testcontainers::core::{ContainerPort, WaitFor};
+use testcontainers_modules::testcontainers::Image;
+
+pub const CQL_PORT: u16 = 9042;
+
+#[derive(Default)]
+pub struct CassandraContainer;
+
+impl Image for CassandraContainer {
+    fn name(&self) -> &str {
+        "cassandra"
+    }
+
+    fn tag(&self) -> &str {
+        "5.0"
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::log(LogWaitStrategy::stdout("Starting listening for CQL clients"))]
+    }
+
+    fn env_vars(&self) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        // a single node starts faster with a smaller heap
+        [
+            ("MAX_HEAP_SIZE", "512M"),
+            ("HEAP_NEWSIZE", "128M"),
+        ]
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[Tcp(CQL_PORT)]
+    }
+}
@@ -0,0 +1,242 @@
+use async_trait::async_trait;
+use log::debug;
+use reqwest::{RequestBuilder, StatusCode};
+use tokio::io::{AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use crate::configuration::{ClickhouseInterface, Configuration};
+use crate::health_checker::http::{client, failure, url};
+use crate::health_checker::Reason::Other;
+use crate::health_checker::wire::{malformed, network_error, run_with_timeout};
+use crate::health_checker::{HealthCheck, NetworkError, State};
+
+#[cfg(test)]
+#[path = "./clickhouse_test.rs"]
+mod test;
+
+const QUERY: &str = "SELECT 1";
+
+// exceptions hold the whole context of the failure, sometimes with a stack trace
+const EXCEPTION_MAX_LENGTH: usize = 200;
+
+const DEFAULT_USER: &str = "default";
+
+const CLIENT_NAME: &str = "dockteur";
+
+// this revision receives the server timezone, newer ones add fields and handshake steps
+const CLIENT_REVISION: u64 = 54213;
+const REVISION_WITH_SERVER_TIMEZONE: u64 = 54058;
+
+const STRING_MAX_SIZE: u64 = 1024 * 1024;
+
+const HELLO: u64 = 0;
+const EXCEPTION: u64 = 2;
+const PING: u64 = 4;
+const PONG: u64 = 4;
+
+pub(crate) struct Clickhouse;
+
+#[async_trait]
+impl HealthCheck for Clickhouse {
+    async fn get_health(&self, configuration: &Configuration) -> Result<State, NetworkError> {
+        let check = async {
+            match configuration.clickhouse_interface {
+                ClickhouseInterface::Http => check_http(configuration).await,
+                ClickhouseInterface::Native => check_native(configuration).await,
+            }
+        };
+
+        run_with_timeout(configuration, check).await
+    }
+}
+
+async fn check_http(configuration: &Configuration) -> Result<State, Result<State, NetworkError>> {
+    let body = send(configuration, client(configuration).get(url(configuration, "/ping").as_ref())).await?;
+
+    if body.trim_end() != "Ok." {
+        return Ok(State::Unhealthy(Other(format!("unexpected response '{}'", truncated(body.trim_end())))));
+    }
+
+    if !configuration.clickhouse_query {
+        return Ok(State::Healthy);
+    }
+
+    let mut request = client(configuration)
+        .post(url(configuration, "/").as_ref())
+        .body(QUERY);
+
+    if let Some(username) = &configuration.username {
+        request = request
+            .header("X-ClickHouse-User", String::from(username.clone()))
+            .header("X-ClickHouse-Key", configuration.password.clone().map(String::from).unwrap_or_default());
+    }
+
+    if let Some(database) = &configuration.database {
+        request = request.header("X-ClickHouse-Database", String::from(database.clone()));
+    }
+
+    let body = send(configuration, request).await?;
+
+    if body.trim_end() != "1" {
+        return Ok(State::Unhealthy(Other(format!("unexpected response '{}'", truncated(body.trim_end())))));
+    }
+
+    Ok(State::Healthy)
+}
+
+/// Sends a request and returns the body of its successful response, the failed ones holding the server exception.
+async fn send(configuration: &Configuration, request: RequestBuilder) -> Result<String, Result<State, NetworkError>> {
+    let response = request.send().await
+        .map_err(|e| failure(configuration, e))?;
+
+    let status = response.status();
+
+    let body = response.text().await
+        .map_err(|e| failure(configuration, e))?;
+
+    debug!("received status {}", status);
+
+    match (status, body.trim()) {
+        (StatusCode::OK, _) => Ok(body),
+        (status, "") => Err(Ok(State::Unhealthy(Other(format!("unexpected status code '{}'", status))))),
+        (_, message) => Err(Ok(exception(message))),
+    }
+}
+
+async fn check_native(configuration: &Configuration) -> Result<State, Result<State, NetworkError>> {
+    let port: u16 = configuration.port.into();
+
+    let stream = TcpStream::connect(("localhost", port)).await
+        .map_err(network_error)?;
+
+    let mut connection = Connection { stream: BufReader::new(stream) };
+
+    let username = configuration.username.clone()
+        .map(String::from)
+        .unwrap_or_else(|| DEFAULT_USER.to_string());
+    let password = configuration.password.clone()
+        .map(String::from)
+        .unwrap_or_default();
+    let database = configuration.database.clone()
+        .map(String::from)
+        .unwrap_or_default();
+
+    debug!("saying hello as '{}'", username);
+
+    let mut hello = Vec::new();
+    write_varuint(&mut hello, HELLO);
+    write_string(&mut hello, CLIENT_NAME);
+    write_varuint(&mut hello, 1);
+    write_varuint(&mut hello, 0);
+    write_varuint(&mut hello, CLIENT_REVISION);
+    write_string(&mut hello, &database);
+    write_string(&mut hello, &username);
+    write_string(&mut hello, &password);
+
+    connection.send(&hello).await?;
+
+    match connection.read_varuint().await? {
+        HELLO => {
+            let name = connection.read_string().await?;
+            let major = connection.read_varuint().await?;
+            let minor = connection.read_varuint().await?;
+            let revision = connection.read_varuint().await?;
+
+            if revision.min(CLIENT_REVISION) >= REVISION_WITH_SERVER_TIMEZONE {
+                connection.read_string().await?;
+            }
+
+            debug!("server {} {}.{} revision {}", name, major, minor, revision);
+        }
+        EXCEPTION => return Ok(exception(&connection.read_exception().await?)),
+        packet => return Ok(State::Unhealthy(Other(format!("unexpected packet '{}'", packet)))),
+    }
+
+    let mut ping = Vec::new();
+    write_varuint(&mut ping, PING);
+
+    connection.send(&ping).await?;
+
+    match connection.read_varuint().await? {
+        PONG => Ok(State::Healthy),
+        EXCEPTION => Ok(exception(&connection.read_exception().await?)),
+        packet => Ok(State::Unhealthy(Other(format!("unexpected packet '{}'", packet)))),
+    }
+}
+
+struct Connection {
+    stream: BufReader<TcpStream>,
+}
+
+impl Connection {
+
+    async fn send(&mut self, packet: &[u8]) -> Result<(), Result<State, NetworkError>> {
+        self.stream.get_mut().write_all(packet).await
+            .map_err(network_error)
+    }
+
+    async fn read_varuint(&mut self) -> Result<u64, Result<State, NetworkError>> {
+        let mut value = 0;
+
+        for shift in (0..64).step_by(7) {
+            let byte = self.stream.read_u8().await
+                .map_err(network_error)?;
+
+            value |= ((byte & 0x7F) as u64) << shift;
+
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+
+        Err(malformed("response"))
+    }
+
+    async fn read_string(&mut self) -> Result<String, Result<State, NetworkError>> {
+        let length = self.read_varuint().await?;
+
+        if length > STRING_MAX_SIZE {
+            return Err(malformed("response"));
+        }
+
+        let mut bytes = vec![0u8; length as usize];
+        self.stream.read_exact(&mut bytes).await
+            .map_err(network_error)?;
+
+        Ok(String::from_utf8_lossy(&bytes).into_owned())
+    }
+
+    /// Reads the exception following its packet type, formatted the way the HTTP interface does.
+    async fn read_exception(&mut self) -> Result<String, Result<State, NetworkError>> {
+        let code = self.stream.read_i32_le().await
+            .map_err(network_error)?;
+        let name = self.read_string().await?;
+        let message = self.read_string().await?;
+
+        Ok(format!("Code: {}. {}: {}", code, name, message))
+    }
+}
+
+fn write_varuint(packet: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        packet.push((value as u8 & 0x7F) | 0x80);
+        value >>= 7;
+    }
+
+    packet.push(value as u8);
+}
+
+fn write_string(packet: &mut Vec<u8>, value: &str) {
+    write_varuint(packet, value.len() as u64);
+    packet.extend_from_slice(value.as_bytes());
+}
+
+fn exception(message: &str) -> State {
+    State::Unhealthy(Other(format!("server exception '{}'", truncated(message))))
+}
+
+fn truncated(message: &str) -> String {
+    match message.char_indices().nth(EXCEPTION_MAX_LENGTH) {
+        Some((end, _)) => format!("{}...", &message[..end]),
+        None => message.to_string(),
+    }
+}
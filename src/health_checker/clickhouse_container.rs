@@ -0,0 +1,46 @@
+use std::borrow::Cow;
+use std::time::Duration;
+use testcontainers_modules::testcontainers::core::ContainerPort::Tcp;
+use testcontainers_modules::testcontainers::core::{ContainerPort, WaitFor};
+use testcontainers_modules::testcontainers::{ContainerRequest, Healthcheck, Image, ImageExt};
+
+pub const HTTP_PORT: u16 = 8123;
+
+pub const NATIVE_PORT: u16 = 9000;
+
+pub const CLICKHOUSE_PASSWORD: &str = "secret";
+
+#[derive(Default)]
+pub struct ClickhouseContainer;
+
+impl Image for ClickhouseContainer {
+    fn name(&self) -> &str {
+        "clickhouse/clickhouse-server"
+    }
+
+    fn tag(&self) -> &str {
+        "24.8-alpine"
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::healthcheck()]
+    }
+
+    fn env_vars(&self) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        // the default user can only connect from the container itself when it has no password
+        [("CLICKHOUSE_PASSWORD", CLICKHOUSE_PASSWORD)]
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[Tcp(HTTP_PORT), Tcp(NATIVE_PORT)]
+    }
+}
+
+/// ClickHouse logs to files only, its readiness is checked by pinging it from the container instead.
+pub fn a_clickhouse_container() -> ContainerRequest<ClickhouseContainer> {
+    let ping = Healthcheck::cmd_shell(format!("wget -q -O - http://127.0.0.1:{}/ping", HTTP_PORT))
+        .with_interval(Duration::from_millis(500))
+        .with_retries(120);
+
+    ClickhouseContainer.with_health_check(ping)
+}
@@ -0,0 +1,223 @@
+use crate::configuration::ClickhouseInterface;
+use crate::health_checker::Reason::Other;
+use crate::health_checker::State::Healthy;
+use crate::health_checker::State::Unhealthy;
+use assert2::{check, assert};
+use rstest::rstest;
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use testcontainers_modules::testcontainers::runners::AsyncRunner;
+use wiremock::matchers::{body_string, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+use crate::configuration::fixtures::{a_clickhouse_configuration, a_clickhouse_configuration_with_credentials};
+use crate::health_checker::clickhouse::{truncated, write_string, write_varuint, Clickhouse, CLIENT_REVISION};
+use crate::health_checker::clickhouse_container::{a_clickhouse_container, CLICKHOUSE_PASSWORD, HTTP_PORT, NATIVE_PORT};
+use crate::health_checker::HealthCheck;
+
+const AUTHENTICATION_FAILED: &str = "Code: 516. DB::Exception: default: Authentication failed: password is incorrect, or there is no user with such name. (AUTHENTICATION_FAILED) (version 24.8.14.39 (official build))";
+
+#[rstest]
+#[case::http(ClickhouseInterface::Http, HTTP_PORT)]
+#[case::native(ClickhouseInterface::Native, NATIVE_PORT)]
+#[tokio::test]
+async fn a_healthy_server_should_be_reported(#[case] interface: ClickhouseInterface, #[case] container_port: u16) {
+    let clickhouse_container = a_clickhouse_container()
+        .start()
+        .await
+        .unwrap();
+
+    let port = clickhouse_container.get_host_port_ipv4(container_port).await.unwrap();
+    let configuration = a_clickhouse_configuration_with_credentials(port, interface, "default", CLICKHOUSE_PASSWORD);
+
+    let result = Clickhouse.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[tokio::test]
+async fn a_server_answering_ping_should_be_reported_as_healthy() {
+    let mock_server = MockServer::start().await;
+    mock_ping(&mock_server, 200, "Ok.\n").await;
+    let configuration = a_clickhouse_configuration(mock_server.address().port(), ClickhouseInterface::Http);
+
+    let result = Clickhouse.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[rstest]
+#[case::unexpected_body(200, "Not ready", "unexpected response 'Not ready'")]
+#[case::unavailable(503, "", "unexpected status code '503 Service Unavailable'")]
+#[case::exception(500, "Code: 241. DB::Exception: Memory limit (total) exceeded. (MEMORY_LIMIT_EXCEEDED)\n", "server exception 'Code: 241. DB::Exception: Memory limit (total) exceeded. (MEMORY_LIMIT_EXCEEDED)'")]
+#[tokio::test]
+async fn a_server_failing_ping_should_be_reported_as_unhealthy(#[case] status: u16, #[case] body: &str, #[case] reason: &str) {
+    let mock_server = MockServer::start().await;
+    mock_ping(&mock_server, status, body).await;
+    let configuration = a_clickhouse_configuration(mock_server.address().port(), ClickhouseInterface::Http);
+
+    let result = Clickhouse.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other(reason.to_string())));
+}
+
+#[tokio::test]
+async fn credentials_should_be_sent_with_the_query() {
+    let mock_server = MockServer::start().await;
+    mock_ping(&mock_server, 200, "Ok.\n").await;
+    Mock::given(method("POST"))
+        .and(path("/"))
+        .and(header("X-ClickHouse-User", "default"))
+        .and(header("X-ClickHouse-Key", "secret"))
+        .and(body_string("SELECT 1"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("1\n"))
+        .mount(&mock_server)
+        .await;
+    let configuration = a_clickhouse_configuration_with_credentials(mock_server.address().port(), ClickhouseInterface::Http, "default", "secret");
+
+    let result = Clickhouse.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[tokio::test]
+async fn a_failing_query_should_be_reported_as_unhealthy() {
+    let mock_server = MockServer::start().await;
+    mock_ping(&mock_server, 200, "Ok.\n").await;
+    Mock::given(method("POST"))
+        .and(path("/"))
+        .respond_with(ResponseTemplate::new(516).set_body_string(AUTHENTICATION_FAILED))
+        .mount(&mock_server)
+        .await;
+    let configuration = a_clickhouse_configuration_with_credentials(mock_server.address().port(), ClickhouseInterface::Http, "default", "wrong");
+
+    let result = Clickhouse.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other(format!("server exception '{}'", truncated(AUTHENTICATION_FAILED)))));
+}
+
+#[tokio::test]
+async fn a_server_answering_hello_and_ping_should_be_reported_as_healthy() {
+    let (port, packets) = a_server_replying(vec![a_server_hello(), a_pong()]).await;
+    let configuration = a_clickhouse_configuration_with_credentials(port, ClickhouseInterface::Native, "default", "secret");
+
+    let result = Clickhouse.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+
+    let mut hello = Vec::new();
+    write_varuint(&mut hello, 0);
+    write_string(&mut hello, "dockteur");
+    write_varuint(&mut hello, 1);
+    write_varuint(&mut hello, 0);
+    write_varuint(&mut hello, CLIENT_REVISION);
+    write_string(&mut hello, "");
+    write_string(&mut hello, "default");
+    write_string(&mut hello, "secret");
+    check!(packets.lock().unwrap()[0] == hello);
+}
+
+#[rstest]
+#[case::authentication(vec![an_exception(516, "DB::Exception", "default: Authentication failed")], "server exception 'Code: 516. DB::Exception: default: Authentication failed'")]
+#[case::ping(vec![a_server_hello(), an_exception(202, "DB::Exception", "Too many simultaneous queries")], "server exception 'Code: 202. DB::Exception: Too many simultaneous queries'")]
+#[case::not_clickhouse(vec![b"HTTP/1.1 400 Bad Request\r\n\r\n".to_vec()], "unexpected packet '72'")]
+#[tokio::test]
+async fn a_failing_native_server_should_be_reported_as_unhealthy(#[case] replies: Vec<Vec<u8>>, #[case] reason: &str) {
+    let (port, _) = a_server_replying(replies).await;
+    let configuration = a_clickhouse_configuration(port, ClickhouseInterface::Native);
+
+    let result = Clickhouse.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other(reason.to_string())));
+}
+
+#[rstest]
+#[case::http(ClickhouseInterface::Http)]
+#[case::native(ClickhouseInterface::Native)]
+#[tokio::test]
+async fn unreachable_server_should_be_reported_as_error(#[case] interface: ClickhouseInterface) {
+    let unused_port = TcpListener::bind("localhost:0").unwrap()
+        .local_addr().unwrap()
+        .port();
+    let configuration = a_clickhouse_configuration(unused_port, interface);
+
+    let result = Clickhouse.get_health(&configuration).await;
+
+    assert!(let Err(error) = result);
+    check!(error.message.starts_with("network error"));
+}
+
+#[test]
+fn long_exception_should_be_truncated() {
+    let message = "é".repeat(250);
+
+    let result = truncated(&message);
+
+    check!(result == format!("{}...", "é".repeat(200)));
+}
+
+async fn mock_ping(mock_server: &MockServer, status: u16, body: &str) {
+    Mock::given(method("GET"))
+        .and(path("/ping"))
+        .respond_with(ResponseTemplate::new(status).set_body_string(body))
+        .mount(mock_server)
+        .await;
+}
+
+fn a_server_hello() -> Vec<u8> {
+    let mut hello = Vec::new();
+    write_varuint(&mut hello, 0);
+    write_string(&mut hello, "ClickHouse");
+    write_varuint(&mut hello, 24);
+    write_varuint(&mut hello, 8);
+    write_varuint(&mut hello, 54472);
+    write_string(&mut hello, "UTC");
+    hello
+}
+
+fn a_pong() -> Vec<u8> {
+    vec![4]
+}
+
+fn an_exception(code: i32, name: &str, message: &str) -> Vec<u8> {
+    let mut exception = Vec::new();
+    write_varuint(&mut exception, 2);
+    exception.extend_from_slice(&code.to_le_bytes());
+    write_string(&mut exception, name);
+    write_string(&mut exception, message);
+    write_string(&mut exception, "");
+    exception.push(0);
+    exception
+}
+
+/// Starts a server sending each reply once a packet is received, recording the received packets.
+async fn a_server_replying(replies: Vec<Vec<u8>>) -> (u16, Arc<Mutex<Vec<Vec<u8>>>>) {
+    let listener = tokio::net::TcpListener::bind("localhost:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let packets = Arc::new(Mutex::new(Vec::new()));
+    let received = packets.clone();
+
+    tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut buffer = vec![0u8; 1024];
+
+        for reply in replies {
+            // the packets are small enough to arrive at once
+            match stream.read(&mut buffer).await {
+                Ok(0) | Err(_) => return,
+                Ok(length) => received.lock().unwrap().push(buffer[..length].to_vec()),
+            }
+
+            stream.write_all(&reply).await.unwrap();
+        }
+    });
+
+    (port, packets)
+}
@@ -0,0 +1,56 @@
+use std::borrow::Cow;
+use testcontainers_modules::testcontainers::core::wait::LogWaitStrategy;
+use testcontainers_modules::testcontainers::core::ContainerPort::{Tcp, Udp};
+use testcontainers_modules::testcontainers::core::{ContainerPort, WaitFor};
+use testcontainers_modules::testcontainers::{CopyDataSource, CopyToContainer, Image};
+
+pub const DNS_PORT: u16 = 53;
+
+pub const DNS_ADDRESS: &str = "10.0.0.1";
+
+// answers the name checked by the dns configurations with this address, and any other name with NXDOMAIN
+const COREFILE: &str = "
+.:53 {
+    hosts {
+        10.0.0.1 db.internal
+    }
+}
+";
+
+pub struct CorednsContainer {
+    copy_to_sources: Vec<CopyToContainer>,
+}
+
+impl Default for CorednsContainer {
+    fn default() -> Self {
+        CorednsContainer {
+            copy_to_sources: vec![CopyToContainer::new(CopyDataSource::Data(COREFILE.as_bytes().to_vec()), "/Corefile")],
+        }
+    }
+}
+
+impl Image for CorednsContainer {
+    fn name(&self) -> &str {
+        "coredns/coredns"
+    }
+
+    fn tag(&self) -> &str {
+        "1.11.3"
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::log(LogWaitStrategy::stdout("CoreDNS-"))]
+    }
+
+    fn cmd(&self) -> impl IntoIterator<Item = impl Into<Cow<'_, str>>> {
+        ["-conf", "/Corefile"]
+    }
+
+    fn copy_to_sources(&self) -> impl IntoIterator<Item = &CopyToContainer> {
+        &self.copy_to_sources
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[Tcp(DNS_PORT), Udp(DNS_PORT)]
+    }
+}
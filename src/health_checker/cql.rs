@@ -0,0 +1,211 @@
+use async_trait::async_trait;
+use log::debug;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use crate::configuration::Configuration;
+use crate::health_checker::Reason::Other;
+use crate::health_checker::wire::{malformed, network_error, run_with_timeout, Reader};
+use crate::health_checker::{HealthCheck, NetworkError, State};
+
+#[cfg(test)]
+#[path = "./cql_test.rs"]
+mod test;
+
+// the version 4 of the native protocol is supported by Cassandra 2.2 and later, and by ScyllaDB
+const PROTOCOL_VERSION: u8 = 0x04;
+const RESPONSE: u8 = 0x80;
+
+const HEADER_SIZE: usize = 9;
+const BODY_MAX_SIZE: usize = 16 * 1024 * 1024;
+
+const CQL_VERSION: &str = "3.0.0";
+
+const QUERY: &str = "SELECT now() FROM system.local";
+
+const CONSISTENCY_ONE: u16 = 0x0001;
+
+const ERROR: u8 = 0x00;
+const STARTUP: u8 = 0x01;
+const READY: u8 = 0x02;
+const AUTHENTICATE: u8 = 0x03;
+const OPTIONS: u8 = 0x05;
+const SUPPORTED: u8 = 0x06;
+const QUERY_REQUEST: u8 = 0x07;
+const RESULT: u8 = 0x08;
+const AUTH_RESPONSE: u8 = 0x0F;
+const AUTH_SUCCESS: u8 = 0x10;
+
+pub(crate) struct Cql;
+
+#[async_trait]
+impl HealthCheck for Cql {
+    async fn get_health(&self, configuration: &Configuration) -> Result<State, NetworkError> {
+        run_with_timeout(configuration, check(configuration)).await
+    }
+}
+
+struct Frame {
+    opcode: u8,
+    body: Vec<u8>,
+}
+
+struct Connection {
+    stream: TcpStream,
+    last_stream_id: i16,
+}
+
+impl Connection {
+
+    /// Sends a request on a new stream and reads its response.
+    async fn request(&mut self, opcode: u8, body: &[u8]) -> Result<Frame, Result<State, NetworkError>> {
+        self.last_stream_id += 1;
+
+        let mut frame = vec![PROTOCOL_VERSION, 0];
+        frame.extend_from_slice(&self.last_stream_id.to_be_bytes());
+        frame.push(opcode);
+        frame.extend_from_slice(&(body.len() as u32).to_be_bytes());
+        frame.extend_from_slice(body);
+
+        self.stream.write_all(&frame).await
+            .map_err(network_error)?;
+
+        let mut header = [0u8; HEADER_SIZE];
+        self.stream.read_exact(&mut header).await
+            .map_err(network_error)?;
+
+        let stream_id = i16::from_be_bytes([header[2], header[3]]);
+        let length = u32::from_be_bytes([header[5], header[6], header[7], header[8]]) as usize;
+
+        if header[0] & RESPONSE == 0 || stream_id != self.last_stream_id || length > BODY_MAX_SIZE {
+            return Err(malformed("response"));
+        }
+
+        let mut body = vec![0u8; length];
+        self.stream.read_exact(&mut body).await
+            .map_err(network_error)?;
+
+        Ok(Frame { opcode: header[4], body })
+    }
+}
+
+async fn check(configuration: &Configuration) -> Result<State, Result<State, NetworkError>> {
+    let port: u16 = configuration.port.into();
+
+    let stream = TcpStream::connect(("localhost", port)).await
+        .map_err(network_error)?;
+
+    let mut connection = Connection { stream, last_stream_id: 0 };
+
+    let response = connection.request(OPTIONS, &[]).await?;
+    expect(&response, SUPPORTED)?;
+
+    if !configuration.cql_query {
+        return Ok(State::Healthy);
+    }
+
+    let response = connection.request(STARTUP, &string_map(&[("CQL_VERSION", CQL_VERSION)])).await?;
+
+    if response.opcode == AUTHENTICATE {
+        let authenticator = read_string(&mut Reader::new(&response.body, "response"))?;
+
+        let username = match &configuration.username {
+            Some(username) => String::from(username.clone()),
+            None => return Ok(State::Unhealthy(Other(format!("authentication required by '{}'", authenticator)))),
+        };
+        let password = configuration.password.clone()
+            .map(String::from)
+            .unwrap_or_default();
+
+        debug!("authenticating as '{}' with {}", username, authenticator);
+
+        // the SASL PLAIN token accepted by the password authenticators
+        let token = format!("\0{}\0{}", username, password);
+
+        let response = connection.request(AUTH_RESPONSE, &bytes(token.as_bytes())).await?;
+
+        if response.opcode == ERROR {
+            return Ok(State::Unhealthy(Other(format!("authentication failed '{}'", describe_error(&response)?))));
+        }
+
+        expect(&response, AUTH_SUCCESS)?;
+    } else {
+        expect(&response, READY)?;
+    }
+
+    let mut query = long_string(QUERY);
+    query.extend_from_slice(&CONSISTENCY_ONE.to_be_bytes());
+    query.push(0);
+
+    let response = connection.request(QUERY_REQUEST, &query).await?;
+    expect(&response, RESULT)?;
+
+    Ok(State::Healthy)
+}
+
+fn expect(frame: &Frame, opcode: u8) -> Result<(), Result<State, NetworkError>> {
+    let reason = match frame.opcode {
+        code if code == opcode => return Ok(()),
+        ERROR => format!("error response '{}'", describe_error(frame)?),
+        code => format!("unexpected response opcode '0x{:02X}'", code),
+    };
+
+    Err(Ok(State::Unhealthy(Other(reason))))
+}
+
+fn describe_error(frame: &Frame) -> Result<String, Result<State, NetworkError>> {
+    let mut reader = Reader::new(&frame.body, "response");
+    let code = reader.u32()?;
+    let message = read_string(&mut reader)?;
+
+    let name = match code {
+        0x0000 => "Server error",
+        0x000A => "Protocol error",
+        0x0100 => "Authentication error",
+        0x1000 => "Unavailable exception",
+        0x1001 => "Overloaded",
+        0x1002 => "Is_bootstrapping",
+        0x1003 => "Truncate_error",
+        0x1100 => "Write_timeout",
+        0x1200 => "Read_timeout",
+        0x1300 => "Read_failure",
+        0x1400 => "Function_failure",
+        0x1500 => "Write_failure",
+        0x2000 => "Syntax_error",
+        0x2100 => "Unauthorized",
+        0x2200 => "Invalid",
+        0x2300 => "Config_error",
+        0x2400 => "Already_exists",
+        0x2500 => "Unprepared",
+        code => return Ok(format!("0x{:04X}: {}", code, message)),
+    };
+
+    Ok(format!("{}: {}", name, message))
+}
+
+fn string_map(entries: &[(&str, &str)]) -> Vec<u8> {
+    let mut map = (entries.len() as u16).to_be_bytes().to_vec();
+
+    for (key, value) in entries {
+        map.extend_from_slice(&string(key));
+        map.extend_from_slice(&string(value));
+    }
+
+    map
+}
+
+fn string(value: &str) -> Vec<u8> {
+    [(value.len() as u16).to_be_bytes().as_slice(), value.as_bytes()].concat()
+}
+
+fn long_string(value: &str) -> Vec<u8> {
+    bytes(value.as_bytes())
+}
+
+fn bytes(value: &[u8]) -> Vec<u8> {
+    [(value.len() as u32).to_be_bytes().as_slice(), value].concat()
+}
+
+fn read_string(reader: &mut Reader) -> Result<String, Result<State, NetworkError>> {
+    let length = reader.u16()? as usize;
+    reader.string(length)
+}
@@ -0,0 +1,190 @@
+use crate::health_checker::Reason::Other;
+use crate::health_checker::State::Healthy;
+use crate::health_checker::State::Unhealthy;
+use assert2::{check, assert};
+use rstest::rstest;
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use testcontainers_modules::testcontainers::runners::AsyncRunner;
+use crate::configuration::fixtures::{a_cql_configuration, a_cql_configuration_with_credentials};
+use crate::health_checker::cassandra_container::{CassandraContainer, CQL_PORT};
+use crate::health_checker::cql::{bytes, string, string_map, Cql, AUTHENTICATE, AUTH_RESPONSE, AUTH_SUCCESS, ERROR, OPTIONS, QUERY_REQUEST, READY, RESULT, STARTUP, SUPPORTED};
+use crate::health_checker::HealthCheck;
+
+const PASSWORD_AUTHENTICATOR: &str = "org.apache.cassandra.auth.PasswordAuthenticator";
+
+#[rstest]
+#[case::options(false)]
+#[case::query(true)]
+#[tokio::test]
+async fn a_healthy_server_should_be_reported(#[case] query: bool) {
+    let cassandra_container = CassandraContainer
+        .start()
+        .await
+        .unwrap();
+
+    let port = cassandra_container.get_host_port_ipv4(CQL_PORT).await.unwrap();
+    let configuration = a_cql_configuration(port, query);
+
+    let result = Cql.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[tokio::test]
+async fn a_server_supporting_options_should_be_reported_as_healthy() {
+    let (port, requests) = a_server_replying(vec![(SUPPORTED, a_supported_body())]).await;
+    let configuration = a_cql_configuration(port, false);
+
+    let result = Cql.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+    check!(*requests.lock().unwrap() == [(OPTIONS, vec![])]);
+}
+
+#[tokio::test]
+async fn a_server_answering_the_query_should_be_reported_as_healthy() {
+    let (port, requests) = a_server_replying(vec![
+        (SUPPORTED, a_supported_body()),
+        (READY, vec![]),
+        (RESULT, a_rows_body()),
+    ]).await;
+    let configuration = a_cql_configuration(port, true);
+
+    let result = Cql.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+
+    let requests = requests.lock().unwrap();
+    check!(requests.len() == 3);
+    check!(requests[1] == (STARTUP, string_map(&[("CQL_VERSION", "3.0.0")])));
+    check!(requests[2].0 == QUERY_REQUEST);
+}
+
+#[tokio::test]
+async fn credentials_should_be_used_to_authenticate() {
+    let (port, requests) = a_server_replying(vec![
+        (SUPPORTED, a_supported_body()),
+        (AUTHENTICATE, string(PASSWORD_AUTHENTICATOR)),
+        (AUTH_SUCCESS, bytes(&[])),
+        (RESULT, a_rows_body()),
+    ]).await;
+    let configuration = a_cql_configuration_with_credentials(port, "cassandra", "secret");
+
+    let result = Cql.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+    check!(requests.lock().unwrap()[2] == (AUTH_RESPONSE, bytes(b"\0cassandra\0secret")));
+}
+
+#[rstest]
+#[case::unsupported_version(vec![an_error(0x000A, "Invalid or unsupported protocol version (4)")], "error response 'Protocol error: Invalid or unsupported protocol version (4)'")]
+#[case::unexpected_opcode(vec![(READY, vec![])], "unexpected response opcode '0x02'")]
+#[case::bad_credentials(vec![(SUPPORTED, a_supported_body()), (AUTHENTICATE, string(PASSWORD_AUTHENTICATOR)), an_error(0x0100, "Provided username cassandra and/or password are incorrect")], "authentication failed 'Authentication error: Provided username cassandra and/or password are incorrect'")]
+#[case::bootstrapping(vec![(SUPPORTED, a_supported_body()), (AUTHENTICATE, string(PASSWORD_AUTHENTICATOR)), (AUTH_SUCCESS, bytes(&[])), an_error(0x1002, "Cannot read from a bootstrapping node")], "error response 'Is_bootstrapping: Cannot read from a bootstrapping node'")]
+#[case::unavailable(vec![(SUPPORTED, a_supported_body()), (READY, vec![]), an_error(0x1000, "Cannot achieve consistency level ONE")], "error response 'Unavailable exception: Cannot achieve consistency level ONE'")]
+#[case::unnamed(vec![(SUPPORTED, a_supported_body()), (READY, vec![]), an_error(0x9999, "custom")], "error response '0x9999: custom'")]
+#[tokio::test]
+async fn a_failing_server_should_be_reported_as_unhealthy(#[case] replies: Vec<(u8, Vec<u8>)>, #[case] reason: &str) {
+    let (port, _) = a_server_replying(replies).await;
+    let configuration = a_cql_configuration_with_credentials(port, "cassandra", "cassandra");
+
+    let result = Cql.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other(reason.to_string())));
+}
+
+#[tokio::test]
+async fn missing_credentials_should_be_reported_as_unhealthy() {
+    let (port, _) = a_server_replying(vec![
+        (SUPPORTED, a_supported_body()),
+        (AUTHENTICATE, string(PASSWORD_AUTHENTICATOR)),
+    ]).await;
+    let configuration = a_cql_configuration(port, true);
+
+    let result = Cql.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other(format!("authentication required by '{}'", PASSWORD_AUTHENTICATOR))));
+}
+
+#[tokio::test]
+async fn a_server_not_speaking_cql_should_be_reported_as_error() {
+    let listener = tokio::net::TcpListener::bind("localhost:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        stream.write_all(b"HTTP/1.1 400 Bad Request\r\n\r\n").await.unwrap();
+    });
+
+    let configuration = a_cql_configuration(port, false);
+
+    let result = Cql.get_health(&configuration).await;
+
+    assert!(let Err(error) = result);
+    check!(error.message == "network error: malformed response");
+}
+
+#[tokio::test]
+async fn unreachable_server_should_be_reported_as_error() {
+    let unused_port = TcpListener::bind("localhost:0").unwrap()
+        .local_addr().unwrap()
+        .port();
+    let configuration = a_cql_configuration(unused_port, false);
+
+    let result = Cql.get_health(&configuration).await;
+
+    assert!(let Err(error) = result);
+    check!(error.message.starts_with("network error"));
+}
+
+fn a_supported_body() -> Vec<u8> {
+    [vec![0, 1], string("CQL_VERSION"), vec![0, 1], string("3.4.7")].concat()
+}
+
+/// The rows of a result, with no metadata as the query is not checked.
+fn a_rows_body() -> Vec<u8> {
+    [2u32.to_be_bytes(), 0x0004u32.to_be_bytes(), 1u32.to_be_bytes(), 0u32.to_be_bytes()].concat()
+}
+
+fn an_error(code: u32, message: &str) -> (u8, Vec<u8>) {
+    (ERROR, [code.to_be_bytes().to_vec(), string(message)].concat())
+}
+
+/// Starts a server sending each reply on the stream of the request received, recording the opcode and body of the
+/// received requests.
+async fn a_server_replying(replies: Vec<(u8, Vec<u8>)>) -> (u16, Arc<Mutex<Vec<(u8, Vec<u8>)>>>) {
+    let listener = tokio::net::TcpListener::bind("localhost:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let received = requests.clone();
+
+    tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+
+        for (opcode, body) in replies {
+            let mut header = [0u8; 9];
+            if stream.read_exact(&mut header).await.is_err() {
+                return;
+            }
+
+            let mut request = vec![0u8; u32::from_be_bytes([header[5], header[6], header[7], header[8]]) as usize];
+            stream.read_exact(&mut request).await.unwrap();
+            received.lock().unwrap().push((header[4], request));
+
+            let mut response = vec![0x84, 0, header[2], header[3], opcode];
+            response.extend_from_slice(&(body.len() as u32).to_be_bytes());
+            response.extend_from_slice(&body);
+            stream.write_all(&response).await.unwrap();
+        }
+    });
+
+    (port, requests)
+}
@@ -0,0 +1,339 @@
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use async_trait::async_trait;
+use log::debug;
+use ring::rand::{SecureRandom, SystemRandom};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{lookup_host, TcpStream, UdpSocket};
+use crate::configuration::{Configuration, DnsRecordType, DnsTransport};
+use crate::health_checker::Reason::Other;
+use crate::health_checker::wire::{malformed, network_error, run_with_timeout, Reader};
+use crate::health_checker::{HealthCheck, NetworkError, State};
+
+#[cfg(test)]
+#[path = "./dns_test.rs"]
+mod test;
+
+const MESSAGE_MAX_SIZE: usize = u16::MAX as usize;
+
+const CLASS_IN: u16 = 1;
+
+const RESPONSE: u16 = 0x8000;
+const TRUNCATED: u16 = 0x0200;
+const RECURSION_DESIRED: u16 = 0x0100;
+
+// compressed names pointing to each other would loop forever
+const NAME_MAX_POINTERS: usize = 64;
+
+pub(crate) struct Dns;
+
+#[async_trait]
+impl HealthCheck for Dns {
+    async fn get_health(&self, configuration: &Configuration) -> Result<State, NetworkError> {
+        run_with_timeout(configuration, check(configuration)).await
+    }
+}
+
+struct Response {
+    flags: u16,
+    values: Vec<String>,
+}
+
+async fn check(configuration: &Configuration) -> Result<State, Result<State, NetworkError>> {
+    let port: u16 = configuration.port.into();
+    let name = String::from(configuration.dns_name.clone());
+    let record_type = configuration.dns_record_type;
+    let query = query(query_id()?, &name, type_code(record_type));
+
+    debug!("querying {} {} over {:?}", name, type_name(record_type), configuration.dns_transport);
+
+    let mut bytes = match configuration.dns_transport {
+        DnsTransport::Udp => exchange_udp(port, &query).await?,
+        DnsTransport::Tcp => exchange_tcp(port, &query).await?,
+    };
+
+    let mut response = parse_response(&bytes, &query, record_type)?;
+
+    if response.flags & TRUNCATED != 0 && configuration.dns_transport == DnsTransport::Udp {
+        debug!("truncated response, querying again over TCP");
+
+        bytes = exchange_tcp(port, &query).await?;
+        response = parse_response(&bytes, &query, record_type)?;
+    }
+
+    let rcode = response.flags & 0x000F;
+
+    if rcode != 0 {
+        return Ok(State::Unhealthy(Other(format!("error response '{}'", describe_rcode(rcode)))));
+    }
+
+    debug!("answer {:?}", response.values);
+
+    let expected = match &configuration.dns_expected {
+        Some(expected) => String::from(expected.clone()),
+        None => return Ok(State::Healthy),
+    };
+
+    Ok(check_values(&expected, &response.values, &name, record_type))
+}
+
+fn check_values(expected: &str, values: &[String], name: &str, record_type: DnsRecordType) -> State {
+    if values.iter().any(|value| normalize(value) == normalize(expected)) {
+        return State::Healthy;
+    }
+
+    if values.is_empty() {
+        return State::Unhealthy(Other(format!("no '{}' record for '{}'", type_name(record_type), name)));
+    }
+
+    State::Unhealthy(Other(format!("answer does not contain '{}', got '{}'", expected, values.join("', '"))))
+}
+
+/// Compares values regardless of their case and of the trailing dot of fully qualified names.
+fn normalize(value: &str) -> String {
+    value.split_whitespace()
+        .map(|field| field.strip_suffix('.').unwrap_or(field).to_lowercase())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+async fn exchange_udp(port: u16, query: &[u8]) -> Result<Vec<u8>, Result<State, NetworkError>> {
+    let addresses = lookup_host(("localhost", port)).await
+        .map_err(network_error)?;
+
+    let mut last_error = io::Error::new(io::ErrorKind::NotFound, "localhost has no address");
+
+    for address in addresses {
+        match exchange_udp_with(address, query).await {
+            Ok(response) => return Ok(response),
+            Err(e) => {
+                debug!("query to {} failed: {}", address, e);
+                last_error = e;
+            }
+        }
+    }
+
+    Err(network_error(last_error))
+}
+
+async fn exchange_udp_with(address: SocketAddr, query: &[u8]) -> io::Result<Vec<u8>> {
+    let local = match address {
+        SocketAddr::V4(_) => SocketAddr::new(IpAddr::V4(Ipv4Addr::UNSPECIFIED), 0),
+        SocketAddr::V6(_) => SocketAddr::new(IpAddr::V6(Ipv6Addr::UNSPECIFIED), 0),
+    };
+
+    let socket = UdpSocket::bind(local).await?;
+    socket.connect(address).await?;
+    socket.send(query).await?;
+
+    let mut buffer = vec![0u8; MESSAGE_MAX_SIZE];
+
+    loop {
+        let length = socket.recv(&mut buffer).await?;
+
+        // datagrams answering another query are ignored
+        if length >= 2 && buffer[..2] == query[..2] {
+            buffer.truncate(length);
+            return Ok(buffer);
+        }
+    }
+}
+
+async fn exchange_tcp(port: u16, query: &[u8]) -> Result<Vec<u8>, Result<State, NetworkError>> {
+    let mut stream = TcpStream::connect(("localhost", port)).await
+        .map_err(network_error)?;
+
+    let mut message = (query.len() as u16).to_be_bytes().to_vec();
+    message.extend_from_slice(query);
+
+    stream.write_all(&message).await
+        .map_err(network_error)?;
+
+    let mut length = [0u8; 2];
+    stream.read_exact(&mut length).await
+        .map_err(network_error)?;
+
+    let mut response = vec![0u8; u16::from_be_bytes(length) as usize];
+    stream.read_exact(&mut response).await
+        .map_err(network_error)?;
+
+    Ok(response)
+}
+
+fn query(id: u16, name: &str, record_type: u16) -> Vec<u8> {
+    let mut query = Vec::new();
+    query.extend_from_slice(&id.to_be_bytes());
+    query.extend_from_slice(&RECURSION_DESIRED.to_be_bytes());
+    query.extend_from_slice(&1u16.to_be_bytes());
+    query.extend_from_slice(&[0; 6]);
+
+    for label in name.split('.').filter(|label| !label.is_empty()) {
+        query.push(label.len() as u8);
+        query.extend_from_slice(label.as_bytes());
+    }
+
+    query.push(0);
+    query.extend_from_slice(&record_type.to_be_bytes());
+    query.extend_from_slice(&CLASS_IN.to_be_bytes());
+    query
+}
+
+/// Reads the header and the answers of a response, keeping the values of the records of the queried type.
+fn parse_response(bytes: &[u8], query: &[u8], record_type: DnsRecordType) -> Result<Response, Result<State, NetworkError>> {
+    let mut message = Reader::new(bytes, "response");
+
+    let id = message.u16()?;
+    let flags = message.u16()?;
+    let questions = message.u16()?;
+    let answers = message.u16()?;
+    message.take(4)?;
+
+    if id.to_be_bytes() != query[..2] || flags & RESPONSE == 0 {
+        return Err(malformed("response"));
+    }
+
+    for _ in 0..questions {
+        read_name(&mut message)?;
+        message.take(4)?;
+    }
+
+    let mut values = Vec::new();
+
+    for _ in 0..answers {
+        read_name(&mut message)?;
+        let answer_type = message.u16()?;
+        message.take(6)?;
+        let length = message.u16()? as usize;
+
+        if answer_type != type_code(record_type) {
+            message.take(length)?;
+            continue;
+        }
+
+        let end = message.offset() + length;
+        let mut data = Reader::new(bytes.get(..end).ok_or_else(|| malformed("response"))?, "response");
+        data.seek(message.offset());
+        values.push(record_value(&mut data, record_type)?);
+        message.seek(end);
+    }
+
+    Ok(Response { flags, values })
+}
+
+/// Formats the data of a record the way `dig` does.
+fn record_value(data: &mut Reader, record_type: DnsRecordType) -> Result<String, Result<State, NetworkError>> {
+    let value = match record_type {
+        DnsRecordType::A => {
+            Ipv4Addr::from(data.array::<4>()?).to_string()
+        }
+        DnsRecordType::Aaaa => {
+            Ipv6Addr::from(data.array::<16>()?).to_string()
+        }
+        DnsRecordType::Cname | DnsRecordType::Ns | DnsRecordType::Ptr => read_name(data)?,
+        DnsRecordType::Mx => format!("{} {}", data.u16()?, read_name(data)?),
+        DnsRecordType::Srv => format!("{} {} {} {}", data.u16()?, data.u16()?, data.u16()?, read_name(data)?),
+        DnsRecordType::Soa => format!(
+            "{} {} {} {} {} {} {}",
+            read_name(data)?, read_name(data)?, data.u32()?, data.u32()?, data.u32()?, data.u32()?, data.u32()?,
+        ),
+        DnsRecordType::Txt => {
+            let mut text = String::new();
+
+            while !data.is_empty() {
+                let length = data.u8()? as usize;
+                text.push_str(&data.string(length)?);
+            }
+
+            text
+        }
+    };
+
+    Ok(value)
+}
+
+/// Returns a random query id, so that a spoofed response is unlikely to match it.
+fn query_id() -> Result<u16, Result<State, NetworkError>> {
+    let mut id = [0u8; 2];
+
+    SystemRandom::new().fill(&mut id)
+        .map_err(|_| Err(NetworkError { message: String::from("network error: no random source") }))?;
+
+    Ok(u16::from_be_bytes(id))
+}
+
+fn type_code(record_type: DnsRecordType) -> u16 {
+    match record_type {
+        DnsRecordType::A => 1,
+        DnsRecordType::Ns => 2,
+        DnsRecordType::Cname => 5,
+        DnsRecordType::Soa => 6,
+        DnsRecordType::Ptr => 12,
+        DnsRecordType::Mx => 15,
+        DnsRecordType::Txt => 16,
+        DnsRecordType::Aaaa => 28,
+        DnsRecordType::Srv => 33,
+    }
+}
+
+fn type_name(record_type: DnsRecordType) -> &'static str {
+    match record_type {
+        DnsRecordType::A => "A",
+        DnsRecordType::Ns => "NS",
+        DnsRecordType::Cname => "CNAME",
+        DnsRecordType::Soa => "SOA",
+        DnsRecordType::Ptr => "PTR",
+        DnsRecordType::Mx => "MX",
+        DnsRecordType::Txt => "TXT",
+        DnsRecordType::Aaaa => "AAAA",
+        DnsRecordType::Srv => "SRV",
+    }
+}
+
+fn describe_rcode(rcode: u16) -> String {
+    let name = match rcode {
+        1 => "FORMERR",
+        2 => "SERVFAIL",
+        3 => "NXDOMAIN",
+        4 => "NOTIMP",
+        5 => "REFUSED",
+        _ => return rcode.to_string(),
+    };
+
+    format!("{} {}", rcode, name)
+}
+
+/// Reads a name, following the pointers of compressed names back into the whole message.
+fn read_name(message: &mut Reader) -> Result<String, Result<State, NetworkError>> {
+    let bytes = message.bytes();
+    let mut labels = Vec::new();
+    let mut offset = message.offset();
+    let mut next = None;
+    let mut pointers = 0;
+
+    loop {
+        let length = *bytes.get(offset).ok_or_else(|| message.malformed())? as usize;
+
+        match length & 0xC0 {
+            0x00 if length == 0 => {
+                offset += 1;
+                break;
+            }
+            0x00 => {
+                let label = bytes.get(offset + 1..offset + 1 + length).ok_or_else(|| message.malformed())?;
+                labels.push(String::from_utf8_lossy(label).into_owned());
+                offset += 1 + length;
+            }
+            0xC0 if pointers < NAME_MAX_POINTERS => {
+                let low = *bytes.get(offset + 1).ok_or_else(|| message.malformed())? as usize;
+                next.get_or_insert(offset + 2);
+                pointers += 1;
+                offset = ((length & 0x3F) << 8) | low;
+            }
+            _ => return Err(message.malformed()),
+        }
+    }
+
+    message.seek(next.unwrap_or(offset));
+
+    Ok(format!("{}.", labels.join(".")))
+}
@@ -0,0 +1,251 @@
+use crate::configuration::{DnsRecordType, DnsTransport};
+use crate::health_checker::Reason::Other;
+use crate::health_checker::State::Healthy;
+use crate::health_checker::State::Unhealthy;
+use assert2::{check, assert};
+use rstest::rstest;
+use std::net::TcpListener;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::UdpSocket;
+use testcontainers_modules::testcontainers::core::ContainerPort;
+use testcontainers_modules::testcontainers::runners::AsyncRunner;
+use crate::configuration::fixtures::{a_dns_configuration, a_dns_configuration_expecting};
+use crate::health_checker::coredns_container::{CorednsContainer, DNS_ADDRESS, DNS_PORT};
+use crate::health_checker::dns::{query, read_name, Dns};
+use crate::health_checker::wire::Reader;
+use crate::health_checker::HealthCheck;
+
+const NOERROR: u16 = 0;
+
+#[rstest]
+#[case::udp(DnsTransport::Udp)]
+#[case::tcp(DnsTransport::Tcp)]
+#[tokio::test]
+async fn a_server_answering_should_be_reported_as_healthy(#[case] transport: DnsTransport) {
+    let port = a_server_answering(NOERROR, vec![(1, vec![10, 0, 0, 1])]).await;
+    let configuration = a_dns_configuration(port, transport);
+
+    let result = Dns.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[rstest]
+#[case::udp(DnsTransport::Udp, ContainerPort::Udp(DNS_PORT))]
+#[case::tcp(DnsTransport::Tcp, ContainerPort::Tcp(DNS_PORT))]
+#[tokio::test]
+async fn a_running_server_should_be_reported_as_healthy(#[case] transport: DnsTransport, #[case] container_port: ContainerPort) {
+    let coredns_container = CorednsContainer::default()
+        .start()
+        .await
+        .unwrap();
+
+    let port = coredns_container.get_host_port_ipv4(container_port).await.unwrap();
+    let configuration = a_dns_configuration(port, transport);
+
+    let result = Dns.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[tokio::test]
+async fn a_running_server_answering_the_expected_address_should_be_reported_as_healthy() {
+    let coredns_container = CorednsContainer::default()
+        .start()
+        .await
+        .unwrap();
+
+    let port = coredns_container.get_host_port_ipv4(ContainerPort::Udp(DNS_PORT)).await.unwrap();
+    let configuration = a_dns_configuration_expecting(port, DnsRecordType::A, DNS_ADDRESS);
+
+    let result = Dns.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[rstest]
+#[case::servfail(2, "error response '2 SERVFAIL'")]
+#[case::nxdomain(3, "error response '3 NXDOMAIN'")]
+#[case::refused(5, "error response '5 REFUSED'")]
+#[case::unnamed(11, "error response '11'")]
+#[tokio::test]
+async fn an_error_response_should_be_reported_as_unhealthy(#[case] rcode: u16, #[case] reason: &str) {
+    let port = a_server_answering(rcode, vec![]).await;
+    let configuration = a_dns_configuration(port, DnsTransport::Udp);
+
+    let result = Dns.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other(reason.to_string())));
+}
+
+#[rstest]
+#[case::a(DnsRecordType::A, "10.0.0.2", vec![(5, a_name("db.example.com")), (1, vec![10, 0, 0, 1]), (1, vec![10, 0, 0, 2])])]
+#[case::aaaa(DnsRecordType::Aaaa, "fd00::1", vec![(28, [vec![0xFD], vec![0; 14], vec![1]].concat())])]
+#[case::cname(DnsRecordType::Cname, "DB.example.com", vec![(5, a_name("db.example.com"))])]
+#[case::mx(DnsRecordType::Mx, "10 mail.example.com.", vec![(15, [vec![0, 10], a_name("mail.example.com")].concat())])]
+#[case::srv(DnsRecordType::Srv, "0 5 5432 db.example.com", vec![(33, [vec![0, 0, 0, 5, 0x15, 0x38], a_name("db.example.com")].concat())])]
+#[case::txt(DnsRecordType::Txt, "v=spf1 -all", vec![(16, [vec![6], b"v=spf1".to_vec(), vec![5], b" -all".to_vec()].concat())])]
+#[tokio::test]
+async fn an_answer_containing_the_expected_value_should_be_reported_as_healthy(#[case] record_type: DnsRecordType, #[case] expected: &str, #[case] answers: Vec<(u16, Vec<u8>)>) {
+    let port = a_server_answering(NOERROR, answers).await;
+    let configuration = a_dns_configuration_expecting(port, record_type, expected);
+
+    let result = Dns.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[rstest]
+#[case::other_values(vec![(1, vec![10, 0, 0, 1]), (1, vec![10, 0, 0, 2])], "answer does not contain '10.0.0.9', got '10.0.0.1', '10.0.0.2'")]
+#[case::no_record(vec![(5, a_name("db.example.com"))], "no 'A' record for 'db.internal'")]
+#[tokio::test]
+async fn an_answer_without_the_expected_value_should_be_reported_as_unhealthy(#[case] answers: Vec<(u16, Vec<u8>)>, #[case] reason: &str) {
+    let port = a_server_answering(NOERROR, answers).await;
+    let configuration = a_dns_configuration_expecting(port, DnsRecordType::A, "10.0.0.9");
+
+    let result = Dns.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other(reason.to_string())));
+}
+
+#[tokio::test]
+async fn a_truncated_response_should_be_queried_again_over_tcp() {
+    let tcp_listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = tcp_listener.local_addr().unwrap().port();
+    let udp_socket = UdpSocket::bind(("127.0.0.1", port)).await.unwrap();
+    let answers = vec![(1, vec![10, 0, 0, 1])];
+
+    tokio::spawn(serve_udp(udp_socket, 0x0200, vec![]));
+    tokio::spawn(serve_tcp(tcp_listener, NOERROR, answers));
+
+    let configuration = a_dns_configuration_expecting(port, DnsRecordType::A, "10.0.0.1");
+
+    let result = Dns.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[rstest]
+#[case::udp(DnsTransport::Udp)]
+#[case::tcp(DnsTransport::Tcp)]
+#[tokio::test]
+async fn unreachable_server_should_be_reported_as_error(#[case] transport: DnsTransport) {
+    let unused_port = TcpListener::bind("localhost:0").unwrap()
+        .local_addr().unwrap()
+        .port();
+    let configuration = a_dns_configuration(unused_port, transport);
+
+    let result = Dns.get_health(&configuration).await;
+
+    assert!(let Err(error) = result);
+    check!(error.message.starts_with("network error"));
+}
+
+#[rstest]
+#[case::root(".", vec![0])]
+#[case::relative("db.internal", [a_name("db.internal")].concat())]
+#[case::absolute("db.internal.", [a_name("db.internal")].concat())]
+fn query_should_hold_the_name(#[case] name: &str, #[case] encoded: Vec<u8>) {
+    let result = query(0x1234, name, 1);
+
+    check!(result[..12] == [0x12, 0x34, 0x01, 0x00, 0, 1, 0, 0, 0, 0, 0, 0]);
+    check!(result[12..] == [encoded, vec![0, 1, 0, 1]].concat());
+}
+
+#[test]
+fn compressed_name_should_be_read() {
+    let bytes = [vec![0; 12], a_name("example.com"), vec![2, b'd', b'b', 0xC0, 12]].concat();
+    let mut message = Reader::new(&bytes, "response");
+    message.seek(25);
+
+    let result = read_name(&mut message);
+
+    assert!(let Ok(name) = result);
+    check!(name == "db.example.com.");
+    check!(message.offset() == bytes.len());
+}
+
+#[test]
+fn looping_name_should_be_reported_as_malformed() {
+    let bytes = [0xC0, 2, 0xC0, 0];
+    let mut message = Reader::new(&bytes, "response");
+
+    let result = read_name(&mut message);
+
+    assert!(let Err(Err(error)) = result);
+    check!(error.message == "network error: malformed response");
+}
+
+fn a_name(name: &str) -> Vec<u8> {
+    let mut encoded = Vec::new();
+
+    for label in name.split('.') {
+        encoded.push(label.len() as u8);
+        encoded.extend_from_slice(label.as_bytes());
+    }
+
+    encoded.push(0);
+    encoded
+}
+
+/// Starts a server answering the queries over UDP and TCP with the given response code and answers, whose records
+/// all belong to the queried name.
+async fn a_server_answering(rcode: u16, answers: Vec<(u16, Vec<u8>)>) -> u16 {
+    let tcp_listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = tcp_listener.local_addr().unwrap().port();
+    let udp_socket = UdpSocket::bind(("127.0.0.1", port)).await.unwrap();
+
+    tokio::spawn(serve_udp(udp_socket, rcode, answers.clone()));
+    tokio::spawn(serve_tcp(tcp_listener, rcode, answers));
+
+    port
+}
+
+async fn serve_udp(socket: UdpSocket, flags: u16, answers: Vec<(u16, Vec<u8>)>) {
+    let mut buffer = vec![0u8; 512];
+
+    while let Ok((length, peer)) = socket.recv_from(&mut buffer).await {
+        let response = a_response(&buffer[..length], flags, &answers);
+        socket.send_to(&response, peer).await.unwrap();
+    }
+}
+
+async fn serve_tcp(listener: tokio::net::TcpListener, flags: u16, answers: Vec<(u16, Vec<u8>)>) {
+    while let Ok((mut stream, _)) = listener.accept().await {
+        let mut length = [0u8; 2];
+        stream.read_exact(&mut length).await.unwrap();
+
+        let mut query = vec![0u8; u16::from_be_bytes(length) as usize];
+        stream.read_exact(&mut query).await.unwrap();
+
+        let response = a_response(&query, flags, &answers);
+        stream.write_all(&(response.len() as u16).to_be_bytes()).await.unwrap();
+        stream.write_all(&response).await.unwrap();
+    }
+}
+
+fn a_response(query: &[u8], flags: u16, answers: &[(u16, Vec<u8>)]) -> Vec<u8> {
+    let mut response = query[..2].to_vec();
+    response.extend_from_slice(&(0x8180 | flags).to_be_bytes());
+    response.extend_from_slice(&1u16.to_be_bytes());
+    response.extend_from_slice(&(answers.len() as u16).to_be_bytes());
+    response.extend_from_slice(&[0; 4]);
+    response.extend_from_slice(&query[12..]);
+
+    for (record_type, data) in answers {
+        response.extend_from_slice(&[0xC0, 12]);
+        response.extend_from_slice(&record_type.to_be_bytes());
+        response.extend_from_slice(&[0, 1, 0, 0, 0, 60]);
+        response.extend_from_slice(&(data.len() as u16).to_be_bytes());
+        response.extend_from_slice(data);
+    }
+
+    response
+}
@@ -0,0 +1,32 @@
+use testcontainers_modules::testcontainers::core::wait::LogWaitStrategy;
+use testcontainers_modules::testcontainers::core::ContainerPort::Tcp;
+use testcontainers_modules::testcontainers::core::{ContainerPort, WaitFor};
+use testcontainers_modules::testcontainers::Image;
+
+pub const IMAP_PORT: u16 = 143;
+
+pub const POP3_PORT: u16 = 110;
+
+// the default configuration accepts any user with this password
+pub const DOVECOT_PASSWORD: &str = "pass";
+
+#[derive(Default)]
+pub struct DovecotContainer;
+
+impl Image for DovecotContainer {
+    fn name(&self) -> &str {
+        "dovecot/dovecot"
+    }
+
+    fn tag(&self) -> &str {
+        "2.3.21"
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::log(LogWaitStrategy::stderr("starting up for"))]
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[Tcp(IMAP_PORT), Tcp(POP3_PORT)]
+    }
+}
@@ -0,0 +1,110 @@
+use std::time::Duration;
+use async_trait::async_trait;
+use log::{debug, error, info};
+use reqwest::StatusCode;
+use serde_json::Value;
+use crate::configuration::{Configuration, ElasticsearchStatus};
+use crate::health_checker::http::{client, failure, url};
+use crate::health_checker::Reason::Other;
+use crate::health_checker::{HealthCheck, NetworkError, State};
+
+#[cfg(test)]
+#[path = "./elasticsearch_test.rs"]
+mod test;
+
+pub(crate) struct Elasticsearch;
+
+#[async_trait]
+impl HealthCheck for Elasticsearch {
+    async fn get_health(&self, configuration: &Configuration) -> Result<State, NetworkError> {
+        let result = cluster_health(configuration).await;
+
+        match &result {
+            Ok(state) => info!("state {}", state),
+            Err(failure) => error!("{}", failure.message),
+        }
+
+        result
+    }
+}
+
+async fn cluster_health(configuration: &Configuration) -> Result<State, NetworkError> {
+    let mut url = url(configuration, "/_cluster/health");
+
+    if let Some(status) = configuration.elasticsearch_wait_for_status {
+        // the cluster answers once its own timeout expires, leave time for the answer to arrive
+        let timeout: Duration = configuration.timeout.into();
+
+        url.query_pairs_mut()
+            .append_pair("wait_for_status", name(status))
+            .append_pair("timeout", &format!("{}ms", timeout.as_millis() / 2));
+    }
+
+    let mut request = client(configuration).get(url.as_ref());
+
+    if let Some(username) = &configuration.username {
+        request = request.basic_auth(String::from(username.clone()), configuration.password.clone().map(String::from));
+    }
+
+    let response = match request.send().await {
+        Ok(response) => response,
+        Err(e) => return failure(configuration, e),
+    };
+
+    debug!("received status {} from {}", response.status(), url);
+
+    // waiting for a status the cluster does not reach in time is answered with a request timeout
+    if response.status() != StatusCode::OK && response.status() != StatusCode::REQUEST_TIMEOUT {
+        return Ok(State::Unhealthy(Other(format!("unexpected status code '{}'", response.status()))));
+    }
+
+    let body = match response.bytes().await {
+        Ok(body) => body,
+        Err(e) => return failure(configuration, e),
+    };
+
+    match serde_json::from_slice::<Value>(&body) {
+        Ok(health) => Ok(check_health(&health, configuration.elasticsearch_minimum_status)),
+        Err(e) => Err(NetworkError {
+            message: format!("network error: malformed response: {}", e),
+        }),
+    }
+}
+
+pub(super) fn check_health(health: &Value, minimum: ElasticsearchStatus) -> State {
+    let status = health["status"].as_str().unwrap_or_default();
+
+    debug!(
+        "cluster {} is {}, {} nodes",
+        health["cluster_name"].as_str().unwrap_or("unknown"),
+        status,
+        health["number_of_nodes"].as_u64().unwrap_or_default(),
+    );
+
+    let reached = match (status, minimum) {
+        ("green", _) => true,
+        ("yellow", ElasticsearchStatus::Yellow) => true,
+        ("yellow", ElasticsearchStatus::Green) | ("red", _) => false,
+        (status, _) => return State::Unhealthy(Other(format!("unexpected cluster status '{}'", status))),
+    };
+
+    if reached {
+        return State::Healthy;
+    }
+
+    let unassigned = health["unassigned_shards"].as_u64().unwrap_or_default();
+
+    let shards = match health["unassigned_primary_shards"].as_u64() {
+        Some(primary) => format!("'{}' unassigned shards including '{}' primary", unassigned, primary),
+        None => format!("'{}' unassigned shards", unassigned),
+    };
+
+    State::Unhealthy(Other(format!("cluster status is '{}', expected at least '{}', {}", status, name(minimum), shards)))
+}
+
+fn name(status: ElasticsearchStatus) -> &'static str {
+    match status {
+        ElasticsearchStatus::Green => "green",
+        ElasticsearchStatus::Yellow => "yellow",
+    }
+}
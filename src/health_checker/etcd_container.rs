@@ -0,0 +1,56 @@
+use std::borrow::Cow;
+use testcontainers_modules::testcontainers::core::wait::LogWaitStrategy;
+use testcontainers_modules::testcontainers::core::ContainerPort::Tcp;
+use testcontainers_modules::testcontainers::core::{ContainerPort, WaitFor};
+use testcontainers_modules::testcontainers::Image;
+
+pub const GRPC_PORT: u16 = 2379;
+
+/// An etcd server, whose client port also serves the `grpc.health.v1.Health` service.
+pub struct EtcdContainer {
+    arguments: Vec<String>,
+}
+
+impl EtcdContainer {
+    pub fn plain() -> Self {
+        EtcdContainer::serving("http", &[])
+    }
+
+    /// Serves the client port over TLS with a self-signed certificate.
+    pub fn with_tls() -> Self {
+        EtcdContainer::serving("https", &["--auto-tls"])
+    }
+
+    fn serving(scheme: &str, options: &[&str]) -> Self {
+        let mut arguments = vec![
+            String::from("/usr/local/bin/etcd"),
+            format!("--listen-client-urls={}://0.0.0.0:{}", scheme, GRPC_PORT),
+            format!("--advertise-client-urls={}://localhost:{}", scheme, GRPC_PORT),
+        ];
+        arguments.extend(options.iter().map(|option| option.to_string()));
+
+        EtcdContainer { arguments }
+    }
+}
+
+impl Image for EtcdContainer {
+    fn name(&self) -> &str {
+        "gcr.io/etcd-development/etcd"
+    }
+
+    fn tag(&self) -> &str {
+        "v3.5.17"
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::log(LogWaitStrategy::stderr("ready to serve client requests"))]
+    }
+
+    fn cmd(&self) -> impl IntoIterator<Item = impl Into<Cow<'_, str>>> {
+        self.arguments.iter().map(String::as_str)
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[Tcp(GRPC_PORT)]
+    }
+}
@@ -0,0 +1,125 @@
+use async_trait::async_trait;
+use log::debug;
+use tokio::net::TcpStream;
+use crate::configuration::Configuration;
+use crate::health_checker::Reason::Other;
+use crate::health_checker::wire::{network_error, run_with_timeout, LineConnection};
+use crate::health_checker::{HealthCheck, NetworkError, State};
+
+#[cfg(test)]
+#[path = "./ftp_test.rs"]
+mod test;
+
+const ANONYMOUS_USERNAME: &str = "anonymous";
+
+const ANONYMOUS_PASSWORD: &str = "dockteur@";
+
+const COMMAND_OK: u16 = 200;
+const SERVICE_READY: u16 = 220;
+const CLOSING: u16 = 221;
+const LOGGED_IN: u16 = 230;
+const PASSWORD_NEEDED: u16 = 331;
+const NOT_LOGGED_IN: u16 = 530;
+
+pub(crate) struct Ftp;
+
+#[async_trait]
+impl HealthCheck for Ftp {
+    async fn get_health(&self, configuration: &Configuration) -> Result<State, NetworkError> {
+        run_with_timeout(configuration, check(configuration)).await
+    }
+}
+
+struct Reply {
+    code: u16,
+    text: String,
+}
+
+/// Reads a reply, whose first line is followed by a dash when it spans several lines, up to the line starting with
+/// the same code followed by a space.
+async fn read_reply(connection: &mut LineConnection<TcpStream>) -> Result<Reply, Result<State, NetworkError>> {
+    let line = connection.read_line().await?;
+
+    let code = match line.get(..3).and_then(|digits| digits.parse::<u16>().ok()) {
+        Some(code) => code,
+        None => return Err(Ok(State::Unhealthy(Other(format!("unexpected reply '{}'", line))))),
+    };
+
+    let mut text = line[3..].trim_start_matches(['-', ' ']).to_string();
+
+    if line[3..].starts_with('-') {
+        let last = format!("{} ", code);
+
+        loop {
+            let line = connection.read_line().await?;
+
+            if let Some(end) = line.strip_prefix(&last) {
+                text = format!("{} {}", text, end);
+                break;
+            }
+        }
+    }
+
+    Ok(Reply { code, text })
+}
+
+async fn command(connection: &mut LineConnection<TcpStream>, command: &str) -> Result<Reply, Result<State, NetworkError>> {
+    connection.send(command).await?;
+    read_reply(connection).await
+}
+
+async fn check(configuration: &Configuration) -> Result<State, Result<State, NetworkError>> {
+    let port: u16 = configuration.port.into();
+
+    let stream = TcpStream::connect(("localhost", port)).await
+        .map_err(network_error)?;
+
+    let mut connection = LineConnection::new(stream);
+
+    let greeting = read_reply(&mut connection).await?;
+    expect(&greeting, SERVICE_READY)?;
+
+    debug!("server {}", greeting.text);
+
+    if configuration.ftp_login {
+        let username = configuration.username.clone()
+            .map(String::from)
+            .unwrap_or_else(|| ANONYMOUS_USERNAME.to_string());
+
+        debug!("logging in as '{}'", username);
+
+        let mut reply = command(&mut connection, &format!("USER {}", username)).await?;
+
+        if reply.code == PASSWORD_NEEDED {
+            let password = configuration.password.clone()
+                .map(String::from)
+                .unwrap_or_else(|| ANONYMOUS_PASSWORD.to_string());
+
+            reply = command(&mut connection, &format!("PASS {}", password)).await?;
+        }
+
+        expect(&reply, LOGGED_IN)?;
+
+        let reply = command(&mut connection, "NOOP").await?;
+        expect(&reply, COMMAND_OK)?;
+    }
+
+    match command(&mut connection, "QUIT").await {
+        Ok(reply) if reply.code != CLOSING => debug!("quit failed: {} {}", reply.code, reply.text),
+        Ok(_) => {}
+        Err(e) => debug!("quit failed: {:?}", e),
+    }
+
+    Ok(State::Healthy)
+}
+
+fn expect(reply: &Reply, expected: u16) -> Result<(), Result<State, NetworkError>> {
+    let reason = match reply.code {
+        code if code == expected => return Ok(()),
+        NOT_LOGGED_IN => format!("login failed '{} {}'", reply.code, reply.text),
+        400..=599 => format!("error response '{} {}'", reply.code, reply.text),
+        _ => format!("unexpected reply '{} {}'", reply.code, reply.text),
+    };
+
+    Err(Ok(State::Unhealthy(Other(reason))))
+}
@@ -0,0 +1,166 @@
+use crate::health_checker::Reason::Other;
+use crate::health_checker::State::Healthy;
+use crate::health_checker::State::Unhealthy;
+use assert2::{check, assert};
+use rstest::rstest;
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use testcontainers_modules::testcontainers::runners::AsyncRunner;
+use crate::configuration::fixtures::{an_ftp_configuration, an_ftp_configuration_with_credentials};
+use crate::health_checker::ftp::Ftp;
+use crate::health_checker::pure_ftpd_container::{PureFtpdContainer, FTP_PASSWORD, FTP_PORT, FTP_USERNAME};
+use crate::health_checker::HealthCheck;
+
+#[tokio::test]
+async fn a_greeting_server_should_be_reported_as_healthy() {
+    let (port, commands) = a_server_replying(&["220 (vsFTPd 3.0.5)", "221 Goodbye."]).await;
+    let configuration = an_ftp_configuration(port, false);
+
+    let result = Ftp.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+    check!(*commands.lock().unwrap() == vec!["QUIT"]);
+}
+
+#[tokio::test]
+async fn an_anonymous_login_should_be_reported_as_healthy() {
+    let (port, commands) = a_server_replying(&[
+        "220-Welcome to Pure-FTPd.\r\n220-You are user number 1 of 50 allowed.\r\n220 Local time is now 10:00.",
+        "331 Please specify the password.",
+        "230 Login successful.",
+        "200 NOOP ok.",
+        "221 Goodbye.",
+    ]).await;
+    let configuration = an_ftp_configuration(port, true);
+
+    let result = Ftp.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+    check!(*commands.lock().unwrap() == vec!["USER anonymous", "PASS dockteur@", "NOOP", "QUIT"]);
+}
+
+#[tokio::test]
+async fn a_login_with_credentials_should_be_reported_as_healthy() {
+    let (port, commands) = a_server_replying(&["220 ready", "331 password", "230 logged in", "200 ok", "221 bye"]).await;
+    let configuration = an_ftp_configuration_with_credentials(port, "partner", "secret");
+
+    let result = Ftp.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+    check!(*commands.lock().unwrap() == vec!["USER partner", "PASS secret", "NOOP", "QUIT"]);
+}
+
+#[tokio::test]
+async fn a_running_server_should_be_reported_as_healthy() {
+    let pure_ftpd_container = PureFtpdContainer
+        .start()
+        .await
+        .unwrap();
+
+    let port = pure_ftpd_container.get_host_port_ipv4(FTP_PORT).await.unwrap();
+    let configuration = an_ftp_configuration(port, false);
+
+    let result = Ftp.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[tokio::test]
+async fn a_server_accepting_credentials_should_be_reported_as_healthy() {
+    let pure_ftpd_container = PureFtpdContainer
+        .start()
+        .await
+        .unwrap();
+
+    let port = pure_ftpd_container.get_host_port_ipv4(FTP_PORT).await.unwrap();
+    let configuration = an_ftp_configuration_with_credentials(port, FTP_USERNAME, FTP_PASSWORD);
+
+    let result = Ftp.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[tokio::test]
+async fn a_server_rejecting_credentials_should_be_reported_as_unhealthy() {
+    let pure_ftpd_container = PureFtpdContainer
+        .start()
+        .await
+        .unwrap();
+
+    let port = pure_ftpd_container.get_host_port_ipv4(FTP_PORT).await.unwrap();
+    let configuration = an_ftp_configuration_with_credentials(port, FTP_USERNAME, "wrong");
+
+    let result = Ftp.get_health(&configuration).await;
+
+    assert!(let Ok(Unhealthy(Other(reason))) = result);
+    check!(reason.starts_with("login failed '530"));
+}
+
+#[rstest]
+#[case::busy(&["421 Too many users"], "error response '421 Too many users'")]
+#[case::not_ftp(&["SSH-2.0-OpenSSH_9.6"], "unexpected reply 'SSH-2.0-OpenSSH_9.6'")]
+#[case::unexpected_greeting(&["120 Service ready in 5 minutes."], "unexpected reply '120 Service ready in 5 minutes.'")]
+#[case::rejected_user(&["220 ready", "530 This FTP server is anonymous only."], "login failed '530 This FTP server is anonymous only.'")]
+#[case::rejected_password(&["220 ready", "331 password", "530 Login incorrect."], "login failed '530 Login incorrect.'")]
+#[case::rejected_noop(&["220 ready", "230 logged in", "502 Command not implemented."], "error response '502 Command not implemented.'")]
+#[tokio::test]
+async fn a_failing_server_should_be_reported_as_unhealthy(#[case] replies: &[&str], #[case] reason: &str) {
+    let (port, _) = a_server_replying(replies).await;
+    let configuration = an_ftp_configuration(port, true);
+
+    let result = Ftp.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other(reason.to_string())));
+}
+
+#[tokio::test]
+async fn unreachable_server_should_be_reported_as_error() {
+    let unused_port = TcpListener::bind("localhost:0").unwrap()
+        .local_addr().unwrap()
+        .port();
+    let configuration = an_ftp_configuration(unused_port, false);
+
+    let result = Ftp.get_health(&configuration).await;
+
+    assert!(let Err(error) = result);
+    check!(error.message.starts_with("network error"));
+}
+
+/// Starts a server sending the first reply as greeting, then each following reply once a command is received,
+/// recording the received commands.
+async fn a_server_replying(replies: &[&str]) -> (u16, Arc<Mutex<Vec<String>>>) {
+    let listener = tokio::net::TcpListener::bind("localhost:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let replies: Vec<String> = replies.iter().map(|reply| format!("{}\r\n", reply)).collect();
+    let commands = Arc::new(Mutex::new(Vec::new()));
+    let received = commands.clone();
+
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut stream = BufReader::new(stream);
+
+        for (index, reply) in replies.iter().enumerate() {
+            if index > 0 {
+                let mut command = String::new();
+                if stream.read_line(&mut command).await.unwrap_or(0) == 0 {
+                    return;
+                }
+                received.lock().unwrap().push(command.trim_end().to_string());
+            }
+
+            stream.get_mut().write_all(reply.as_bytes()).await.unwrap();
+        }
+
+        let mut ignored = String::new();
+        while stream.read_line(&mut ignored).await.unwrap_or(0) > 0 {}
+    });
+
+    (port, commands)
+}
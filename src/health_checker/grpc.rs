@@ -0,0 +1,295 @@
+use std::time::Duration;
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::{HeaderMap, Request, StatusCode};
+use log::debug;
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::net::TcpStream;
+use crate::configuration::Configuration;
+use crate::health_checker::Reason::Other;
+use crate::health_checker::wire::{malformed, network_error, run_with_timeout, Reader};
+use crate::health_checker::{tls, HealthCheck, NetworkError, State};
+
+#[cfg(test)]
+#[path = "./grpc_test.rs"]
+mod test;
+
+const CHECK_PATH: &str = "/grpc.health.v1.Health/Check";
+
+const MESSAGE_MAX_SIZE: usize = 1 << 20;
+
+// grpc-timeout values are limited to 8 digits
+const TIMEOUT_MAX_MILLISECONDS: u128 = 99_999_999;
+
+const OK: u32 = 0;
+const NOT_FOUND: u32 = 5;
+const UNIMPLEMENTED: u32 = 12;
+
+const UNKNOWN: u64 = 0;
+const SERVING: u64 = 1;
+const NOT_SERVING: u64 = 2;
+const SERVICE_UNKNOWN: u64 = 3;
+
+pub(crate) struct Grpc;
+
+#[async_trait]
+impl HealthCheck for Grpc {
+    async fn get_health(&self, configuration: &Configuration) -> Result<State, NetworkError> {
+        run_with_timeout(configuration, check(configuration)).await
+    }
+}
+
+struct Reply {
+    status: StatusCode,
+    grpc_status: Option<u32>,
+    grpc_message: String,
+    data: Vec<u8>,
+}
+
+async fn check(configuration: &Configuration) -> Result<State, Result<State, NetworkError>> {
+    let port: u16 = configuration.port.into();
+    let timeout: Duration = configuration.timeout.into();
+    let service = String::from(configuration.grpc_service.clone());
+
+    let stream = TcpStream::connect(("localhost", port)).await
+        .map_err(network_error)?;
+
+    debug!("checking health of service '{}'", service);
+
+    let request = check_request(&service);
+
+    let reply = if configuration.tls {
+        let stream = tls::connect(stream, configuration, &[b"h2"]).await
+            .map_err(network_error)?;
+
+        call(stream, &format!("https://localhost:{}{}", port, CHECK_PATH), &request, timeout).await?
+    } else {
+        call(stream, &format!("http://localhost:{}{}", port, CHECK_PATH), &request, timeout).await?
+    };
+
+    if reply.status != StatusCode::OK {
+        return Ok(State::Unhealthy(Other(format!("unexpected status code '{}'", reply.status))));
+    }
+
+    match reply.grpc_status {
+        None => Err(malformed("response")),
+        Some(OK) => Ok(check_response(&service, &reply.data)?),
+        Some(NOT_FOUND) => Ok(State::Unhealthy(Other(format!("service '{}' is unknown", service)))),
+        Some(UNIMPLEMENTED) => Ok(State::Unhealthy(Other(String::from("health checking is not implemented by the server")))),
+        Some(code) if reply.grpc_message.is_empty() => Ok(State::Unhealthy(Other(format!("error response '{}'", describe_status(code))))),
+        Some(code) => Ok(State::Unhealthy(Other(format!("error response '{}: {}'", describe_status(code), reply.grpc_message)))),
+    }
+}
+
+/// Sends a unary request over a new HTTP/2 connection and collects its response, whose status is either in the
+/// trailers or, when the server replies without a message, in the headers.
+async fn call<S>(stream: S, uri: &str, message: &[u8], timeout: Duration) -> Result<Reply, Result<State, NetworkError>>
+where
+    S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+{
+    let (client, connection) = h2::client::handshake(stream).await
+        .map_err(network_error)?;
+
+    tokio::spawn(async move {
+        if let Err(e) = connection.await {
+            debug!("connection closed: {}", e);
+        }
+    });
+
+    let mut client = client.ready().await
+        .map_err(network_error)?;
+
+    let request = Request::post(uri)
+        .header("content-type", "application/grpc")
+        .header("te", "trailers")
+        .header("grpc-timeout", format!("{}m", timeout.as_millis().min(TIMEOUT_MAX_MILLISECONDS)))
+        .header("user-agent", concat!("dockteur/", std::env!("CARGO_PKG_VERSION")))
+        .body(())
+        .map_err(network_error)?;
+
+    let (response, mut sender) = client.send_request(request, false)
+        .map_err(network_error)?;
+
+    let mut frame = vec![0];
+    frame.extend_from_slice(&(message.len() as u32).to_be_bytes());
+    frame.extend_from_slice(message);
+
+    sender.send_data(Bytes::from(frame), true)
+        .map_err(network_error)?;
+
+    let (head, mut body) = response.await
+        .map_err(network_error)?
+        .into_parts();
+
+    let mut data = Vec::new();
+
+    while let Some(chunk) = body.data().await {
+        let chunk = chunk.map_err(network_error)?;
+        body.flow_control().release_capacity(chunk.len())
+            .map_err(network_error)?;
+
+        data.extend_from_slice(&chunk);
+
+        if data.len() > MESSAGE_MAX_SIZE {
+            return Err(malformed("response"));
+        }
+    }
+
+    let trailers = body.trailers().await
+        .map_err(network_error)?;
+
+    let metadata = match &trailers {
+        Some(trailers) if trailers.contains_key("grpc-status") => trailers,
+        _ => &head.headers,
+    };
+
+    Ok(Reply {
+        status: head.status,
+        grpc_status: grpc_status(metadata),
+        grpc_message: grpc_message(metadata),
+        data,
+    })
+}
+
+fn check_request(service: &str) -> Vec<u8> {
+    let mut request = Vec::new();
+
+    if !service.is_empty() {
+        request.push(0x0A);
+        varint(&mut request, service.len() as u64);
+        request.extend_from_slice(service.as_bytes());
+    }
+
+    request
+}
+
+fn check_response(service: &str, data: &[u8]) -> Result<State, Result<State, NetworkError>> {
+    let mut frame = Reader::new(data, "response");
+
+    if frame.u8()? != 0 {
+        return Err(malformed("response"));
+    }
+
+    let length = frame.u32()? as usize;
+    let message = frame.take(length)?;
+
+    let subject = match service {
+        "" => String::from("server"),
+        service => format!("service '{}'", service),
+    };
+
+    match serving_status(message)? {
+        SERVING => Ok(State::Healthy),
+        NOT_SERVING => Ok(State::Unhealthy(Other(format!("{} is not serving", subject)))),
+        UNKNOWN => Ok(State::Unhealthy(Other(format!("{} serving status is unknown", subject)))),
+        SERVICE_UNKNOWN => Ok(State::Unhealthy(Other(format!("service '{}' is unknown", service)))),
+        status => Ok(State::Unhealthy(Other(format!("unexpected serving status '{}'", status)))),
+    }
+}
+
+/// Reads the status field of a health check response, absent when it holds its default value.
+fn serving_status(message: &[u8]) -> Result<u64, Result<State, NetworkError>> {
+    let mut message = Reader::new(message, "response");
+    let mut status = UNKNOWN;
+
+    while !message.is_empty() {
+        let key = read_varint(&mut message)?;
+
+        let length = match key & 0x07 {
+            0 => {
+                let value = read_varint(&mut message)?;
+                if key >> 3 == 1 {
+                    status = value;
+                }
+                0
+            }
+            1 => 8,
+            2 => read_varint(&mut message)? as usize,
+            5 => 4,
+            _ => return Err(malformed("response")),
+        };
+
+        message.take(length)?;
+    }
+
+    Ok(status)
+}
+
+fn varint(buffer: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buffer.push((value as u8 & 0x7F) | 0x80);
+        value >>= 7;
+    }
+
+    buffer.push(value as u8);
+}
+
+fn read_varint(message: &mut Reader) -> Result<u64, Result<State, NetworkError>> {
+    let mut value = 0u64;
+
+    for shift in (0..64).step_by(7) {
+        let byte = message.u8()?;
+
+        value |= ((byte & 0x7F) as u64) << shift;
+
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+
+    Err(malformed("response"))
+}
+
+fn grpc_status(metadata: &HeaderMap) -> Option<u32> {
+    metadata.get("grpc-status")?
+        .to_str().ok()?
+        .parse().ok()
+}
+
+fn grpc_message(metadata: &HeaderMap) -> String {
+    let message = match metadata.get("grpc-message") {
+        Some(message) => message.as_bytes(),
+        None => return String::new(),
+    };
+
+    let mut decoded = Vec::new();
+    let mut index = 0;
+
+    while index < message.len() {
+        let escaped = message.get(index + 1..index + 3)
+            .filter(|_| message[index] == b'%')
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                index += 3;
+            }
+            None => {
+                decoded.push(message[index]);
+                index += 1;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn describe_status(code: u32) -> String {
+    let name = match code {
+        1 => "CANCELLED",
+        2 => "UNKNOWN",
+        3 => "INVALID_ARGUMENT",
+        4 => "DEADLINE_EXCEEDED",
+        7 => "PERMISSION_DENIED",
+        8 => "RESOURCE_EXHAUSTED",
+        9 => "FAILED_PRECONDITION",
+        13 => "INTERNAL",
+        14 => "UNAVAILABLE",
+        16 => "UNAUTHENTICATED",
+        _ => return code.to_string(),
+    };
+
+    format!("{} {}", code, name)
+}
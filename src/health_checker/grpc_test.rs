@@ -0,0 +1,198 @@
+use crate::configuration::Configuration;
+use crate::health_checker::Reason::Other;
+use crate::health_checker::State::Healthy;
+use crate::health_checker::State::Unhealthy;
+use assert2::{check, assert};
+use bytes::Bytes;
+use http::{HeaderMap, HeaderValue, Response};
+use rstest::rstest;
+use std::net::TcpListener;
+use testcontainers_modules::testcontainers::runners::AsyncRunner;
+use crate::configuration::fixtures::a_grpc_configuration;
+use crate::health_checker::grpc::{check_request, serving_status, Grpc};
+use crate::health_checker::etcd_container::{EtcdContainer, GRPC_PORT};
+use crate::health_checker::HealthCheck;
+
+#[rstest]
+#[case::plain(EtcdContainer::plain(), false)]
+#[case::tls(EtcdContainer::with_tls(), true)]
+#[tokio::test]
+async fn a_healthy_server_should_be_reported(#[case] image: EtcdContainer, #[case] tls: bool) {
+    let etcd_container = image
+        .start()
+        .await
+        .unwrap();
+
+    let port = etcd_container.get_host_port_ipv4(GRPC_PORT).await.unwrap();
+    let configuration = Configuration {
+        tls,
+        tls_skip_verify: tls,
+        ..a_grpc_configuration(port, "")
+    };
+
+    let result = Grpc.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[tokio::test]
+async fn a_server_not_knowing_the_service_should_be_reported_as_unhealthy() {
+    let etcd_container = EtcdContainer::plain()
+        .start()
+        .await
+        .unwrap();
+
+    let port = etcd_container.get_host_port_ipv4(GRPC_PORT).await.unwrap();
+    let configuration = a_grpc_configuration(port, "api");
+
+    let result = Grpc.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other(String::from("service 'api' is unknown"))));
+}
+
+#[tokio::test]
+async fn a_serving_server_should_be_reported_as_healthy() {
+    let port = a_server_answering(Some(1), 0, "").await;
+    let configuration = a_grpc_configuration(port, "");
+
+    let result = Grpc.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[rstest]
+#[case::not_serving("", 2, "server is not serving")]
+#[case::unknown("", 0, "server serving status is unknown")]
+#[case::service_not_serving("api", 2, "service 'api' is not serving")]
+#[case::service_unknown("api", 3, "service 'api' is unknown")]
+#[case::unexpected("", 7, "unexpected serving status '7'")]
+#[tokio::test]
+async fn a_server_not_serving_should_be_reported_as_unhealthy(#[case] service: &str, #[case] status: u64, #[case] reason: &str) {
+    let port = a_server_answering(Some(status), 0, "").await;
+    let configuration = a_grpc_configuration(port, service);
+
+    let result = Grpc.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other(reason.to_string())));
+}
+
+#[rstest]
+#[case::unimplemented(12, "", "health checking is not implemented by the server")]
+#[case::not_found(5, "", "service 'api' is unknown")]
+#[case::unavailable(14, "backend%20down", "error response '14 UNAVAILABLE: backend down'")]
+#[case::unnamed(42, "", "error response '42'")]
+#[tokio::test]
+async fn an_error_status_should_be_reported_as_unhealthy(#[case] code: u32, #[case] message: &'static str, #[case] reason: &str) {
+    let port = a_server_answering(None, code, message).await;
+    let configuration = a_grpc_configuration(port, "api");
+
+    let result = Grpc.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other(reason.to_string())));
+}
+
+#[tokio::test]
+async fn a_failed_tls_handshake_should_be_reported_as_error() {
+    let port = a_server_answering(Some(1), 0, "").await;
+    let configuration = Configuration {
+        tls: true,
+        ..a_grpc_configuration(port, "")
+    };
+
+    let result = Grpc.get_health(&configuration).await;
+
+    assert!(let Err(error) = result);
+    check!(error.message.starts_with("network error"));
+}
+
+#[tokio::test]
+async fn unreachable_server_should_be_reported_as_error() {
+    let unused_port = TcpListener::bind("localhost:0").unwrap()
+        .local_addr().unwrap()
+        .port();
+    let configuration = a_grpc_configuration(unused_port, "");
+
+    let result = Grpc.get_health(&configuration).await;
+
+    assert!(let Err(error) = result);
+    check!(error.message.starts_with("network error"));
+}
+
+#[rstest]
+#[case::server("", vec![])]
+#[case::service("api", vec![0x0A, 3, b'a', b'p', b'i'])]
+fn check_request_should_hold_the_service_name(#[case] service: &str, #[case] expected: Vec<u8>) {
+    let result = check_request(service);
+
+    check!(result == expected);
+}
+
+#[rstest]
+#[case::serving(vec![0x08, 1], 1)]
+#[case::default(vec![], 0)]
+#[case::unknown_fields(vec![0x12, 2, 0x08, 2, 0x08, 2, 0x1D, 0, 0, 0, 0], 2)]
+fn serving_status_should_be_read_from_response(#[case] message: Vec<u8>, #[case] expected: u64) {
+    let result = serving_status(&message);
+
+    assert!(let Ok(status) = result);
+    check!(status == expected);
+}
+
+#[test]
+fn truncated_response_should_be_reported_as_malformed() {
+    let result = serving_status(&[0x12, 5, 0x08]);
+
+    assert!(let Err(Err(error)) = result);
+    check!(error.message == "network error: malformed response");
+}
+
+/// Starts a server answering every call with the given serving status followed by the given gRPC status, or with
+/// the gRPC status alone when no serving status is given.
+async fn a_server_answering(serving_status: Option<u64>, code: u32, message: &'static str) -> u16 {
+    let listener = tokio::net::TcpListener::bind("localhost:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+
+        let mut connection = match h2::server::handshake(stream).await {
+            Ok(connection) => connection,
+            Err(_) => return,
+        };
+
+        while let Some(Ok((_, mut respond))) = connection.accept().await {
+            let response = Response::builder()
+                .status(200)
+                .header("content-type", "application/grpc");
+
+            let mut status = HeaderMap::new();
+            status.insert("grpc-status", HeaderValue::from(code));
+            if !message.is_empty() {
+                status.insert("grpc-message", HeaderValue::from_static(message));
+            }
+
+            match serving_status {
+                Some(serving_status) => {
+                    let mut sender = respond.send_response(response.body(()).unwrap(), false).unwrap();
+                    let message = [0x08, serving_status as u8];
+                    let mut frame = vec![0, 0, 0, 0, message.len() as u8];
+                    frame.extend_from_slice(&message);
+                    sender.send_data(Bytes::from(frame), false).unwrap();
+                    sender.send_trailers(status).unwrap();
+                }
+                None => {
+                    let mut response = response.body(()).unwrap();
+                    response.headers_mut().extend(status);
+                    respond.send_response(response, true).unwrap();
+                }
+            }
+        }
+    });
+
+    port
+}
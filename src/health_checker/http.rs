@@ -15,17 +15,10 @@ pub(crate) struct Http;
 #[async_trait]
 impl HealthCheck for Http {
     async fn get_health(&self, configuration: &Configuration) -> Result<State, NetworkError> {
-        let mut url = Url::parse("http://localhost").unwrap();
-        url.set_port(Some(configuration.port.into())).unwrap();
         let x: String = configuration.path.clone().into();
-        url.set_path(&x);
+        let url = url(configuration, &x);
 
-        let client = Client::builder()
-            .timeout(configuration.timeout.into())
-            .build()
-            .unwrap();
-
-        let response = client
+        let response = client(configuration)
             .request(configuration.method.clone().into(), url.as_ref())
             .send()
             .await;
@@ -41,15 +34,7 @@ impl HealthCheck for Http {
                     Ok(State::Unhealthy(Other(content)))
                 }
             }
-            Err(e) => {
-                if e.is_timeout() {
-                    Ok(State::Unhealthy(Timeout(configuration.timeout.into())))
-                } else {
-                    Err(NetworkError {
-                        message: format!("network error: {}", e),
-                    })
-                }
-            }
+            Err(e) => failure(configuration, e),
         };
 
         match &result {
@@ -60,3 +45,28 @@ impl HealthCheck for Http {
         result
     }
 }
+
+pub(super) fn url(configuration: &Configuration, path: &str) -> Url {
+    let mut url = Url::parse("http://localhost").unwrap();
+    url.set_port(Some(configuration.port.into())).unwrap();
+    url.set_path(path);
+    url
+}
+
+pub(super) fn client(configuration: &Configuration) -> Client {
+    Client::builder()
+        .timeout(configuration.timeout.into())
+        .build()
+        .unwrap()
+}
+
+/// Reports a request timing out as unhealthy, and any other failure as a network error.
+pub(super) fn failure(configuration: &Configuration, error: reqwest::Error) -> Result<State, NetworkError> {
+    if error.is_timeout() {
+        Ok(State::Unhealthy(Timeout(configuration.timeout.into())))
+    } else {
+        Err(NetworkError {
+            message: format!("network error: {}", error),
+        })
+    }
+}
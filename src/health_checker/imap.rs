@@ -0,0 +1,98 @@
+use async_trait::async_trait;
+use log::debug;
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::net::TcpStream;
+use crate::configuration::Configuration;
+use crate::health_checker::Reason::Other;
+use crate::health_checker::wire::{network_error, run_with_timeout, LineConnection};
+use crate::health_checker::{tls, HealthCheck, NetworkError, State};
+
+#[cfg(test)]
+#[path = "./imap_test.rs"]
+mod test;
+
+pub(crate) struct Imap;
+
+#[async_trait]
+impl HealthCheck for Imap {
+    async fn get_health(&self, configuration: &Configuration) -> Result<State, NetworkError> {
+        run_with_timeout(configuration, check(configuration)).await
+    }
+}
+
+/// Sends a tagged command and returns the status and text of its completion, skipping the untagged responses.
+async fn command<S: AsyncRead + AsyncWrite + Unpin>(connection: &mut LineConnection<S>, tag: &str, command: &str) -> Result<(String, String), Result<State, NetworkError>> {
+    connection.send(&format!("{} {}", tag, command)).await?;
+
+    loop {
+        let line = connection.read_line().await?;
+
+        if let Some(completion) = line.strip_prefix(tag).and_then(|completion| completion.strip_prefix(' ')) {
+            let (status, text) = completion.split_once(' ').unwrap_or((completion, ""));
+            return Ok((status.to_uppercase(), text.to_string()));
+        }
+    }
+}
+
+async fn check(configuration: &Configuration) -> Result<State, Result<State, NetworkError>> {
+    let port: u16 = configuration.port.into();
+
+    let stream = TcpStream::connect(("localhost", port)).await
+        .map_err(network_error)?;
+
+    if configuration.tls {
+        let stream = tls::connect(stream, configuration, &[b"imap"]).await
+            .map_err(network_error)?;
+
+        session(LineConnection::new(stream), configuration).await
+    } else {
+        session(LineConnection::new(stream), configuration).await
+    }
+}
+
+async fn session<S: AsyncRead + AsyncWrite + Unpin>(mut connection: LineConnection<S>, configuration: &Configuration) -> Result<State, Result<State, NetworkError>> {
+    let greeting = connection.read_line().await?;
+
+    let (status, text) = match greeting.strip_prefix("* ") {
+        Some(response) => response.split_once(' ').unwrap_or((response, "")),
+        None => return Ok(State::Unhealthy(Other(format!("unexpected greeting '{}'", greeting)))),
+    };
+
+    let authenticated = match status.to_uppercase().as_str() {
+        "OK" => false,
+        "PREAUTH" => true,
+        "BYE" => return Ok(State::Unhealthy(Other(format!("connection refused '{}'", text)))),
+        _ => return Ok(State::Unhealthy(Other(format!("unexpected greeting '{}'", greeting)))),
+    };
+
+    debug!("server {}", text);
+
+    if let (Some(username), false) = (&configuration.username, authenticated) {
+        let username = String::from(username.clone());
+        let password = configuration.password.clone()
+            .map(String::from)
+            .unwrap_or_default();
+
+        debug!("logging in as '{}'", username);
+
+        let login = format!("LOGIN {} {}", quoted(&username), quoted(&password));
+
+        match command(&mut connection, "a1", &login).await? {
+            (status, _) if status == "OK" => {}
+            (status, text) if status == "NO" => return Ok(State::Unhealthy(Other(format!("login failed '{}'", text)))),
+            (status, text) => return Ok(State::Unhealthy(Other(format!("error response '{} {}'", status, text)))),
+        }
+    }
+
+    match command(&mut connection, "a2", "LOGOUT").await {
+        Ok((status, text)) if status != "OK" => debug!("logout failed: {} {}", status, text),
+        Ok(_) => {}
+        Err(e) => debug!("logout failed: {:?}", e),
+    }
+
+    Ok(State::Healthy)
+}
+
+fn quoted(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
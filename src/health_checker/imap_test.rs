@@ -0,0 +1,130 @@
+use crate::health_checker::Reason::Other;
+use crate::health_checker::State::Healthy;
+use crate::health_checker::State::Unhealthy;
+use assert2::{check, assert};
+use rstest::rstest;
+use std::net::TcpListener;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use testcontainers_modules::testcontainers::runners::AsyncRunner;
+use crate::configuration::fixtures::{an_imap_configuration, an_imap_configuration_with_credentials};
+use crate::health_checker::dovecot_container::{DovecotContainer, DOVECOT_PASSWORD, IMAP_PORT};
+use crate::health_checker::imap::Imap;
+use crate::health_checker::HealthCheck;
+
+#[tokio::test]
+async fn a_healthy_server_should_be_reported() {
+    let dovecot_container = DovecotContainer
+        .start()
+        .await
+        .unwrap();
+
+    let port = dovecot_container.get_host_port_ipv4(IMAP_PORT).await.unwrap();
+    let configuration = an_imap_configuration(port);
+
+    let result = Imap.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[tokio::test]
+async fn a_server_accepting_credentials_should_be_reported_as_healthy() {
+    let dovecot_container = DovecotContainer
+        .start()
+        .await
+        .unwrap();
+
+    let port = dovecot_container.get_host_port_ipv4(IMAP_PORT).await.unwrap();
+    let configuration = an_imap_configuration_with_credentials(port, "dockteur", DOVECOT_PASSWORD);
+
+    let result = Imap.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[tokio::test]
+async fn a_server_rejecting_credentials_should_be_reported_as_unhealthy() {
+    let dovecot_container = DovecotContainer
+        .start()
+        .await
+        .unwrap();
+
+    let port = dovecot_container.get_host_port_ipv4(IMAP_PORT).await.unwrap();
+    let configuration = an_imap_configuration_with_credentials(port, "dockteur", "wrong");
+
+    let result = Imap.get_health(&configuration).await;
+
+    assert!(let Ok(Unhealthy(Other(reason))) = result);
+    check!(reason.starts_with("login failed '[AUTHENTICATIONFAILED]"));
+}
+
+#[rstest]
+#[case::ok(&["* OK [CAPABILITY IMAP4rev1] ready", "a1 OK Logged in", "* BYE logging out\r\na2 OK Logout completed"])]
+#[case::preauthenticated(&["* PREAUTH ready", "* BYE logging out\r\na2 OK Logout completed"])]
+#[case::logged_in(&["* OK ready", "* CAPABILITY IMAP4rev1\r\na1 OK Logged in", "a2 OK Logout completed"])]
+#[tokio::test]
+async fn a_greeting_server_should_be_reported_as_healthy(#[case] replies: &[&str]) {
+    let port = a_server_replying(replies).await;
+    let configuration = an_imap_configuration_with_credentials(port, "dockteur", "secret");
+
+    let result = Imap.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[rstest]
+#[case::bye(&["* BYE too many connections"], "connection refused 'too many connections'")]
+#[case::not_imap(&["+OK POP3 ready"], "unexpected greeting '+OK POP3 ready'")]
+#[case::unknown_status(&["* NOPE ready"], "unexpected greeting '* NOPE ready'")]
+#[case::rejected_login(&["* OK ready", "a1 NO [AUTHENTICATIONFAILED] Authentication failed."], "login failed '[AUTHENTICATIONFAILED] Authentication failed.'")]
+#[case::disabled_login(&["* OK ready", "a1 BAD LOGIN is disabled"], "error response 'BAD LOGIN is disabled'")]
+#[tokio::test]
+async fn a_failing_server_should_be_reported_as_unhealthy(#[case] replies: &[&str], #[case] reason: &str) {
+    let port = a_server_replying(replies).await;
+    let configuration = an_imap_configuration_with_credentials(port, "dockteur", "secret");
+
+    let result = Imap.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other(reason.to_string())));
+}
+
+#[tokio::test]
+async fn unreachable_server_should_be_reported_as_error() {
+    let unused_port = TcpListener::bind("localhost:0").unwrap()
+        .local_addr().unwrap()
+        .port();
+    let configuration = an_imap_configuration(unused_port);
+
+    let result = Imap.get_health(&configuration).await;
+
+    assert!(let Err(error) = result);
+    check!(error.message.starts_with("network error"));
+}
+
+/// Starts a server sending the first reply as greeting, then each following reply once a command is received.
+async fn a_server_replying(replies: &[&str]) -> u16 {
+    let listener = tokio::net::TcpListener::bind("localhost:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let replies: Vec<String> = replies.iter().map(|reply| format!("{}\r\n", reply)).collect();
+
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut stream = BufReader::new(stream);
+        let mut command = String::new();
+
+        for (index, reply) in replies.iter().enumerate() {
+            if index > 0 && stream.read_line(&mut command).await.unwrap_or(0) == 0 {
+                return;
+            }
+
+            stream.get_mut().write_all(reply.as_bytes()).await.unwrap();
+        }
+
+        while stream.read_line(&mut command).await.unwrap_or(0) > 0 {}
+    });
+
+    port
+}
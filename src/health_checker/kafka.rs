@@ -0,0 +1,284 @@
+use async_trait::async_trait;
+use log::debug;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use crate::configuration::Configuration;
+use crate::health_checker::Reason::Other;
+use crate::health_checker::wire::{malformed, network_error, run_with_timeout, Reader};
+use crate::health_checker::{HealthCheck, NetworkError, State};
+
+#[cfg(test)]
+#[path = "./kafka_test.rs"]
+mod test;
+
+const CLIENT_ID: &str = "dockteur";
+
+const RESPONSE_MAX_SIZE: usize = 1 << 24;
+
+const API_VERSIONS: i16 = 18;
+
+const API_VERSIONS_VERSION: i16 = 0;
+
+const METADATA: i16 = 3;
+
+const METADATA_VERSION: i16 = 4;
+
+pub(crate) struct Kafka;
+
+#[async_trait]
+impl HealthCheck for Kafka {
+    async fn get_health(&self, configuration: &Configuration) -> Result<State, NetworkError> {
+        run_with_timeout(configuration, check(configuration)).await
+    }
+}
+
+struct ApiVersion {
+    key: i16,
+    min: i16,
+    max: i16,
+}
+
+struct Broker {
+    id: i32,
+    host: String,
+    port: i32,
+}
+
+struct Partition {
+    id: i32,
+    error_code: i16,
+    leader: i32,
+}
+
+struct Topic {
+    error_code: i16,
+    name: String,
+    partitions: Vec<Partition>,
+}
+
+struct Metadata {
+    brokers: Vec<Broker>,
+    controller_id: i32,
+    topics: Vec<Topic>,
+}
+
+async fn check(configuration: &Configuration) -> Result<State, Result<State, NetworkError>> {
+    let port: u16 = configuration.port.into();
+
+    let mut stream = TcpStream::connect(("localhost", port)).await
+        .map_err(network_error)?;
+
+    let versions = api_versions(&mut stream).await?;
+
+    if !configuration.kafka_check_metadata {
+        return Ok(State::Healthy);
+    }
+
+    match versions.iter().find(|version| version.key == METADATA) {
+        Some(version) if version.min <= METADATA_VERSION && METADATA_VERSION <= version.max => {}
+        Some(version) => return Ok(State::Unhealthy(Other(format!(
+            "unsupported metadata versions '{}' to '{}'", version.min, version.max,
+        )))),
+        None => return Ok(State::Unhealthy(Other(String::from("metadata requests are not supported")))),
+    }
+
+    let topics = configuration.kafka_topics.names();
+    let metadata = metadata(&mut stream, topics).await?;
+
+    debug!("{} brokers, controller {}", metadata.brokers.len(), metadata.controller_id);
+
+    // the configuration requires the broker id along with the metadata check
+    if let Some(id) = configuration.kafka_broker_id {
+        match metadata.brokers.iter().find(|broker| broker.id == i32::from(id)) {
+            Some(broker) => debug!("broker {} advertised as {}:{}", broker.id, broker.host, broker.port),
+            None => return Ok(State::Unhealthy(Other(format!("broker '{}' is not part of the cluster metadata", i32::from(id))))),
+        }
+    }
+
+    Ok(check_topics(topics, &metadata.topics))
+}
+
+fn check_topics(expected: &[String], topics: &[Topic]) -> State {
+    for name in expected {
+        let topic = match topics.iter().find(|topic| &topic.name == name) {
+            Some(topic) => topic,
+            None => return State::Unhealthy(Other(format!("topic '{}' is missing from the cluster metadata", name))),
+        };
+
+        if topic.error_code != 0 {
+            return State::Unhealthy(Other(format!("topic '{}' is unavailable '{}'", name, describe_error(topic.error_code))));
+        }
+
+        if let Some(partition) = topic.partitions.iter().find(|partition| partition.error_code != 0) {
+            return State::Unhealthy(Other(format!(
+                "topic '{}' partition '{}' is unavailable '{}'",
+                name, partition.id, describe_error(partition.error_code),
+            )));
+        }
+
+        let leaderless = topic.partitions.iter().filter(|partition| partition.leader < 0).count();
+
+        if leaderless > 0 {
+            return State::Unhealthy(Other(format!(
+                "topic '{}' has '{}' of '{}' partitions without a leader",
+                name, leaderless, topic.partitions.len(),
+            )));
+        }
+    }
+
+    State::Healthy
+}
+
+async fn api_versions(stream: &mut TcpStream) -> Result<Vec<ApiVersion>, Result<State, NetworkError>> {
+    let body = exchange(stream, API_VERSIONS, API_VERSIONS_VERSION, 1, &[]).await?;
+    let mut response = Reader::new(&body, "response");
+
+    let error_code = response.i16()?;
+
+    if error_code != 0 {
+        return Err(Ok(State::Unhealthy(Other(format!("error response '{}'", describe_error(error_code))))));
+    }
+
+    let mut versions = Vec::new();
+
+    for _ in 0..array_length(&mut response)? {
+        versions.push(ApiVersion {
+            key: response.i16()?,
+            min: response.i16()?,
+            max: response.i16()?,
+        });
+    }
+
+    Ok(versions)
+}
+
+async fn metadata(stream: &mut TcpStream, topics: &[String]) -> Result<Metadata, Result<State, NetworkError>> {
+    let mut request = Vec::new();
+    request.extend_from_slice(&(topics.len() as i32).to_be_bytes());
+    for topic in topics {
+        string(&mut request, topic);
+    }
+    request.push(0);
+
+    let body = exchange(stream, METADATA, METADATA_VERSION, 2, &request).await?;
+    let mut response = Reader::new(&body, "response");
+
+    response.i32()?;
+
+    let mut brokers = Vec::new();
+
+    for _ in 0..array_length(&mut response)? {
+        let id = response.i32()?;
+        let host = nullable_string(&mut response)?.unwrap_or_default();
+        let port = response.i32()?;
+        nullable_string(&mut response)?;
+        brokers.push(Broker { id, host, port });
+    }
+
+    nullable_string(&mut response)?;
+    let controller_id = response.i32()?;
+
+    let mut topics = Vec::new();
+
+    for _ in 0..array_length(&mut response)? {
+        let error_code = response.i16()?;
+        let name = nullable_string(&mut response)?.unwrap_or_default();
+        bool(&mut response)?;
+
+        let mut partitions = Vec::new();
+
+        for _ in 0..array_length(&mut response)? {
+            let error_code = response.i16()?;
+            let id = response.i32()?;
+            let leader = response.i32()?;
+            i32_array(&mut response)?;
+            i32_array(&mut response)?;
+            partitions.push(Partition { id, error_code, leader });
+        }
+
+        topics.push(Topic { error_code, name, partitions });
+    }
+
+    Ok(Metadata { brokers, controller_id, topics })
+}
+
+/// Sends a request and returns the body of its response, following the response header.
+async fn exchange(stream: &mut TcpStream, api_key: i16, api_version: i16, correlation_id: i32, body: &[u8]) -> Result<Vec<u8>, Result<State, NetworkError>> {
+    let mut request = Vec::new();
+    request.extend_from_slice(&api_key.to_be_bytes());
+    request.extend_from_slice(&api_version.to_be_bytes());
+    request.extend_from_slice(&correlation_id.to_be_bytes());
+    string(&mut request, CLIENT_ID);
+    request.extend_from_slice(body);
+
+    let mut frame = (request.len() as i32).to_be_bytes().to_vec();
+    frame.extend_from_slice(&request);
+
+    stream.write_all(&frame).await
+        .map_err(network_error)?;
+
+    let mut size = [0u8; 4];
+    stream.read_exact(&mut size).await
+        .map_err(network_error)?;
+
+    let size = i32::from_be_bytes(size);
+
+    if size < 4 || size as usize > RESPONSE_MAX_SIZE {
+        return Err(malformed("response"));
+    }
+
+    let mut response = vec![0u8; size as usize];
+    stream.read_exact(&mut response).await
+        .map_err(network_error)?;
+
+    let body = response.split_off(4);
+
+    if response != correlation_id.to_be_bytes() {
+        return Err(malformed("response"));
+    }
+
+    Ok(body)
+}
+
+fn string(buffer: &mut Vec<u8>, value: &str) {
+    buffer.extend_from_slice(&(value.len() as i16).to_be_bytes());
+    buffer.extend_from_slice(value.as_bytes());
+}
+
+fn describe_error(code: i16) -> String {
+    let name = match code {
+        3 => "UNKNOWN_TOPIC_OR_PARTITION",
+        5 => "LEADER_NOT_AVAILABLE",
+        9 => "REPLICA_NOT_AVAILABLE",
+        17 => "INVALID_TOPIC_EXCEPTION",
+        29 => "TOPIC_AUTHORIZATION_FAILED",
+        35 => "UNSUPPORTED_VERSION",
+        _ => return code.to_string(),
+    };
+
+    format!("{} {}", code, name)
+}
+
+fn bool(response: &mut Reader) -> Result<bool, Result<State, NetworkError>> {
+    Ok(response.u8()? != 0)
+}
+
+fn array_length(response: &mut Reader) -> Result<usize, Result<State, NetworkError>> {
+    Ok(response.i32()?.max(0) as usize)
+}
+
+fn i32_array(response: &mut Reader) -> Result<(), Result<State, NetworkError>> {
+    let length = array_length(response)?;
+    response.take(length.saturating_mul(4))?;
+    Ok(())
+}
+
+fn nullable_string(response: &mut Reader) -> Result<Option<String>, Result<State, NetworkError>> {
+    let length = response.i16()?;
+
+    if length < 0 {
+        return Ok(None);
+    }
+
+    response.string(length as usize).map(Some)
+}
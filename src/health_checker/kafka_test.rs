@@ -0,0 +1,191 @@
+use crate::health_checker::Reason::Other;
+use crate::health_checker::State::Healthy;
+use crate::health_checker::State::Unhealthy;
+use assert2::{check, assert};
+use rstest::rstest;
+use std::net::TcpListener;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use testcontainers_modules::kafka::apache::{Kafka as KafkaContainer, KAFKA_PORT};
+use testcontainers_modules::testcontainers::runners::AsyncRunner;
+use crate::configuration::fixtures::{a_kafka_configuration, a_kafka_configuration_with_metadata};
+use crate::health_checker::kafka::{check_topics, Kafka, Partition, Topic};
+use crate::health_checker::{HealthCheck, State};
+
+#[tokio::test]
+async fn a_healthy_broker_should_be_reported() {
+    let kafka_container = KafkaContainer::default()
+        .start()
+        .await
+        .unwrap();
+
+    let port = kafka_container.get_host_port_ipv4(KAFKA_PORT).await.unwrap();
+    let configuration = a_kafka_configuration(port);
+
+    let result = Kafka.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[tokio::test]
+async fn a_broker_part_of_the_cluster_metadata_should_be_reported_as_healthy() {
+    let kafka_container = KafkaContainer::default()
+        .start()
+        .await
+        .unwrap();
+
+    let port = kafka_container.get_host_port_ipv4(KAFKA_PORT).await.unwrap();
+    let configuration = a_kafka_configuration_with_metadata(port, Some(1), &[]);
+
+    let result = Kafka.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[tokio::test]
+async fn a_broker_missing_from_the_cluster_metadata_should_be_reported_as_unhealthy() {
+    let kafka_container = KafkaContainer::default()
+        .start()
+        .await
+        .unwrap();
+
+    let port = kafka_container.get_host_port_ipv4(KAFKA_PORT).await.unwrap();
+    let configuration = a_kafka_configuration_with_metadata(port, Some(2), &[]);
+
+    let result = Kafka.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other(String::from("broker '2' is not part of the cluster metadata"))));
+}
+
+#[tokio::test]
+async fn an_unknown_topic_should_be_reported_as_unhealthy() {
+    let kafka_container = KafkaContainer::default()
+        .start()
+        .await
+        .unwrap();
+
+    let port = kafka_container.get_host_port_ipv4(KAFKA_PORT).await.unwrap();
+    let configuration = a_kafka_configuration_with_metadata(port, Some(1), &["missing"]);
+
+    let result = Kafka.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other(String::from("topic 'missing' is unavailable '3 UNKNOWN_TOPIC_OR_PARTITION'"))));
+}
+
+#[rstest]
+#[case::local_broker_present(2, Healthy)]
+#[case::local_broker_absent(3, Unhealthy(Other(String::from("broker '3' is not part of the cluster metadata"))))]
+#[tokio::test]
+async fn the_local_broker_should_be_told_apart_from_brokers_advertising_the_same_port(#[case] broker_id: i32, #[case] expected: State) {
+    let port = a_broker_answering(&[(1, "kafka-1", 9092), (2, "kafka-2", 9092)]).await;
+    let configuration = a_kafka_configuration_with_metadata(port, Some(broker_id), &[]);
+
+    let result = Kafka.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == expected);
+}
+
+#[tokio::test]
+async fn unreachable_broker_should_be_reported_as_error() {
+    let unused_port = TcpListener::bind("localhost:0").unwrap()
+        .local_addr().unwrap()
+        .port();
+    let configuration = a_kafka_configuration(unused_port);
+
+    let result = Kafka.get_health(&configuration).await;
+
+    assert!(let Err(error) = result);
+    check!(error.message.starts_with("network error"));
+}
+
+#[test]
+fn topics_with_leaders_should_be_healthy() {
+    let topics = vec![a_topic("orders", &[1, 2, 3])];
+
+    let result = check_topics(&[String::from("orders")], &topics);
+
+    check!(result == Healthy);
+}
+
+#[test]
+fn topics_with_partitions_without_leader_should_be_unhealthy() {
+    let topics = vec![a_topic("orders", &[1, -1, -1])];
+
+    let result = check_topics(&[String::from("orders")], &topics);
+
+    check!(result == Unhealthy(Other(String::from("topic 'orders' has '2' of '3' partitions without a leader"))));
+}
+
+#[test]
+fn topics_with_partitions_in_error_should_be_unhealthy() {
+    let mut topic = a_topic("orders", &[1, 2]);
+    topic.partitions[1].error_code = 5;
+
+    let result = check_topics(&[String::from("orders")], &[topic]);
+
+    check!(result == Unhealthy(Other(String::from("topic 'orders' partition '1' is unavailable '5 LEADER_NOT_AVAILABLE'"))));
+}
+
+#[test]
+fn topics_missing_from_the_response_should_be_unhealthy() {
+    let topics = vec![a_topic("orders", &[1])];
+
+    let result = check_topics(&[String::from("payments")], &topics);
+
+    check!(result == Unhealthy(Other(String::from("topic 'payments' is missing from the cluster metadata"))));
+}
+
+fn a_topic(name: &str, leaders: &[i32]) -> Topic {
+    Topic {
+        error_code: 0,
+        name: name.to_string(),
+        partitions: leaders.iter()
+            .enumerate()
+            .map(|(id, &leader)| Partition { id: id as i32, error_code: 0, leader })
+            .collect(),
+    }
+}
+
+/// Starts a broker answering the ApiVersions request, then the Metadata request with the given brokers and no topic.
+async fn a_broker_answering(brokers: &[(i32, &str, i32)]) -> u16 {
+    let listener = tokio::net::TcpListener::bind("localhost:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    let mut api_versions = vec![0, 0];
+    api_versions.extend_from_slice(&1i32.to_be_bytes());
+    api_versions.extend_from_slice(&[0, 3, 0, 0, 0, 12]);
+
+    let mut metadata = 0i32.to_be_bytes().to_vec();
+    metadata.extend_from_slice(&(brokers.len() as i32).to_be_bytes());
+    for (id, host, port) in brokers {
+        metadata.extend_from_slice(&id.to_be_bytes());
+        metadata.extend_from_slice(&(host.len() as i16).to_be_bytes());
+        metadata.extend_from_slice(host.as_bytes());
+        metadata.extend_from_slice(&port.to_be_bytes());
+        metadata.extend_from_slice(&(-1i16).to_be_bytes());
+    }
+    metadata.extend_from_slice(&(-1i16).to_be_bytes());
+    metadata.extend_from_slice(&brokers[0].0.to_be_bytes());
+    metadata.extend_from_slice(&0i32.to_be_bytes());
+
+    tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+
+        for body in [api_versions, metadata] {
+            let size = stream.read_i32().await.unwrap();
+            let mut request = vec![0u8; size as usize];
+            stream.read_exact(&mut request).await.unwrap();
+
+            let mut response = ((body.len() + 4) as i32).to_be_bytes().to_vec();
+            response.extend_from_slice(&request[4..8]);
+            response.extend_from_slice(&body);
+            stream.write_all(&response).await.unwrap();
+        }
+    });
+
+    port
+}
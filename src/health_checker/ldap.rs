@@ -0,0 +1,352 @@
+use async_trait::async_trait;
+use log::debug;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::net::TcpStream;
+use crate::configuration::Configuration;
+use crate::health_checker::Reason::Other;
+use crate::health_checker::wire::{malformed, network_error, run_with_timeout, Reader};
+use crate::health_checker::{tls, HealthCheck, NetworkError, State};
+
+#[cfg(test)]
+#[path = "./ldap_test.rs"]
+mod test;
+
+const MESSAGE_MAX_SIZE: usize = 1024 * 1024;
+
+const LDAP_VERSION: u8 = 3;
+
+const SEQUENCE: u8 = 0x30;
+const BOOLEAN: u8 = 0x01;
+const INTEGER: u8 = 0x02;
+const OCTET_STRING: u8 = 0x04;
+const ENUMERATED: u8 = 0x0A;
+const SIMPLE_AUTHENTICATION: u8 = 0x80;
+const PRESENT_FILTER: u8 = 0x87;
+
+const BIND_REQUEST: u8 = 0x60;
+const BIND_RESPONSE: u8 = 0x61;
+const UNBIND_REQUEST: u8 = 0x42;
+const SEARCH_REQUEST: u8 = 0x63;
+const SEARCH_RESULT_ENTRY: u8 = 0x64;
+const SEARCH_RESULT_DONE: u8 = 0x65;
+const SEARCH_RESULT_REFERENCE: u8 = 0x73;
+const EXTENDED_RESPONSE: u8 = 0x78;
+
+const SUCCESS: i64 = 0;
+
+// unsolicited notifications, like the notice of disconnection, are sent with this message ID
+const UNSOLICITED_ID: i64 = 0;
+
+pub(crate) struct Ldap;
+
+#[async_trait]
+impl HealthCheck for Ldap {
+    async fn get_health(&self, configuration: &Configuration) -> Result<State, NetworkError> {
+        run_with_timeout(configuration, check(configuration)).await
+    }
+}
+
+struct Message {
+    operation: u8,
+    content: Vec<u8>,
+}
+
+struct LdapResult {
+    code: i64,
+    diagnostic: String,
+}
+
+struct Connection<S> {
+    stream: S,
+    last_id: i64,
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin> Connection<S> {
+
+    fn new(stream: S) -> Self {
+        Connection { stream, last_id: 0 }
+    }
+
+    async fn send(&mut self, operation: u8, content: &[u8]) -> Result<i64, Result<State, NetworkError>> {
+        self.last_id += 1;
+
+        let message = element(SEQUENCE, &[integer(self.last_id), element(operation, content)].concat());
+
+        self.stream.write_all(&message).await
+            .map_err(network_error)?;
+
+        Ok(self.last_id)
+    }
+
+    /// Reads the next message answering the given request, failing on unsolicited notifications.
+    async fn read_message(&mut self, id: i64) -> Result<Message, Result<State, NetworkError>> {
+        loop {
+            let envelope = self.read_envelope().await?;
+            let mut reader = Reader::new(&envelope, "response");
+            let message_id = read_integer(&mut reader)?;
+            let (operation, content) = read_element(&mut reader)?;
+
+            if message_id == UNSOLICITED_ID && operation == EXTENDED_RESPONSE {
+                let result = ldap_result(content)?;
+                return Err(Ok(State::Unhealthy(Other(format!("connection closed '{}'", describe(&result))))));
+            }
+
+            if message_id == id {
+                return Ok(Message { operation, content: content.to_vec() });
+            }
+
+            debug!("ignoring message {}", message_id);
+        }
+    }
+
+    /// Reads the sequence holding a message, before its whole length is received when it isn't one.
+    async fn read_envelope(&mut self) -> Result<Vec<u8>, Result<State, NetworkError>> {
+        let mut header = [0u8; 2];
+        self.stream.read_exact(&mut header).await
+            .map_err(network_error)?;
+
+        if header[0] != SEQUENCE {
+            return Err(malformed("response"));
+        }
+
+        let length = match header[1] {
+            length if length & 0x80 == 0 => length as usize,
+            length => {
+                let count = (length & 0x7F) as usize;
+
+                if count == 0 || count > 4 {
+                    return Err(malformed("response"));
+                }
+
+                let mut bytes = [0u8; 4];
+                self.stream.read_exact(&mut bytes[4 - count..]).await
+                    .map_err(network_error)?;
+
+                u32::from_be_bytes(bytes) as usize
+            }
+        };
+
+        if length > MESSAGE_MAX_SIZE {
+            return Err(malformed("response"));
+        }
+
+        let mut content = vec![0u8; length];
+        self.stream.read_exact(&mut content).await
+            .map_err(network_error)?;
+
+        Ok(content)
+    }
+}
+
+async fn check(configuration: &Configuration) -> Result<State, Result<State, NetworkError>> {
+    let port: u16 = configuration.port.into();
+
+    let stream = TcpStream::connect(("localhost", port)).await
+        .map_err(network_error)?;
+
+    if configuration.tls {
+        let stream = tls::connect(stream, configuration, &[]).await
+            .map_err(network_error)?;
+
+        session(Connection::new(stream), configuration).await
+    } else {
+        session(Connection::new(stream), configuration).await
+    }
+}
+
+async fn session<S: AsyncRead + AsyncWrite + Unpin>(mut connection: Connection<S>, configuration: &Configuration) -> Result<State, Result<State, NetworkError>> {
+    let name = configuration.username.clone()
+        .map(String::from)
+        .unwrap_or_default();
+    let password = configuration.password.clone()
+        .map(String::from)
+        .unwrap_or_default();
+
+    match name.as_str() {
+        "" => debug!("binding anonymously"),
+        name => debug!("binding as '{}'", name),
+    }
+
+    let id = connection.send(BIND_REQUEST, &bind_request(&name, &password)).await?;
+    let response = connection.read_message(id).await?;
+
+    if response.operation != BIND_RESPONSE {
+        return Err(malformed("response"));
+    }
+
+    let result = ldap_result(&response.content)?;
+
+    if result.code != SUCCESS {
+        return Ok(State::Unhealthy(Other(format!("bind failed '{}'", describe(&result)))));
+    }
+
+    let id = connection.send(SEARCH_REQUEST, &root_dse_search_request()).await?;
+
+    loop {
+        let response = connection.read_message(id).await?;
+
+        match response.operation {
+            SEARCH_RESULT_ENTRY | SEARCH_RESULT_REFERENCE => {}
+            SEARCH_RESULT_DONE => {
+                let result = ldap_result(&response.content)?;
+
+                if result.code != SUCCESS {
+                    return Ok(State::Unhealthy(Other(format!("search failed '{}'", describe(&result)))));
+                }
+
+                break;
+            }
+            _ => return Err(malformed("response")),
+        }
+    }
+
+    if let Err(e) = connection.send(UNBIND_REQUEST, &[]).await {
+        debug!("unbind failed: {:?}", e);
+    }
+
+    Ok(State::Healthy)
+}
+
+fn bind_request(name: &str, password: &str) -> Vec<u8> {
+    [
+        integer(LDAP_VERSION as i64),
+        element(OCTET_STRING, name.as_bytes()),
+        element(SIMPLE_AUTHENTICATION, password.as_bytes()),
+    ].concat()
+}
+
+/// Searches the entry with an empty name, holding the information about the server itself.
+fn root_dse_search_request() -> Vec<u8> {
+    let base_object_scope = element(ENUMERATED, &[0]);
+    let never_dereference_aliases = element(ENUMERATED, &[0]);
+    let no_limit = integer(0);
+    let types_only = element(BOOLEAN, &[0]);
+
+    [
+        element(OCTET_STRING, b""),
+        base_object_scope,
+        never_dereference_aliases,
+        no_limit.clone(),
+        no_limit,
+        types_only,
+        element(PRESENT_FILTER, b"objectClass"),
+        element(SEQUENCE, &element(OCTET_STRING, b"namingContexts")),
+    ].concat()
+}
+
+fn ldap_result(content: &[u8]) -> Result<LdapResult, Result<State, NetworkError>> {
+    let mut reader = Reader::new(content, "response");
+
+    let code = match read_element(&mut reader)? {
+        (ENUMERATED, bytes) => signed(bytes)?,
+        _ => return Err(malformed("response")),
+    };
+    let _matched_name = read_element(&mut reader)?;
+    let (_, diagnostic) = read_element(&mut reader)?;
+
+    Ok(LdapResult { code, diagnostic: String::from_utf8_lossy(diagnostic).into_owned() })
+}
+
+fn describe(result: &LdapResult) -> String {
+    let code = match code_name(result.code) {
+        Some(name) => format!("{} {}", result.code, name),
+        None => result.code.to_string(),
+    };
+
+    match result.diagnostic.as_str() {
+        "" => code,
+        diagnostic => format!("{}: {}", code, diagnostic),
+    }
+}
+
+fn code_name(code: i64) -> Option<&'static str> {
+    let name = match code {
+        1 => "operationsError",
+        2 => "protocolError",
+        3 => "timeLimitExceeded",
+        4 => "sizeLimitExceeded",
+        7 => "authMethodNotSupported",
+        8 => "strongerAuthRequired",
+        11 => "adminLimitExceeded",
+        13 => "confidentialityRequired",
+        32 => "noSuchObject",
+        34 => "invalidDNSyntax",
+        48 => "inappropriateAuthentication",
+        49 => "invalidCredentials",
+        50 => "insufficientAccessRights",
+        51 => "busy",
+        52 => "unavailable",
+        53 => "unwillingToPerform",
+        80 => "other",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+fn element(tag: u8, content: &[u8]) -> Vec<u8> {
+    let mut element = vec![tag];
+
+    match content.len() {
+        length if length < 0x80 => element.push(length as u8),
+        length => {
+            let bytes = (length as u32).to_be_bytes();
+            let significant = bytes.iter().skip_while(|&&byte| byte == 0).count();
+            element.push(0x80 | significant as u8);
+            element.extend_from_slice(&bytes[4 - significant..]);
+        }
+    }
+
+    element.extend_from_slice(content);
+    element
+}
+
+fn integer(value: i64) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+
+    // the shortest two's complement encoding keeps a single sign bit
+    let mut start = 0;
+    while start < 7 && ((bytes[start] == 0 && bytes[start + 1] & 0x80 == 0) || (bytes[start] == 0xFF && bytes[start + 1] & 0x80 != 0)) {
+        start += 1;
+    }
+
+    element(INTEGER, &bytes[start..])
+}
+
+fn signed(bytes: &[u8]) -> Result<i64, Result<State, NetworkError>> {
+    if bytes.is_empty() || bytes.len() > 8 {
+        return Err(malformed("response"));
+    }
+
+    let fill = if bytes[0] & 0x80 != 0 { 0xFF } else { 0 };
+    let mut value = [fill; 8];
+    value[8 - bytes.len()..].copy_from_slice(bytes);
+
+    Ok(i64::from_be_bytes(value))
+}
+
+fn read_element<'a>(reader: &mut Reader<'a>) -> Result<(u8, &'a [u8]), Result<State, NetworkError>> {
+    let tag = reader.u8()?;
+
+    let length = match reader.u8()? {
+        length if length & 0x80 == 0 => length as usize,
+        length => {
+            let count = (length & 0x7F) as usize;
+
+            if count == 0 || count > 4 {
+                return Err(malformed("response"));
+            }
+
+            reader.take(count)?.iter().fold(0, |length, &byte| (length << 8) | byte as usize)
+        }
+    };
+
+    Ok((tag, reader.take(length)?))
+}
+
+fn read_integer(reader: &mut Reader) -> Result<i64, Result<State, NetworkError>> {
+    match read_element(reader)? {
+        (INTEGER, bytes) => signed(bytes),
+        _ => Err(malformed("response")),
+    }
+}
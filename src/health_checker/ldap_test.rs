@@ -0,0 +1,171 @@
+use crate::health_checker::Reason::Other;
+use crate::health_checker::State::Healthy;
+use crate::health_checker::State::Unhealthy;
+use assert2::{check, assert};
+use rstest::rstest;
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use testcontainers_modules::testcontainers::runners::AsyncRunner;
+use crate::configuration::fixtures::{an_ldap_configuration, an_ldap_configuration_with_credentials};
+use crate::health_checker::ldap::{bind_request, element, integer, Ldap, BIND_REQUEST, BIND_RESPONSE, ENUMERATED, EXTENDED_RESPONSE, OCTET_STRING, SEARCH_RESULT_DONE, SEARCH_RESULT_ENTRY, SEARCH_RESULT_REFERENCE, SEQUENCE};
+use crate::health_checker::openldap_container::{OpenldapContainer, ADMIN_DN, ADMIN_PASSWORD, LDAP_PORT};
+use crate::health_checker::HealthCheck;
+
+#[rstest]
+#[case::anonymous(None)]
+#[case::simple((ADMIN_DN, ADMIN_PASSWORD))]
+#[tokio::test]
+async fn a_healthy_server_should_be_reported(#[case] credentials: impl Into<Option<(&str, &str)>>) {
+    let openldap_container = OpenldapContainer
+        .start()
+        .await
+        .unwrap();
+
+    let port = openldap_container.get_host_port_ipv4(LDAP_PORT).await.unwrap();
+    let configuration = match credentials.into() {
+        None => an_ldap_configuration(port),
+        Some((name, password)) => an_ldap_configuration_with_credentials(port, name, password),
+    };
+
+    let result = Ldap.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[tokio::test]
+async fn a_server_accepting_an_anonymous_bind_should_be_reported_as_healthy() {
+    let (port, requests) = a_server_replying(vec![
+        a_message(1, BIND_RESPONSE, &a_result(0, "")),
+        [
+            a_message(2, SEARCH_RESULT_ENTRY, &[element(OCTET_STRING, b""), element(SEQUENCE, b"")].concat()),
+            a_message(2, SEARCH_RESULT_REFERENCE, &element(OCTET_STRING, b"ldap://other.example.org/")),
+            a_message(2, SEARCH_RESULT_DONE, &a_result(0, "")),
+        ].concat(),
+    ]).await;
+    let configuration = an_ldap_configuration(port);
+
+    let result = Ldap.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+    check!(requests.lock().unwrap()[0] == a_message(1, BIND_REQUEST, &bind_request("", "")));
+}
+
+#[tokio::test]
+async fn credentials_should_be_used_to_bind() {
+    let (port, requests) = a_server_replying(vec![
+        a_message(1, BIND_RESPONSE, &a_result(0, "")),
+        a_message(2, SEARCH_RESULT_DONE, &a_result(0, "")),
+    ]).await;
+    let configuration = an_ldap_configuration_with_credentials(port, "cn=admin,dc=example,dc=org", "secret");
+
+    let result = Ldap.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+    check!(requests.lock().unwrap()[0] == a_message(1, BIND_REQUEST, &bind_request("cn=admin,dc=example,dc=org", "secret")));
+}
+
+#[rstest]
+#[case::invalid_credentials(vec![a_message(1, BIND_RESPONSE, &a_result(49, "invalid credentials"))], "bind failed '49 invalidCredentials: invalid credentials'")]
+#[case::unwilling(vec![a_message(1, BIND_RESPONSE, &a_result(53, ""))], "bind failed '53 unwillingToPerform'")]
+#[case::unnamed(vec![a_message(1, BIND_RESPONSE, &a_result(99, "custom"))], "bind failed '99: custom'")]
+#[case::search(vec![a_message(1, BIND_RESPONSE, &a_result(0, "")), a_message(2, SEARCH_RESULT_DONE, &a_result(32, ""))], "search failed '32 noSuchObject'")]
+#[case::disconnection(vec![a_message(0, EXTENDED_RESPONSE, &a_result(52, "shutting down"))], "connection closed '52 unavailable: shutting down'")]
+#[tokio::test]
+async fn a_failing_server_should_be_reported_as_unhealthy(#[case] replies: Vec<Vec<u8>>, #[case] reason: &str) {
+    let (port, _) = a_server_replying(replies).await;
+    let configuration = an_ldap_configuration(port);
+
+    let result = Ldap.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other(reason.to_string())));
+}
+
+#[tokio::test]
+async fn a_server_not_speaking_ldap_should_be_reported_as_error() {
+    let (port, _) = a_server_replying(vec![b"HTTP/1.1 400 Bad Request\r\n\r\n".to_vec()]).await;
+    let configuration = an_ldap_configuration(port);
+
+    let result = Ldap.get_health(&configuration).await;
+
+    assert!(let Err(error) = result);
+    check!(error.message == "network error: malformed response");
+}
+
+#[tokio::test]
+async fn unreachable_server_should_be_reported_as_error() {
+    let unused_port = TcpListener::bind("localhost:0").unwrap()
+        .local_addr().unwrap()
+        .port();
+    let configuration = an_ldap_configuration(unused_port);
+
+    let result = Ldap.get_health(&configuration).await;
+
+    assert!(let Err(error) = result);
+    check!(error.message.starts_with("network error"));
+}
+
+#[rstest]
+#[case::zero(0, vec![0x02, 0x01, 0x00])]
+#[case::one_byte(127, vec![0x02, 0x01, 0x7F])]
+#[case::sign_bit(128, vec![0x02, 0x02, 0x00, 0x80])]
+#[case::two_bytes(256, vec![0x02, 0x02, 0x01, 0x00])]
+#[case::negative(-1, vec![0x02, 0x01, 0xFF])]
+fn integer_should_use_the_shortest_encoding(#[case] value: i64, #[case] encoded: Vec<u8>) {
+    check!(integer(value) == encoded);
+}
+
+#[test]
+fn long_content_should_use_the_long_length_form() {
+    let result = element(OCTET_STRING, &[0; 300]);
+
+    check!(result[..4] == [0x04, 0x82, 0x01, 0x2C]);
+    check!(result.len() == 304);
+}
+
+fn a_message(id: i64, operation: u8, content: &[u8]) -> Vec<u8> {
+    element(SEQUENCE, &[integer(id), element(operation, content)].concat())
+}
+
+fn a_result(code: u8, diagnostic: &str) -> Vec<u8> {
+    [
+        element(ENUMERATED, &[code]),
+        element(OCTET_STRING, b""),
+        element(OCTET_STRING, diagnostic.as_bytes()),
+    ].concat()
+}
+
+/// Starts a server sending each reply once a request is received, recording the received requests.
+async fn a_server_replying(replies: Vec<Vec<u8>>) -> (u16, Arc<Mutex<Vec<Vec<u8>>>>) {
+    let listener = tokio::net::TcpListener::bind("localhost:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let received = requests.clone();
+
+    tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut replies = replies.into_iter();
+
+        loop {
+            // the requests are short enough to use the short length form
+            let mut header = [0u8; 2];
+            if stream.read_exact(&mut header).await.is_err() {
+                return;
+            }
+
+            let mut request = vec![0u8; header[1] as usize];
+            stream.read_exact(&mut request).await.unwrap();
+            received.lock().unwrap().push([header.to_vec(), request].concat());
+
+            if let Some(reply) = replies.next() {
+                stream.write_all(&reply).await.unwrap();
+            }
+        }
+    });
+
+    (port, requests)
+}
@@ -0,0 +1,36 @@
+use std::borrow::Cow;
+use testcontainers_modules::testcontainers::core::wait::LogWaitStrategy;
+use testcontainers_modules::testcontainers::core::ContainerPort::Tcp;
+use testcontainers_modules::testcontainers::core::{ContainerPort, WaitFor};
+use testcontainers_modules::testcontainers::Image;
+
+pub const SMTP_PORT: u16 = 1025;
+
+#[derive(Default)]
+pub struct MailpitContainer;
+
+impl Image for MailpitContainer {
+    fn name(&self) -> &str {
+        "axllent/mailpit"
+    }
+
+    fn tag(&self) -> &str {
+        "v1.24"
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::log(LogWaitStrategy::stderr("[smtpd] starting on"))]
+    }
+
+    fn env_vars(&self) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        // a self-signed certificate is generated for these names, enabling STARTTLS
+        [
+            ("MP_SMTP_TLS_CERT", "sans:localhost"),
+            ("MP_SMTP_TLS_KEY", "sans:localhost"),
+        ]
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[Tcp(SMTP_PORT)]
+    }
+}
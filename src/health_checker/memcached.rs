@@ -0,0 +1,105 @@
+use async_trait::async_trait;
+use log::debug;
+use tokio::net::TcpStream;
+use crate::configuration::{Configuration, MemcachedCheck, MemcachedKeyPrefix};
+use crate::health_checker::Reason::Other;
+use crate::health_checker::wire::{network_error, run_with_timeout, LineConnection};
+use crate::health_checker::{unique_token, HealthCheck, NetworkError, State};
+
+#[cfg(test)]
+#[path = "./memcached_test.rs"]
+mod test;
+
+const ROUND_TRIP_KEY_TTL_SECONDS: u64 = 30;
+
+pub(crate) struct Memcached;
+
+#[async_trait]
+impl HealthCheck for Memcached {
+    async fn get_health(&self, configuration: &Configuration) -> Result<State, NetworkError> {
+        run_with_timeout(configuration, check(configuration)).await
+    }
+}
+
+async fn check(configuration: &Configuration) -> Result<State, Result<State, NetworkError>> {
+    let port: u16 = configuration.port.into();
+
+    let stream = TcpStream::connect(("localhost", port)).await
+        .map_err(network_error)?;
+
+    let mut connection = LineConnection::new(stream);
+
+    version(&mut connection).await?;
+
+    match configuration.memcached_check {
+        MemcachedCheck::Version => Ok(State::Healthy),
+        MemcachedCheck::RoundTrip => round_trip(&mut connection, &configuration.memcached_key_prefix).await,
+    }
+}
+
+async fn version(connection: &mut LineConnection<TcpStream>) -> Result<(), Result<State, NetworkError>> {
+    connection.send("version").await?;
+
+    let line = connection.read_line().await?;
+
+    match line.strip_prefix("VERSION ") {
+        Some(version) => {
+            debug!("server version {}", version);
+            Ok(())
+        }
+        None => Err(Ok(unhealthy_on_reply(line, "error response"))),
+    }
+}
+
+async fn round_trip(connection: &mut LineConnection<TcpStream>, prefix: &MemcachedKeyPrefix) -> Result<State, Result<State, NetworkError>> {
+    let token = unique_token();
+    let key = format!("{}{}", String::from(prefix.clone()), token);
+
+    debug!("writing key {}", key);
+
+    connection.send(&format!("set {} 0 {} {}", key, ROUND_TRIP_KEY_TTL_SECONDS, token.len())).await?;
+    connection.send(&token).await?;
+
+    let line = connection.read_line().await?;
+
+    if line != "STORED" {
+        return Err(Ok(unhealthy_on_reply(line, "write rejected")));
+    }
+
+    connection.send(&format!("get {}", key)).await?;
+
+    let line = connection.read_line().await?;
+
+    let value = if line == "END" {
+        None
+    } else if line.starts_with(&format!("VALUE {} ", key)) {
+        let value = connection.read_line().await?;
+
+        let end = connection.read_line().await?;
+        if end != "END" {
+            return Err(Ok(unhealthy_on_reply(end, "read rejected")));
+        }
+
+        Some(value)
+    } else {
+        return Err(Ok(unhealthy_on_reply(line, "read rejected")));
+    };
+
+    connection.send(&format!("delete {}", key)).await?;
+
+    let line = connection.read_line().await?;
+
+    if line != "DELETED" && line != "NOT_FOUND" {
+        return Err(Ok(unhealthy_on_reply(line, "delete rejected")));
+    }
+
+    match value {
+        Some(value) if value == token => Ok(State::Healthy),
+        Some(value) => Ok(State::Unhealthy(Other(format!("unexpected value '{}' read from key '{}'", value, key)))),
+        None => Ok(State::Unhealthy(Other(format!("key '{}' not found after being written", key)))),
+    }
+}
+
+fn unhealthy_on_reply(line: String, description: &str) -> State {
+    State::Unhealthy(Other(format!("{} '{}'", description, line)))
+}
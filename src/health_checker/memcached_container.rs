@@ -0,0 +1,32 @@
+use std::borrow::Cow;
+use testcontainers_modules::testcontainers::core::wait::LogWaitStrategy;
+use testcontainers_modules::testcontainers::core::ContainerPort::Tcp;
+use testcontainers_modules::testcontainers::core::{ContainerPort, WaitFor};
+use testcontainers_modules::testcontainers::Image;
+
+pub const MEMCACHED_PORT: u16 = 11211;
+
+#[derive(Default)]
+pub struct MemcachedContainer;
+
+impl Image for MemcachedContainer {
+    fn name(&self) -> &str {
+        "memcached"
+    }
+
+    fn tag(&self) -> &str {
+        "1.6"
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::log(LogWaitStrategy::stderr("server listening"))]
+    }
+
+    fn cmd(&self) -> impl IntoIterator<Item = impl Into<Cow<'_, str>>> {
+        ["-vv"]
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[Tcp(MEMCACHED_PORT)]
+    }
+}
@@ -0,0 +1,115 @@
+use crate::configuration::MemcachedCheck;
+use crate::health_checker::Reason::Other;
+use crate::health_checker::State::Healthy;
+use crate::health_checker::State::Unhealthy;
+use assert2::{check, assert};
+use rstest::rstest;
+use std::net::TcpListener;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use testcontainers_modules::testcontainers::runners::AsyncRunner;
+use crate::configuration::fixtures::a_memcached_configuration;
+use crate::health_checker::memcached::Memcached;
+use crate::health_checker::memcached_container::{MemcachedContainer, MEMCACHED_PORT};
+use crate::health_checker::HealthCheck;
+
+#[rstest]
+#[case::version(MemcachedCheck::Version)]
+#[case::round_trip(MemcachedCheck::RoundTrip)]
+#[tokio::test]
+async fn a_healthy_memcached_should_be_reported(#[case] check: MemcachedCheck) {
+    let memcached_container = MemcachedContainer
+        .start()
+        .await
+        .unwrap();
+
+    let port = memcached_container.get_host_port_ipv4(MEMCACHED_PORT).await.unwrap();
+    let configuration = a_memcached_configuration(port, check);
+
+    let result = Memcached.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[tokio::test]
+async fn a_server_error_on_version_should_be_reported_as_unhealthy() {
+    let port = a_server_replying(&["SERVER_ERROR out of memory"]).await;
+    let configuration = a_memcached_configuration(port, MemcachedCheck::Version);
+
+    let result = Memcached.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other(String::from("error response 'SERVER_ERROR out of memory'"))));
+}
+
+#[tokio::test]
+async fn a_rejected_write_should_be_reported_as_unhealthy() {
+    let port = a_server_replying(&["VERSION 1.6.0", "SERVER_ERROR out of memory storing object"]).await;
+    let configuration = a_memcached_configuration(port, MemcachedCheck::RoundTrip);
+
+    let result = Memcached.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other(String::from("write rejected 'SERVER_ERROR out of memory storing object'"))));
+}
+
+#[tokio::test]
+async fn a_missing_key_should_be_reported_as_unhealthy() {
+    let port = a_server_replying(&["VERSION 1.6.0", "STORED", "END", "NOT_FOUND"]).await;
+    let configuration = a_memcached_configuration(port, MemcachedCheck::RoundTrip);
+
+    let result = Memcached.get_health(&configuration).await;
+
+    assert!(let Ok(Unhealthy(Other(reason))) = result);
+    check!(reason.ends_with("not found after being written"));
+}
+
+#[tokio::test]
+async fn a_connection_closed_by_the_server_should_be_reported_as_error() {
+    let port = a_server_replying(&[]).await;
+    let configuration = a_memcached_configuration(port, MemcachedCheck::Version);
+
+    let result = Memcached.get_health(&configuration).await;
+
+    assert!(let Err(error) = result);
+    check!(error.message.starts_with("network error"));
+}
+
+#[tokio::test]
+async fn unreachable_memcached_should_be_reported_as_error() {
+    let unused_port = TcpListener::bind("localhost:0").unwrap()
+        .local_addr().unwrap()
+        .port();
+    let configuration = a_memcached_configuration(unused_port, MemcachedCheck::Version);
+
+    let result = Memcached.get_health(&configuration).await;
+
+    assert!(let Err(error) = result);
+    check!(error.message.starts_with("network error"));
+}
+
+/// Starts a server answering each command with the given reply, then closing the connection.
+async fn a_server_replying(replies: &[&str]) -> u16 {
+    let listener = tokio::net::TcpListener::bind("localhost:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let replies: Vec<String> = replies.iter().map(|reply| reply.to_string()).collect();
+
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut stream = BufReader::new(stream);
+
+        for reply in replies {
+            let mut command = String::new();
+            stream.read_line(&mut command).await.unwrap();
+
+            if command.starts_with("set ") {
+                let mut data = String::new();
+                stream.read_line(&mut data).await.unwrap();
+            }
+
+            stream.get_mut().write_all(format!("{}\r\n", reply).as_bytes()).await.unwrap();
+        }
+    });
+
+    port
+}
@@ -0,0 +1,528 @@
+use std::fmt;
+use std::num::NonZeroU32;
+use async_trait::async_trait;
+use log::debug;
+use ring::rand::{SecureRandom, SystemRandom};
+use ring::{digest, hmac, pbkdf2};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use crate::configuration::{Configuration, MongodbState};
+use crate::health_checker::Reason::Other;
+use crate::health_checker::wire::{malformed, network_error, run_with_timeout, Reader};
+use crate::health_checker::{HealthCheck, NetworkError, State};
+
+#[cfg(test)]
+#[path = "./mongodb_test.rs"]
+mod test;
+
+const DEFAULT_AUTHENTICATION_DATABASE: &str = "admin";
+
+const OP_MSG: i32 = 2013;
+
+const HEADER_SIZE: usize = 16;
+
+// the default maxMessageSizeBytes of the servers
+const MESSAGE_MAX_SIZE: usize = 48_000_000;
+
+const SCRAM_SHA_256: &str = "SCRAM-SHA-256";
+
+const NONCE_SIZE: usize = 24;
+
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+pub(crate) struct Mongodb;
+
+#[async_trait]
+impl HealthCheck for Mongodb {
+    async fn get_health(&self, configuration: &Configuration) -> Result<State, NetworkError> {
+        run_with_timeout(configuration, hello(configuration)).await
+    }
+}
+
+async fn hello(configuration: &Configuration) -> Result<State, Result<State, NetworkError>> {
+    let port: u16 = configuration.port.into();
+
+    let stream = TcpStream::connect(("localhost", port)).await
+        .map_err(network_error)?;
+
+    let mut connection = Connection { stream, last_request_id: 0 };
+
+    if let Some(username) = &configuration.username {
+        let username = String::from(username.clone());
+        let password = configuration.password.clone()
+            .map(String::from)
+            .unwrap_or_default();
+        let source = configuration.database.clone()
+            .map(String::from)
+            .unwrap_or_else(|| DEFAULT_AUTHENTICATION_DATABASE.to_string());
+
+        debug!("authenticating as '{}' on database '{}'", username, source);
+
+        authenticate(&mut connection, &username, &password, &source).await?;
+    }
+
+    let reply = connection.command(document([
+        ("hello", Bson::Int32(1)),
+        ("$db", Bson::String(DEFAULT_AUTHENTICATION_DATABASE.to_string())),
+    ])).await?;
+
+    if let Some(error) = command_error(&reply) {
+        return Ok(State::Unhealthy(Other(format!("error response '{}'", error))));
+    }
+
+    Ok(check_reply(&reply, configuration.mongodb_state))
+}
+
+pub(super) fn check_reply(reply: &Document, expected: MongodbState) -> State {
+    if !ok(reply) {
+        return State::Unhealthy(Other(format!("unexpected response '{}'", reply)));
+    }
+
+    let writable_primary = reply.get_bool("isWritablePrimary").unwrap_or(false);
+    let state = member_state(reply);
+
+    debug!("member state is '{}'", state);
+
+    match expected {
+        MongodbState::Any => State::Healthy,
+        MongodbState::WritablePrimary if writable_primary => State::Healthy,
+        MongodbState::WritablePrimary => State::Unhealthy(Other(format!("node is not a writable primary, member state is '{}'", state))),
+        MongodbState::Secondary if state == "SECONDARY" => State::Healthy,
+        MongodbState::Secondary => State::Unhealthy(Other(format!("member state is '{}', expected 'SECONDARY'", state))),
+    }
+}
+
+fn member_state(reply: &Document) -> &'static str {
+    let replica_set = reply.get("setName").is_some();
+
+    if reply.get_str("msg") == Some("isdbgrid") {
+        "MONGOS"
+    } else if !replica_set {
+        "STANDALONE"
+    } else if reply.get_bool("isWritablePrimary") == Some(true) {
+        "PRIMARY"
+    } else if reply.get_bool("secondary") == Some(true) {
+        "SECONDARY"
+    } else if reply.get_bool("arbiterOnly") == Some(true) {
+        "ARBITER"
+    } else {
+        "OTHER"
+    }
+}
+
+fn ok(reply: &Document) -> bool {
+    match reply.get("ok") {
+        Some(Bson::Double(ok)) => *ok == 1.0,
+        Some(Bson::Int32(ok)) => *ok == 1,
+        Some(Bson::Int64(ok)) => *ok == 1,
+        _ => false,
+    }
+}
+
+/// Describes the failure of a command, when its reply is not successful.
+fn command_error(reply: &Document) -> Option<String> {
+    if ok(reply) {
+        return None;
+    }
+
+    let message = reply.get_str("errmsg").unwrap_or_default();
+
+    match reply.get("code") {
+        Some(Bson::Int32(code)) => Some(format!("{} {}", code, message)),
+        _ => Some(message.to_string()),
+    }
+}
+
+/// Runs the SCRAM-SHA-256 conversation, the default mechanism of the servers since 4.0.
+async fn authenticate(connection: &mut Connection, username: &str, password: &str, source: &str) -> Result<(), Result<State, NetworkError>> {
+    let client_nonce = nonce()?;
+    let client_first_bare = format!("n={},r={}", username.replace('=', "=3D").replace(',', "=2C"), client_nonce);
+
+    let reply = connection.command(document([
+        ("saslStart", Bson::Int32(1)),
+        ("mechanism", Bson::String(SCRAM_SHA_256.to_string())),
+        ("payload", Bson::Binary(format!("n,,{}", client_first_bare).into_bytes())),
+        ("options", Bson::Document(document([("skipEmptyExchange", Bson::Boolean(true))]))),
+        ("$db", Bson::String(source.to_string())),
+    ])).await?;
+
+    let (conversation_id, server_first) = sasl_payload(&reply)?;
+    let (client_final, server_signature) = client_final(&client_first_bare, &server_first, &client_nonce, password)?;
+
+    let mut reply = connection.command(document([
+        ("saslContinue", Bson::Int32(1)),
+        ("conversationId", conversation_id.clone()),
+        ("payload", Bson::Binary(client_final.into_bytes())),
+        ("$db", Bson::String(source.to_string())),
+    ])).await?;
+
+    let (_, server_final) = sasl_payload(&reply)?;
+
+    if server_final.strip_prefix("v=").and_then(base64_decode) != Some(server_signature) {
+        return Err(Ok(State::Unhealthy(Other(String::from("authentication failed 'invalid server signature'")))));
+    }
+
+    while reply.get_bool("done") != Some(true) {
+        reply = connection.command(document([
+            ("saslContinue", Bson::Int32(1)),
+            ("conversationId", conversation_id.clone()),
+            ("payload", Bson::Binary(Vec::new())),
+            ("$db", Bson::String(source.to_string())),
+        ])).await?;
+
+        sasl_payload(&reply)?;
+    }
+
+    Ok(())
+}
+
+fn sasl_payload(reply: &Document) -> Result<(Bson, String), Result<State, NetworkError>> {
+    if let Some(error) = command_error(reply) {
+        return Err(Ok(State::Unhealthy(Other(format!("authentication failed '{}'", error)))));
+    }
+
+    let payload = match (reply.get("conversationId"), reply.get("payload")) {
+        (Some(conversation_id), Some(Bson::Binary(payload))) => (conversation_id.clone(), String::from_utf8_lossy(payload).into_owned()),
+        _ => return Err(malformed("response")),
+    };
+
+    match payload.1.strip_prefix("e=") {
+        Some(error) => Err(Ok(State::Unhealthy(Other(format!("authentication failed '{}'", error))))),
+        None => Ok(payload),
+    }
+}
+
+/// Answers the first message of the server, returning the final message of the client and the signature the server
+/// must prove it knows the password with.
+fn client_final(client_first_bare: &str, server_first: &str, client_nonce: &str, password: &str) -> Result<(String, Vec<u8>), Result<State, NetworkError>> {
+    let attribute = |name: &str| server_first.split(',')
+        .find_map(|attribute| attribute.strip_prefix(name)?.strip_prefix('='));
+
+    let nonce = attribute("r")
+        .filter(|nonce| nonce.starts_with(client_nonce))
+        .ok_or_else(|| malformed("response"))?;
+    let salt = attribute("s")
+        .and_then(base64_decode)
+        .ok_or_else(|| malformed("response"))?;
+    let iterations = attribute("i")
+        .and_then(|iterations| iterations.parse::<NonZeroU32>().ok())
+        .ok_or_else(|| malformed("response"))?;
+
+    let mut salted_password = [0u8; digest::SHA256_OUTPUT_LEN];
+    pbkdf2::derive(pbkdf2::PBKDF2_HMAC_SHA256, iterations, &salt, password.as_bytes(), &mut salted_password);
+    let salted_password = hmac::Key::new(hmac::HMAC_SHA256, &salted_password);
+
+    let client_key = hmac::sign(&salted_password, b"Client Key");
+    let stored_key = digest::digest(&digest::SHA256, client_key.as_ref());
+    let server_key = hmac::sign(&salted_password, b"Server Key");
+
+    let without_proof = format!("c=biws,r={}", nonce);
+    let auth_message = format!("{},{},{}", client_first_bare, server_first, without_proof);
+
+    let client_signature = hmac::sign(&hmac::Key::new(hmac::HMAC_SHA256, stored_key.as_ref()), auth_message.as_bytes());
+    let server_signature = hmac::sign(&hmac::Key::new(hmac::HMAC_SHA256, server_key.as_ref()), auth_message.as_bytes());
+
+    let proof: Vec<u8> = client_key.as_ref().iter()
+        .zip(client_signature.as_ref())
+        .map(|(key, signature)| key ^ signature)
+        .collect();
+
+    Ok((format!("{},p={}", without_proof, base64_encode(&proof)), server_signature.as_ref().to_vec()))
+}
+
+fn nonce() -> Result<String, Result<State, NetworkError>> {
+    let mut nonce = [0u8; NONCE_SIZE];
+
+    SystemRandom::new().fill(&mut nonce)
+        .map_err(|_| Err(NetworkError { message: String::from("network error: no random source") }))?;
+
+    Ok(base64_encode(&nonce))
+}
+
+fn base64_encode(bytes: &[u8]) -> String {
+    let mut encoded = String::new();
+
+    for chunk in bytes.chunks(3) {
+        let value = chunk.iter().enumerate().fold(0u32, |value, (index, &byte)| value | ((byte as u32) << (16 - 8 * index)));
+
+        for index in 0..4 {
+            if index <= chunk.len() {
+                encoded.push(BASE64_ALPHABET[((value >> (18 - 6 * index)) & 0x3F) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+
+    encoded
+}
+
+fn base64_decode(encoded: &str) -> Option<Vec<u8>> {
+    let encoded = encoded.trim_end_matches('=').as_bytes();
+    let mut decoded = Vec::new();
+
+    for chunk in encoded.chunks(4) {
+        if chunk.len() == 1 {
+            return None;
+        }
+
+        let mut value = 0u32;
+
+        for (index, byte) in chunk.iter().enumerate() {
+            let digit = BASE64_ALPHABET.iter().position(|candidate| candidate == byte)? as u32;
+            value |= digit << (18 - 6 * index);
+        }
+
+        decoded.extend_from_slice(&value.to_be_bytes()[1..chunk.len()]);
+    }
+
+    Some(decoded)
+}
+
+struct Connection {
+    stream: TcpStream,
+    last_request_id: i32,
+}
+
+impl Connection {
+
+    /// Sends a command in an `OP_MSG` message and reads the reply document.
+    async fn command(&mut self, command: Document) -> Result<Document, Result<State, NetworkError>> {
+        self.last_request_id += 1;
+
+        let body = command.to_bytes();
+
+        let mut message = Vec::new();
+        message.extend_from_slice(&((HEADER_SIZE + 5 + body.len()) as i32).to_le_bytes());
+        message.extend_from_slice(&self.last_request_id.to_le_bytes());
+        message.extend_from_slice(&0i32.to_le_bytes());
+        message.extend_from_slice(&OP_MSG.to_le_bytes());
+        message.extend_from_slice(&0u32.to_le_bytes());
+        message.push(0);
+        message.extend_from_slice(&body);
+
+        self.stream.write_all(&message).await
+            .map_err(network_error)?;
+
+        let mut header = [0u8; HEADER_SIZE];
+        self.stream.read_exact(&mut header).await
+            .map_err(network_error)?;
+
+        let mut reader = Reader::new(&header, "response");
+        let length = reader.i32_le()? as usize;
+        let _request_id = reader.i32_le()?;
+        let response_to = reader.i32_le()?;
+        let op_code = reader.i32_le()?;
+
+        if op_code != OP_MSG || response_to != self.last_request_id || !(HEADER_SIZE + 5..=MESSAGE_MAX_SIZE).contains(&length) {
+            return Err(malformed("response"));
+        }
+
+        let mut body = vec![0u8; length - HEADER_SIZE];
+        self.stream.read_exact(&mut body).await
+            .map_err(network_error)?;
+
+        let mut reader = Reader::new(&body, "response");
+        let _flags = reader.i32_le()?;
+
+        match reader.u8()? {
+            0 => read_document(&mut reader),
+            _ => Err(malformed("response")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum Bson {
+    Double(f64),
+    String(String),
+    Document(Document),
+    Binary(Vec<u8>),
+    Boolean(bool),
+    Int32(i32),
+    Int64(i64),
+    Other,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(super) struct Document(Vec<(String, Bson)>);
+
+pub(super) fn document<const N: usize>(elements: [(&str, Bson); N]) -> Document {
+    Document(elements.into_iter().map(|(key, value)| (key.to_string(), value)).collect())
+}
+
+impl Document {
+
+    fn get(&self, key: &str) -> Option<&Bson> {
+        self.0.iter().find(|(name, _)| name == key).map(|(_, value)| value)
+    }
+
+    fn get_bool(&self, key: &str) -> Option<bool> {
+        match self.get(key) {
+            Some(Bson::Boolean(value)) => Some(*value),
+            _ => None,
+        }
+    }
+
+    fn get_str(&self, key: &str) -> Option<&str> {
+        match self.get(key) {
+            Some(Bson::String(value)) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub(super) fn to_bytes(&self) -> Vec<u8> {
+        let mut elements = Vec::new();
+
+        for (key, value) in &self.0 {
+            let (kind, bytes) = match value {
+                Bson::Double(value) => (0x01, value.to_le_bytes().to_vec()),
+                Bson::String(value) => {
+                    let mut bytes = ((value.len() + 1) as i32).to_le_bytes().to_vec();
+                    bytes.extend_from_slice(value.as_bytes());
+                    bytes.push(0);
+                    (0x02, bytes)
+                }
+                Bson::Document(value) => (0x03, value.to_bytes()),
+                Bson::Binary(value) => {
+                    let mut bytes = (value.len() as i32).to_le_bytes().to_vec();
+                    bytes.push(0);
+                    bytes.extend_from_slice(value);
+                    (0x05, bytes)
+                }
+                Bson::Boolean(value) => (0x08, vec![*value as u8]),
+                Bson::Int32(value) => (0x10, value.to_le_bytes().to_vec()),
+                Bson::Int64(value) => (0x12, value.to_le_bytes().to_vec()),
+                Bson::Other => (0x0A, Vec::new()),
+            };
+
+            elements.push(kind);
+            elements.extend_from_slice(key.as_bytes());
+            elements.push(0);
+            elements.extend_from_slice(&bytes);
+        }
+
+        let mut bytes = ((elements.len() + 5) as i32).to_le_bytes().to_vec();
+        bytes.extend_from_slice(&elements);
+        bytes.push(0);
+        bytes
+    }
+}
+
+impl fmt::Display for Document {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{")?;
+
+        for (index, (key, value)) in self.0.iter().enumerate() {
+            let separator = if index == 0 { " " } else { ", " };
+
+            match value {
+                Bson::Double(value) => write!(f, "{}\"{}\": {:?}", separator, key, value)?,
+                Bson::String(value) => write!(f, "{}\"{}\": \"{}\"", separator, key, value)?,
+                Bson::Document(value) => write!(f, "{}\"{}\": {}", separator, key, value)?,
+                Bson::Boolean(value) => write!(f, "{}\"{}\": {}", separator, key, value)?,
+                Bson::Int32(value) => write!(f, "{}\"{}\": {}", separator, key, value)?,
+                Bson::Int64(value) => write!(f, "{}\"{}\": {}", separator, key, value)?,
+                Bson::Binary(_) | Bson::Other => write!(f, "{}\"{}\": ...", separator, key)?,
+            }
+        }
+
+        write!(f, " }}")
+    }
+}
+
+fn read_length(reader: &mut Reader) -> Result<usize, Result<State, NetworkError>> {
+    usize::try_from(reader.i32_le()?).map_err(|_| reader.malformed())
+}
+
+fn read_cstring(reader: &mut Reader) -> Result<String, Result<State, NetworkError>> {
+    let end = reader.remaining().iter().position(|&byte| byte == 0).ok_or_else(|| reader.malformed())?;
+    let value = reader.string(end)?;
+    reader.u8()?;
+    Ok(value)
+}
+
+fn read_string(reader: &mut Reader) -> Result<String, Result<State, NetworkError>> {
+    let length = read_length(reader)?;
+    let bytes = reader.take(length)?;
+
+    match bytes.split_last() {
+        Some((0, value)) => Ok(String::from_utf8_lossy(value).into_owned()),
+        _ => Err(reader.malformed()),
+    }
+}
+
+fn read_document(reader: &mut Reader) -> Result<Document, Result<State, NetworkError>> {
+    let length = read_length(reader)?;
+
+    if length < 5 {
+        return Err(reader.malformed());
+    }
+
+    let bytes = reader.take(length - 4)?;
+    let mut elements_reader = Reader::new(&bytes[..bytes.len() - 1], "response");
+    let mut elements = Vec::new();
+
+    while !elements_reader.is_empty() {
+        let kind = elements_reader.u8()?;
+        let key = read_cstring(&mut elements_reader)?;
+        elements.push((key, read_value(&mut elements_reader, kind)?));
+    }
+
+    Ok(Document(elements))
+}
+
+fn read_value(reader: &mut Reader, kind: u8) -> Result<Bson, Result<State, NetworkError>> {
+    let value = match kind {
+        0x01 => Bson::Double(f64::from_le_bytes(reader.array()?)),
+        0x02 => Bson::String(read_string(reader)?),
+        0x03 => Bson::Document(read_document(reader)?),
+        0x04 => {
+            read_document(reader)?;
+            Bson::Other
+        }
+        0x05 => {
+            let length = read_length(reader)?;
+            reader.u8()?;
+            Bson::Binary(reader.take(length)?.to_vec())
+        }
+        0x06 | 0x0A | 0x7F | 0xFF => Bson::Other,
+        0x07 => {
+            reader.take(12)?;
+            Bson::Other
+        }
+        0x08 => Bson::Boolean(reader.u8()? != 0),
+        0x09 | 0x11 => {
+            reader.take(8)?;
+            Bson::Other
+        }
+        0x0B => {
+            read_cstring(reader)?;
+            read_cstring(reader)?;
+            Bson::Other
+        }
+        0x0C => {
+            read_string(reader)?;
+            reader.take(12)?;
+            Bson::Other
+        }
+        0x0D | 0x0E => {
+            read_string(reader)?;
+            Bson::Other
+        }
+        0x0F => {
+            let length = read_length(reader)?;
+            reader.take(length.checked_sub(4).ok_or_else(|| reader.malformed())?)?;
+            Bson::Other
+        }
+        0x10 => Bson::Int32(reader.i32_le()?),
+        0x12 => Bson::Int64(i64::from_le_bytes(reader.array()?)),
+        0x13 => {
+            reader.take(16)?;
+            Bson::Other
+        }
+        _ => return Err(reader.malformed()),
+    };
+
+    Ok(value)
+}
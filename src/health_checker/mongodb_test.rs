@@ -0,0 +1,267 @@
+use crate::configuration::MongodbState;
+use crate::health_checker::Reason::Other;
+use crate::health_checker::State::Healthy;
+use crate::health_checker::State::Unhealthy;
+use assert2::{check, assert};
+use rstest::rstest;
+use std::net::TcpListener;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use testcontainers_modules::mongo::Mongo as MongoContainer;
+use testcontainers_modules::testcontainers::core::ImageExt;
+use testcontainers_modules::testcontainers::runners::AsyncRunner;
+use crate::configuration::fixtures::{a_mongodb_configuration, a_mongodb_configuration_with_credentials};
+use crate::health_checker::mongodb::{base64_decode, base64_encode, check_reply, client_final, document, Bson, Document, Mongodb};
+use crate::health_checker::HealthCheck;
+
+const MONGODB_PORT: u16 = 27017;
+
+#[tokio::test]
+async fn a_healthy_mongodb_should_be_reported() {
+    let mongodb_container = MongoContainer::default()
+        .start()
+        .await
+        .unwrap();
+
+    let port = mongodb_container.get_host_port_ipv4(MONGODB_PORT).await.unwrap();
+    let configuration = a_mongodb_configuration(port, MongodbState::WritablePrimary);
+
+    let result = Mongodb.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[tokio::test]
+async fn a_replica_set_primary_should_be_reported_as_healthy() {
+    let mongodb_container = MongoContainer::repl_set()
+        .start()
+        .await
+        .unwrap();
+
+    let port = mongodb_container.get_host_port_ipv4(MONGODB_PORT).await.unwrap();
+    let configuration = a_mongodb_configuration(port, MongodbState::WritablePrimary);
+
+    let result = Mongodb.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[tokio::test]
+async fn an_authenticated_user_should_be_reported_as_healthy() {
+    let mongodb_container = MongoContainer::default()
+        .with_env_var("MONGO_INITDB_ROOT_USERNAME", "root")
+        .with_env_var("MONGO_INITDB_ROOT_PASSWORD", "secret")
+        .start()
+        .await
+        .unwrap();
+
+    let port = mongodb_container.get_host_port_ipv4(MONGODB_PORT).await.unwrap();
+    let configuration = a_mongodb_configuration_with_credentials(port, "root", "secret");
+
+    let result = Mongodb.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[tokio::test]
+async fn a_failed_authentication_should_be_reported_as_unhealthy() {
+    let mongodb_container = MongoContainer::default()
+        .with_env_var("MONGO_INITDB_ROOT_USERNAME", "root")
+        .with_env_var("MONGO_INITDB_ROOT_PASSWORD", "secret")
+        .start()
+        .await
+        .unwrap();
+
+    let port = mongodb_container.get_host_port_ipv4(MONGODB_PORT).await.unwrap();
+    let configuration = a_mongodb_configuration_with_credentials(port, "root", "wrong");
+
+    let result = Mongodb.get_health(&configuration).await;
+
+    assert!(let Ok(Unhealthy(Other(reason))) = result);
+    check!(reason.starts_with("authentication failed"));
+}
+
+#[tokio::test]
+async fn unreachable_mongodb_should_be_reported_as_error() {
+    let unused_port = TcpListener::bind("localhost:0").unwrap()
+        .local_addr().unwrap()
+        .port();
+    let configuration = a_mongodb_configuration(unused_port, MongodbState::Any);
+
+    let result = Mongodb.get_health(&configuration).await;
+
+    assert!(let Err(error) = result);
+    check!(error.message.starts_with("network error"));
+}
+
+#[tokio::test]
+async fn an_error_response_should_be_reported_as_unhealthy() {
+    let port = a_server_replying(vec![
+        document([("ok", Bson::Double(0.0)), ("errmsg", Bson::String(String::from("node is recovering"))), ("code", Bson::Int32(91))]),
+    ]).await;
+    let configuration = a_mongodb_configuration(port, MongodbState::Any);
+
+    let result = Mongodb.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other(String::from("error response '91 node is recovering'"))));
+}
+
+#[tokio::test]
+async fn a_rejected_authentication_should_be_reported_as_unhealthy() {
+    let port = a_server_replying(vec![
+        document([("ok", Bson::Double(0.0)), ("errmsg", Bson::String(String::from("Authentication failed."))), ("code", Bson::Int32(18))]),
+    ]).await;
+    let configuration = a_mongodb_configuration_with_credentials(port, "root", "wrong");
+
+    let result = Mongodb.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other(String::from("authentication failed '18 Authentication failed.'"))));
+}
+
+#[tokio::test]
+async fn a_reply_holding_other_types_should_be_read() {
+    let mut reply = document([("ok", Bson::Double(1.0)), ("isWritablePrimary", Bson::Boolean(true))]).to_bytes();
+    reply.pop();
+    // an object id, an array holding a timestamp and a 64-bit integer
+    reply.extend_from_slice(b"\x07processId\0");
+    reply.extend_from_slice(&[0x42; 12]);
+    reply.extend_from_slice(b"\x04hosts\0\x10\0\0\0\x110\0");
+    reply.extend_from_slice(&[0x01; 8]);
+    reply.extend_from_slice(b"\0\x12localTime\0");
+    reply.extend_from_slice(&7i64.to_le_bytes());
+    reply.push(0);
+    let length = reply.len() as i32;
+    reply[..4].copy_from_slice(&length.to_le_bytes());
+
+    let port = a_server_sending(vec![reply]).await;
+    let configuration = a_mongodb_configuration(port, MongodbState::WritablePrimary);
+
+    let result = Mongodb.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[tokio::test]
+async fn a_reply_not_holding_a_document_should_be_reported_as_error() {
+    let port = a_server_sending(vec![b"HTTP/1.1 400 Bad Request\r\n\r\n".to_vec()]).await;
+    let configuration = a_mongodb_configuration(port, MongodbState::Any);
+
+    let result = Mongodb.get_health(&configuration).await;
+
+    assert!(let Err(error) = result);
+    check!(error.message == "network error: malformed response");
+}
+
+#[rstest]
+#[case::standalone_as_any(MongodbState::Any, document([("ok", Bson::Double(1.0)), ("isWritablePrimary", Bson::Boolean(true))]))]
+#[case::standalone_as_writable_primary(MongodbState::WritablePrimary, document([("ok", Bson::Double(1.0)), ("isWritablePrimary", Bson::Boolean(true))]))]
+#[case::primary_as_writable_primary(MongodbState::WritablePrimary, document([("ok", Bson::Double(1.0)), ("isWritablePrimary", Bson::Boolean(true)), ("setName", Bson::String(String::from("rs")))]))]
+#[case::secondary(MongodbState::Secondary, document([("ok", Bson::Int32(1)), ("isWritablePrimary", Bson::Boolean(false)), ("secondary", Bson::Boolean(true)), ("setName", Bson::String(String::from("rs")))]))]
+fn expected_member_state_should_be_healthy(#[case] expected: MongodbState, #[case] reply: Document) {
+    let result = check_reply(&reply, expected);
+
+    check!(result == Healthy);
+}
+
+#[rstest]
+#[case::secondary_as_writable_primary(
+    MongodbState::WritablePrimary,
+    document([("ok", Bson::Double(1.0)), ("isWritablePrimary", Bson::Boolean(false)), ("secondary", Bson::Boolean(true)), ("setName", Bson::String(String::from("rs")))]),
+    "node is not a writable primary, member state is 'SECONDARY'",
+)]
+#[case::arbiter_as_secondary(
+    MongodbState::Secondary,
+    document([("ok", Bson::Double(1.0)), ("isWritablePrimary", Bson::Boolean(false)), ("secondary", Bson::Boolean(false)), ("arbiterOnly", Bson::Boolean(true)), ("setName", Bson::String(String::from("rs")))]),
+    "member state is 'ARBITER', expected 'SECONDARY'",
+)]
+#[case::recovering_as_secondary(
+    MongodbState::Secondary,
+    document([("ok", Bson::Double(1.0)), ("isWritablePrimary", Bson::Boolean(false)), ("secondary", Bson::Boolean(false)), ("setName", Bson::String(String::from("rs")))]),
+    "member state is 'OTHER', expected 'SECONDARY'",
+)]
+fn unexpected_member_state_should_be_unhealthy(#[case] expected: MongodbState, #[case] reply: Document, #[case] reason: &str) {
+    let result = check_reply(&reply, expected);
+
+    check!(result == Unhealthy(Other(reason.to_string())));
+}
+
+#[test]
+fn a_failed_reply_should_be_unhealthy() {
+    let reply = document([("ok", Bson::Double(0.0))]);
+
+    let result = check_reply(&reply, MongodbState::Any);
+
+    assert!(let Unhealthy(Other(reason)) = result);
+    check!(reason.starts_with("unexpected response"));
+}
+
+#[test]
+fn the_client_proof_should_follow_the_scram_sha_256_exchange() {
+    // the example exchange of RFC 7677
+    let server_first = "r=rOprNGfwEbeRWgbNEkqO%hvYDpWUa2RaTCAfuxFIlj)hNlF$k0,s=W22ZaJ0SNY7soEsUEjb6gQ==,i=4096";
+
+    let result = client_final("n=user,r=rOprNGfwEbeRWgbNEkqO", server_first, "rOprNGfwEbeRWgbNEkqO", "pencil");
+
+    assert!(let Ok((message, server_signature)) = result);
+    check!(message == "c=biws,r=rOprNGfwEbeRWgbNEkqO%hvYDpWUa2RaTCAfuxFIlj)hNlF$k0,p=dHzbZapWIk4jUhN+Ute9ytag9zjfMHgsqmmiz7AndVQ=");
+    check!(base64_encode(&server_signature) == "6rriTRBi23WpRR/wtup+mMhUZUn/dB5nLTJRsjl95G4=");
+}
+
+#[test]
+fn a_server_nonce_not_extending_the_client_one_should_be_rejected() {
+    let result = client_final("n=user,r=client", "r=other,s=W22ZaJ0SNY7soEsUEjb6gQ==,i=4096", "client", "pencil");
+
+    assert!(let Err(Err(error)) = result);
+    check!(error.message == "network error: malformed response");
+}
+
+#[rstest]
+#[case::empty(b"", "")]
+#[case::one_byte(b"f", "Zg==")]
+#[case::two_bytes(b"fo", "Zm8=")]
+#[case::three_bytes(b"foo", "Zm9v")]
+#[case::four_bytes(b"foob", "Zm9vYg==")]
+fn base64_should_encode_and_decode(#[case] bytes: &[u8], #[case] encoded: &str) {
+    check!(base64_encode(bytes) == encoded);
+    check!(base64_decode(encoded) == Some(bytes.to_vec()));
+}
+
+/// Starts a server answering each command with the given reply documents.
+async fn a_server_replying(replies: Vec<Document>) -> u16 {
+    a_server_sending(replies.iter().map(|reply| reply.to_bytes()).collect()).await
+}
+
+/// Starts a server answering each message with an `OP_MSG` holding the given bytes as its body section.
+async fn a_server_sending(replies: Vec<Vec<u8>>) -> u16 {
+    let listener = tokio::net::TcpListener::bind("localhost:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+
+        for reply in replies {
+            let length = stream.read_i32_le().await.unwrap();
+            let request_id = stream.read_i32_le().await.unwrap();
+            let mut ignored = vec![0u8; length as usize - 8];
+            stream.read_exact(&mut ignored).await.unwrap();
+
+            let mut message = Vec::new();
+            message.extend_from_slice(&(reply.len() as i32 + 21).to_le_bytes());
+            message.extend_from_slice(&0i32.to_le_bytes());
+            message.extend_from_slice(&request_id.to_le_bytes());
+            message.extend_from_slice(&2013i32.to_le_bytes());
+            message.extend_from_slice(&0u32.to_le_bytes());
+            message.push(0);
+            message.extend_from_slice(&reply);
+
+            stream.write_all(&message).await.unwrap();
+        }
+    });
+
+    port
+}
@@ -0,0 +1,311 @@
+use async_trait::async_trait;
+use log::debug;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use crate::configuration::{Configuration, MqttVersion};
+use crate::health_checker::Reason::Other;
+use crate::health_checker::wire::{malformed, network_error, run_with_timeout, Reader};
+use crate::health_checker::{unique_token, HealthCheck, NetworkError, State};
+
+#[cfg(test)]
+#[path = "./mqtt_test.rs"]
+mod test;
+
+const CONNECT: u8 = 1;
+const CONNACK: u8 = 2;
+const PUBLISH: u8 = 3;
+const SUBSCRIBE: u8 = 8;
+const SUBACK: u8 = 9;
+const DISCONNECT: u8 = 14;
+
+const CLEAN_SESSION: u8 = 0x02;
+const PASSWORD: u8 = 0x40;
+const USERNAME: u8 = 0x80;
+
+const KEEP_ALIVE_SECONDS: u16 = 30;
+
+const SUBSCRIPTION_PACKET_ID: u16 = 1;
+
+const PACKET_MAX_SIZE: usize = 1 << 20;
+
+pub(crate) struct Mqtt;
+
+#[async_trait]
+impl HealthCheck for Mqtt {
+    async fn get_health(&self, configuration: &Configuration) -> Result<State, NetworkError> {
+        run_with_timeout(configuration, check(configuration)).await
+    }
+}
+
+struct Packet {
+    kind: u8,
+    flags: u8,
+    body: Vec<u8>,
+}
+
+async fn check(configuration: &Configuration) -> Result<State, Result<State, NetworkError>> {
+    let port: u16 = configuration.port.into();
+    let version = configuration.mqtt_version;
+    let client_id = format!("dockteur{}", unique_token());
+
+    let mut stream = TcpStream::connect(("localhost", port)).await
+        .map_err(network_error)?;
+
+    debug!("connecting as client {}", client_id);
+
+    send(&mut stream, CONNECT << 4, &connect_body(configuration, &client_id)).await?;
+
+    let connack = expect(&mut stream, version, CONNACK).await?;
+    let code = *connack.body.get(1).ok_or_else(|| malformed("packet"))?;
+
+    if code != 0 {
+        return Ok(State::Unhealthy(Other(format!("connection refused '{} {}'", code, describe_reason(version, code)))));
+    }
+
+    if configuration.mqtt_check_delivery {
+        if let Some(state) = delivery(&mut stream, version, &client_id).await? {
+            return Ok(state);
+        }
+    }
+
+    send(&mut stream, DISCONNECT << 4, &[]).await?;
+
+    Ok(State::Healthy)
+}
+
+/// Publishes a message on a topic only this client subscribes to, returning the failure when it is not delivered.
+async fn delivery(stream: &mut TcpStream, version: MqttVersion, client_id: &str) -> Result<Option<State>, Result<State, NetworkError>> {
+    let topic = format!("$dockteur/{}", client_id);
+
+    debug!("subscribing to topic {}", topic);
+
+    let mut subscribe = SUBSCRIPTION_PACKET_ID.to_be_bytes().to_vec();
+    properties(&mut subscribe, version);
+    string(&mut subscribe, &topic);
+    subscribe.push(0);
+
+    send(stream, SUBSCRIBE << 4 | 0x02, &subscribe).await?;
+
+    let suback = expect(stream, version, SUBACK).await?;
+    let mut suback = Reader::new(&suback.body, "packet");
+    suback.u16()?;
+    if version == MqttVersion::V5 {
+        skip_properties(&mut suback)?;
+    }
+
+    match suback.remaining().first() {
+        Some(code) if *code < 0x80 => {}
+        Some(code) => return Ok(Some(State::Unhealthy(Other(format!("subscribe rejected '{}'", code))))),
+        None => return Err(malformed("packet")),
+    }
+
+    let mut publish = Vec::new();
+    string(&mut publish, &topic);
+    properties(&mut publish, version);
+    publish.extend_from_slice(client_id.as_bytes());
+
+    send(stream, PUBLISH << 4, &publish).await?;
+
+    loop {
+        let packet = expect(stream, version, PUBLISH).await?;
+        let mut body = Reader::new(&packet.body, "packet");
+        let received_topic = read_string(&mut body)?;
+
+        if packet.flags & 0x06 != 0 {
+            body.u16()?;
+        }
+
+        if version == MqttVersion::V5 {
+            skip_properties(&mut body)?;
+        }
+
+        if received_topic != topic {
+            continue;
+        }
+
+        if body.remaining() == client_id.as_bytes() {
+            return Ok(None);
+        }
+
+        return Ok(Some(State::Unhealthy(Other(format!(
+            "delivery failed: unexpected message '{}'",
+            String::from_utf8_lossy(body.remaining()),
+        )))));
+    }
+}
+
+fn connect_body(configuration: &Configuration, client_id: &str) -> Vec<u8> {
+    let version = configuration.mqtt_version;
+    let username = configuration.username.clone().map(String::from);
+    let password = configuration.password.clone()
+        .map(String::from)
+        .filter(|_| username.is_some() || version == MqttVersion::V5);
+
+    let mut flags = CLEAN_SESSION;
+    if username.is_some() {
+        flags |= USERNAME;
+    }
+    if password.is_some() {
+        flags |= PASSWORD;
+    }
+
+    let mut body = Vec::new();
+    string(&mut body, "MQTT");
+    body.push(match version {
+        MqttVersion::V311 => 4,
+        MqttVersion::V5 => 5,
+    });
+    body.push(flags);
+    body.extend_from_slice(&KEEP_ALIVE_SECONDS.to_be_bytes());
+    properties(&mut body, version);
+    string(&mut body, client_id);
+
+    if let Some(username) = username {
+        string(&mut body, &username);
+    }
+
+    if let Some(password) = password {
+        string(&mut body, &password);
+    }
+
+    body
+}
+
+/// Reads packets until the expected one, reporting a disconnection requested by the broker.
+async fn expect(stream: &mut TcpStream, version: MqttVersion, expected: u8) -> Result<Packet, Result<State, NetworkError>> {
+    loop {
+        let packet = read_packet(stream).await?;
+
+        if packet.kind == expected {
+            return Ok(packet);
+        }
+
+        if packet.kind == DISCONNECT {
+            let code = packet.body.first().copied().unwrap_or(0);
+            return Err(Ok(State::Unhealthy(Other(format!("disconnected by the broker '{} {}'", code, describe_reason(version, code))))));
+        }
+    }
+}
+
+async fn read_packet(stream: &mut TcpStream) -> Result<Packet, Result<State, NetworkError>> {
+    let mut header = [0u8; 1];
+    stream.read_exact(&mut header).await
+        .map_err(network_error)?;
+
+    let mut length = 0usize;
+
+    for shift in [0, 7, 14, 21] {
+        let mut byte = [0u8; 1];
+        stream.read_exact(&mut byte).await
+            .map_err(network_error)?;
+
+        length |= ((byte[0] & 0x7F) as usize) << shift;
+
+        if byte[0] & 0x80 == 0 {
+            break;
+        }
+
+        if shift == 21 {
+            return Err(malformed("packet"));
+        }
+    }
+
+    if length > PACKET_MAX_SIZE {
+        return Err(malformed("packet"));
+    }
+
+    let mut body = vec![0u8; length];
+    stream.read_exact(&mut body).await
+        .map_err(network_error)?;
+
+    Ok(Packet { kind: header[0] >> 4, flags: header[0] & 0x0F, body })
+}
+
+async fn send(stream: &mut TcpStream, header: u8, body: &[u8]) -> Result<(), Result<State, NetworkError>> {
+    let mut packet = vec![header];
+    variable_length(&mut packet, body.len());
+    packet.extend_from_slice(body);
+
+    stream.write_all(&packet).await
+        .map_err(network_error)
+}
+
+fn variable_length(buffer: &mut Vec<u8>, mut length: usize) {
+    loop {
+        let mut byte = (length % 128) as u8;
+        length /= 128;
+
+        if length > 0 {
+            byte |= 0x80;
+        }
+
+        buffer.push(byte);
+
+        if length == 0 {
+            break;
+        }
+    }
+}
+
+fn string(buffer: &mut Vec<u8>, value: &str) {
+    buffer.extend_from_slice(&(value.len() as u16).to_be_bytes());
+    buffer.extend_from_slice(value.as_bytes());
+}
+
+fn properties(buffer: &mut Vec<u8>, version: MqttVersion) {
+    if version == MqttVersion::V5 {
+        buffer.push(0);
+    }
+}
+
+fn describe_reason(version: MqttVersion, code: u8) -> &'static str {
+    match (version, code) {
+        (_, 0) => "normal disconnection",
+        (MqttVersion::V311, 1) => "unacceptable protocol version",
+        (MqttVersion::V311, 2) => "identifier rejected",
+        (MqttVersion::V311, 3) => "server unavailable",
+        (MqttVersion::V311, 4) => "bad username or password",
+        (MqttVersion::V311, 5) => "not authorized",
+        (MqttVersion::V5, 0x80) => "unspecified error",
+        (MqttVersion::V5, 0x81) => "malformed packet",
+        (MqttVersion::V5, 0x82) => "protocol error",
+        (MqttVersion::V5, 0x83) => "implementation specific error",
+        (MqttVersion::V5, 0x84) => "unsupported protocol version",
+        (MqttVersion::V5, 0x85) => "client identifier not valid",
+        (MqttVersion::V5, 0x86) => "bad user name or password",
+        (MqttVersion::V5, 0x87) => "not authorized",
+        (MqttVersion::V5, 0x88) => "server unavailable",
+        (MqttVersion::V5, 0x89) => "server busy",
+        (MqttVersion::V5, 0x8A) => "banned",
+        (MqttVersion::V5, 0x8B) => "server shutting down",
+        (MqttVersion::V5, 0x8C) => "bad authentication method",
+        (MqttVersion::V5, 0x8E) => "session taken over",
+        (MqttVersion::V5, 0x95) => "packet too large",
+        (MqttVersion::V5, 0x97) => "quota exceeded",
+        (MqttVersion::V5, 0x9C) => "use another server",
+        (MqttVersion::V5, 0x9D) => "server moved",
+        (MqttVersion::V5, 0x9F) => "connection rate exceeded",
+        _ => "unknown reason",
+    }
+}
+
+fn read_string(body: &mut Reader) -> Result<String, Result<State, NetworkError>> {
+    let length = body.u16()? as usize;
+    body.string(length)
+}
+
+fn skip_properties(body: &mut Reader) -> Result<(), Result<State, NetworkError>> {
+    let mut length = 0usize;
+
+    for shift in [0, 7, 14, 21] {
+        let byte = body.u8()?;
+        length |= ((byte & 0x7F) as usize) << shift;
+
+        if byte & 0x80 == 0 {
+            body.take(length)?;
+            return Ok(());
+        }
+    }
+
+    Err(malformed("packet"))
+}
@@ -0,0 +1,98 @@
+use crate::configuration::MqttVersion;
+use crate::health_checker::Reason::Other;
+use crate::health_checker::State::Healthy;
+use crate::health_checker::State::Unhealthy;
+use assert2::{check, assert};
+use rstest::rstest;
+use std::net::TcpListener;
+use tokio::io::AsyncWriteExt;
+use testcontainers_modules::mosquitto::Mosquitto as MosquittoContainer;
+use testcontainers_modules::testcontainers::runners::AsyncRunner;
+use crate::configuration::fixtures::a_mqtt_configuration;
+use crate::health_checker::mqtt::{read_packet, Mqtt};
+use crate::health_checker::HealthCheck;
+
+const MQTT_PORT: u16 = 1883;
+
+#[rstest]
+#[case::v311_connection(MqttVersion::V311, false)]
+#[case::v311_delivery(MqttVersion::V311, true)]
+#[case::v5_connection(MqttVersion::V5, false)]
+#[case::v5_delivery(MqttVersion::V5, true)]
+#[tokio::test]
+async fn a_healthy_broker_should_be_reported(#[case] version: MqttVersion, #[case] check_delivery: bool) {
+    let mosquitto_container = MosquittoContainer::default()
+        .start()
+        .await
+        .unwrap();
+
+    let port = mosquitto_container.get_host_port_ipv4(MQTT_PORT).await.unwrap();
+    let configuration = a_mqtt_configuration(port, version, check_delivery);
+
+    let result = Mqtt.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[rstest]
+#[case::v311_bad_credentials(MqttVersion::V311, vec![0x20, 2, 0, 4], "connection refused '4 bad username or password'")]
+#[case::v311_server_unavailable(MqttVersion::V311, vec![0x20, 2, 0, 3], "connection refused '3 server unavailable'")]
+#[case::v5_not_authorized(MqttVersion::V5, vec![0x20, 3, 0, 0x87, 0], "connection refused '135 not authorized'")]
+#[case::v5_server_busy(MqttVersion::V5, vec![0x20, 3, 0, 0x89, 0], "connection refused '137 server busy'")]
+#[tokio::test]
+async fn a_refused_connection_should_be_reported_as_unhealthy(#[case] version: MqttVersion, #[case] connack: Vec<u8>, #[case] reason: &str) {
+    let port = a_broker_replying(vec![connack]).await;
+    let configuration = a_mqtt_configuration(port, version, false);
+
+    let result = Mqtt.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other(reason.to_string())));
+}
+
+#[tokio::test]
+async fn a_rejected_subscription_should_be_reported_as_unhealthy() {
+    let port = a_broker_replying(vec![vec![0x20, 2, 0, 0], vec![0x90, 3, 0, 1, 0x80]]).await;
+    let configuration = a_mqtt_configuration(port, MqttVersion::V311, true);
+
+    let result = Mqtt.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other(String::from("subscribe rejected '128'"))));
+}
+
+#[tokio::test]
+async fn unreachable_broker_should_be_reported_as_error() {
+    let unused_port = TcpListener::bind("localhost:0").unwrap()
+        .local_addr().unwrap()
+        .port();
+    let configuration = a_mqtt_configuration(unused_port, MqttVersion::V311, false);
+
+    let result = Mqtt.get_health(&configuration).await;
+
+    assert!(let Err(error) = result);
+    check!(error.message.starts_with("network error"));
+}
+
+/// Starts a broker answering each packet sent by the client with the given packet.
+async fn a_broker_replying(packets: Vec<Vec<u8>>) -> u16 {
+    let listener = tokio::net::TcpListener::bind("localhost:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+
+        for packet in packets {
+            if read_packet(&mut stream).await.is_err() {
+                return;
+            }
+
+            stream.write_all(&packet).await.unwrap();
+        }
+
+        while read_packet(&mut stream).await.is_ok() {}
+    });
+
+    port
+}
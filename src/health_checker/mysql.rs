@@ -0,0 +1,102 @@
+use async_trait::async_trait;
+use log::{debug, error, info};
+use mysql_async::prelude::Queryable;
+use mysql_async::{Conn, OptsBuilder, Row, Value};
+use crate::configuration::Configuration;
+use crate::health_checker::Reason::{Other, Timeout};
+use crate::health_checker::{HealthCheck, NetworkError, State};
+use crate::health_checker::sql::check_value;
+
+#[cfg(test)]
+#[path = "./mysql_test.rs"]
+mod test;
+
+const DEFAULT_USERNAME: &str = "root";
+
+pub(crate) struct Mysql;
+
+#[async_trait]
+impl HealthCheck for Mysql {
+    async fn get_health(&self, configuration: &Configuration) -> Result<State, NetworkError> {
+        let timeout: std::time::Duration = configuration.timeout.into();
+
+        let result = match tokio::time::timeout(timeout, ping(configuration)).await {
+            Err(_) => Ok(State::Unhealthy(Timeout(timeout))),
+            Ok(result) => result,
+        };
+
+        match &result {
+            Ok(state) => info!("state {}", state),
+            Err(failure) => error!("{}", failure.message),
+        }
+
+        result
+    }
+}
+
+async fn ping(configuration: &Configuration) -> Result<State, NetworkError> {
+    let username = configuration.username.clone()
+        .map(String::from)
+        .unwrap_or_else(|| DEFAULT_USERNAME.to_string());
+
+    let options = OptsBuilder::default()
+        .ip_or_hostname("localhost")
+        .tcp_port(configuration.port.into())
+        .prefer_socket(false)
+        .user(Some(&username))
+        .pass(configuration.password.clone().map(String::from))
+        .db_name(configuration.database.clone().map(String::from));
+
+    debug!("connecting as '{}'", username);
+
+    let mut connection = match Conn::new(options).await {
+        Ok(connection) => connection,
+        Err(e) => return unhealthy_on_server_error(e),
+    };
+
+    let result = match connection.ping().await {
+        Ok(_) => match &configuration.sql_query {
+            None => Ok(State::Healthy),
+            Some(query) => run_query(&mut connection, &String::from(query.clone()), configuration).await,
+        },
+        Err(e) => unhealthy_on_server_error(e),
+    };
+
+    if let Err(e) = connection.disconnect().await {
+        debug!("disconnection failed: {}", e);
+    }
+
+    result
+}
+
+async fn run_query(connection: &mut Conn, query: &str, configuration: &Configuration) -> Result<State, NetworkError> {
+    if let Err(e) = connection.query_drop("SET SESSION TRANSACTION READ ONLY").await {
+        return unhealthy_on_server_error(e);
+    }
+
+    debug!("running query '{}'", query);
+
+    let row = match connection.query_first::<Row, _>(query).await {
+        Ok(row) => row,
+        Err(e) => return unhealthy_on_server_error(e),
+    };
+
+    let value = row.map(|row| match row.as_ref(0) {
+        None | Some(Value::NULL) => None,
+        Some(Value::Bytes(bytes)) => Some(String::from_utf8_lossy(bytes).into_owned()),
+        Some(value) => Some(value.as_sql(true)),
+    });
+
+    Ok(check_value(configuration.sql_expectation.as_ref(), value))
+}
+
+fn unhealthy_on_server_error(error: mysql_async::Error) -> Result<State, NetworkError> {
+    match error {
+        mysql_async::Error::Server(server_error) => {
+            Ok(State::Unhealthy(Other(format!("error response '{} {}'", server_error.code, server_error.message))))
+        }
+        error => Err(NetworkError {
+            message: format!("network error: {}", error),
+        }),
+    }
+}
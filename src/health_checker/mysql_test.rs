@@ -0,0 +1,196 @@
+use crate::health_checker::Reason::Other;
+use crate::health_checker::State::Healthy;
+use crate::health_checker::State::Unhealthy;
+use assert2::{check, assert};
+use rstest::rstest;
+use mysql_async::{Conn, OptsBuilder};
+use std::net::TcpListener;
+use testcontainers_modules::mysql::Mysql as MysqlContainer;
+use testcontainers_modules::testcontainers::runners::AsyncRunner;
+use testcontainers_modules::testcontainers::{ContainerAsync, ImageExt};
+use tokio::io::AsyncWriteExt;
+use crate::configuration::fixtures::{a_mysql_configuration, a_mysql_configuration_with_query};
+use crate::health_checker::mysql::Mysql;
+use crate::health_checker::HealthCheck;
+
+const MYSQL_PORT: u16 = 3306;
+
+const USERS: &str = "
+CREATE USER 'native'@'%' IDENTIFIED WITH mysql_native_password BY 'secret';
+CREATE USER 'sha2'@'%' IDENTIFIED WITH caching_sha2_password BY 'secret';
+";
+
+#[tokio::test]
+async fn a_healthy_mysql_should_be_reported() {
+    let mysql_container = start_mysql().await;
+
+    let port = mysql_container.get_host_port_ipv4(MYSQL_PORT).await.unwrap();
+    let configuration = a_mysql_configuration(port, "root", None, Some("test"));
+
+    let result = Mysql.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[rstest]
+#[case::mysql_native_password("native")]
+#[case::caching_sha2_password("sha2")]
+#[tokio::test]
+async fn a_user_authenticated_with_a_password_should_be_reported_as_healthy(#[case] username: &str) {
+    let mysql_container = start_mysql().await;
+
+    let port = mysql_container.get_host_port_ipv4(MYSQL_PORT).await.unwrap();
+    let configuration = a_mysql_configuration(port, username, Some("secret"), None);
+
+    let result = Mysql.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[tokio::test]
+async fn a_denied_access_should_be_reported_as_unhealthy() {
+    let mysql_container = start_mysql().await;
+
+    let port = mysql_container.get_host_port_ipv4(MYSQL_PORT).await.unwrap();
+    let configuration = a_mysql_configuration(port, "sha2", Some("wrong"), None);
+
+    let result = Mysql.get_health(&configuration).await;
+
+    assert!(let Ok(Unhealthy(Other(reason))) = result);
+    check!(reason.starts_with("error response '1045"));
+}
+
+#[tokio::test]
+async fn an_unknown_database_should_be_reported_as_unhealthy() {
+    let mysql_container = start_mysql().await;
+
+    let port = mysql_container.get_host_port_ipv4(MYSQL_PORT).await.unwrap();
+    let configuration = a_mysql_configuration(port, "root", None, Some("unknown"));
+
+    let result = Mysql.get_health(&configuration).await;
+
+    assert!(let Ok(Unhealthy(Other(reason))) = result);
+    check!(reason.starts_with("error response '1049"));
+}
+
+#[tokio::test]
+async fn too_many_connections_should_be_reported_as_unhealthy() {
+    let mysql_container = MysqlContainer::default()
+        .with_init_sql(USERS.to_string().into_bytes())
+        .with_cmd(["--max-connections=1"])
+        .start()
+        .await
+        .unwrap();
+
+    let port = mysql_container.get_host_port_ipv4(MYSQL_PORT).await.unwrap();
+
+    // the only connection left once this one is held is reserved to the administrators
+    let held_connection = Conn::new(OptsBuilder::default()
+        .ip_or_hostname("localhost")
+        .tcp_port(port)
+        .prefer_socket(false)
+        .user(Some("root")))
+        .await
+        .unwrap();
+    let configuration = a_mysql_configuration(port, "native", Some("secret"), None);
+
+    let result = Mysql.get_health(&configuration).await;
+
+    drop(held_connection);
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other(String::from("error response '1040 Too many connections'"))));
+}
+
+#[tokio::test]
+async fn a_connection_rejected_before_the_handshake_should_be_reported_as_unhealthy() {
+    let port = a_server_rejecting_the_connection(1040, "Too many connections").await;
+    let configuration = a_mysql_configuration(port, "root", None, None);
+
+    let result = Mysql.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other(String::from("error response '1040 Too many connections'"))));
+}
+
+#[tokio::test]
+async fn a_query_returning_the_expected_value_should_be_reported_as_healthy() {
+    let mysql_container = start_mysql().await;
+
+    let port = mysql_container.get_host_port_ipv4(MYSQL_PORT).await.unwrap();
+    let configuration = a_mysql_configuration_with_query(port, "SELECT @@read_only", "0");
+
+    let result = Mysql.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[tokio::test]
+async fn a_query_returning_an_unexpected_value_should_be_reported_as_unhealthy() {
+    let mysql_container = start_mysql().await;
+
+    let port = mysql_container.get_host_port_ipv4(MYSQL_PORT).await.unwrap();
+    let configuration = a_mysql_configuration_with_query(port, "SELECT 42", "< 10");
+
+    let result = Mysql.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other(String::from("query returned '42', expected '< 10'"))));
+}
+
+#[tokio::test]
+async fn a_writing_query_should_be_reported_as_unhealthy() {
+    let mysql_container = start_mysql().await;
+
+    let port = mysql_container.get_host_port_ipv4(MYSQL_PORT).await.unwrap();
+    let configuration = a_mysql_configuration_with_query(port, "INSERT INTO mysql.user (Host) VALUES ('dockteur')", "1");
+
+    let result = Mysql.get_health(&configuration).await;
+
+    assert!(let Ok(Unhealthy(Other(reason))) = result);
+    check!(reason.starts_with("error response '1792"));
+}
+
+#[tokio::test]
+async fn unreachable_mysql_should_be_reported_as_error() {
+    let unused_port = TcpListener::bind("localhost:0").unwrap()
+        .local_addr().unwrap()
+        .port();
+    let configuration = a_mysql_configuration(unused_port, "root", None, None);
+
+    let result = Mysql.get_health(&configuration).await;
+
+    assert!(let Err(error) = result);
+    check!(error.message.starts_with("network error"));
+}
+
+async fn start_mysql() -> ContainerAsync<MysqlContainer> {
+    MysqlContainer::default()
+        .with_init_sql(USERS.to_string().into_bytes())
+        .start()
+        .await
+        .unwrap()
+}
+
+/// Starts a server sending an error packet in place of the handshake, the way a server out of connections does.
+async fn a_server_rejecting_the_connection(code: u16, message: &str) -> u16 {
+    let listener = tokio::net::TcpListener::bind("localhost:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    let mut payload = vec![0xFF];
+    payload.extend_from_slice(&code.to_le_bytes());
+    payload.extend_from_slice(message.as_bytes());
+
+    let mut packet = (payload.len() as u32).to_le_bytes()[..3].to_vec();
+    packet.push(0);
+    packet.extend_from_slice(&payload);
+
+    tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        stream.write_all(&packet).await.unwrap();
+    });
+
+    port
+}
@@ -0,0 +1,91 @@
+use async_trait::async_trait;
+use log::debug;
+use serde_json::{json, Value};
+use tokio::net::TcpStream;
+use crate::configuration::Configuration;
+use crate::health_checker::Reason::Other;
+use crate::health_checker::wire::{network_error, run_with_timeout, LineConnection};
+use crate::health_checker::{HealthCheck, NetworkError, State};
+
+#[cfg(test)]
+#[path = "./nats_test.rs"]
+mod test;
+
+pub(crate) struct Nats;
+
+#[async_trait]
+impl HealthCheck for Nats {
+    async fn get_health(&self, configuration: &Configuration) -> Result<State, NetworkError> {
+        run_with_timeout(configuration, ping(configuration)).await
+    }
+}
+
+async fn ping(configuration: &Configuration) -> Result<State, Result<State, NetworkError>> {
+    let port: u16 = configuration.port.into();
+
+    let stream = TcpStream::connect(("localhost", port)).await
+        .map_err(network_error)?;
+
+    let mut connection = LineConnection::new(stream);
+
+    let greeting = connection.read_line().await?;
+
+    let info = match greeting.strip_prefix("INFO ").map(serde_json::from_str::<Value>) {
+        Some(Ok(info)) => info,
+        _ => return Err(Ok(State::Unhealthy(Other(format!("unexpected greeting '{}'", greeting))))),
+    };
+
+    debug!(
+        "server {} version {}, jetstream {}",
+        info["server_id"].as_str().unwrap_or("unknown"),
+        info["version"].as_str().unwrap_or("unknown"),
+        if info["jetstream"].as_bool().unwrap_or(false) { "enabled" } else { "disabled" },
+    );
+
+    if info["tls_required"].as_bool().unwrap_or(false) {
+        return Err(Ok(State::Unhealthy(Other(String::from("server requires TLS")))));
+    }
+
+    connection.send(&format!("CONNECT {}", connect_options(configuration))).await?;
+    connection.send("PING").await?;
+
+    loop {
+        let line = connection.read_line().await?;
+
+        match line.as_str() {
+            "PONG" => return Ok(State::Healthy),
+            "PING" => connection.send("PONG").await?,
+            "+OK" => {}
+            line if line.starts_with("INFO ") => {}
+            line => match line.strip_prefix("-ERR ") {
+                Some(message) => return Ok(State::Unhealthy(Other(format!("error response {}", message)))),
+                None => return Ok(State::Unhealthy(Other(format!("unexpected response '{}'", line)))),
+            },
+        }
+    }
+}
+
+fn connect_options(configuration: &Configuration) -> Value {
+    let mut options = json!({
+        "verbose": false,
+        "pedantic": false,
+        "name": "dockteur",
+        "lang": "rust",
+        "version": std::env!("CARGO_PKG_VERSION"),
+        "protocol": 1,
+    });
+
+    if let Some(token) = &configuration.token {
+        options["auth_token"] = json!(String::from(token.clone()));
+    }
+
+    if let Some(username) = &configuration.username {
+        options["user"] = json!(String::from(username.clone()));
+    }
+
+    if let Some(password) = &configuration.password {
+        options["pass"] = json!(String::from(password.clone()));
+    }
+
+    options
+}
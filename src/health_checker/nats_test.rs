@@ -0,0 +1,131 @@
+use crate::health_checker::Reason::Other;
+use crate::health_checker::State::Healthy;
+use crate::health_checker::State::Unhealthy;
+use assert2::{check, assert};
+use std::net::TcpListener;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use testcontainers_modules::nats::{Nats as NatsContainer, NatsServerCmd};
+use testcontainers_modules::testcontainers::core::ImageExt;
+use testcontainers_modules::testcontainers::runners::AsyncRunner;
+use crate::configuration::fixtures::{a_nats_configuration, a_nats_configuration_with_credentials};
+use crate::health_checker::nats::Nats;
+use crate::health_checker::HealthCheck;
+
+const NATS_PORT: u16 = 4222;
+
+#[tokio::test]
+async fn a_healthy_server_should_be_reported() {
+    let nats_container = NatsContainer::default()
+        .start()
+        .await
+        .unwrap();
+
+    let port = nats_container.get_host_port_ipv4(NATS_PORT).await.unwrap();
+    let configuration = a_nats_configuration(port);
+
+    let result = Nats.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[tokio::test]
+async fn an_authenticated_user_should_be_reported_as_healthy() {
+    let nats_container = NatsContainer::default()
+        .with_cmd(&NatsServerCmd::default().with_user("dockteur").with_password("secret"))
+        .start()
+        .await
+        .unwrap();
+
+    let port = nats_container.get_host_port_ipv4(NATS_PORT).await.unwrap();
+    let configuration = a_nats_configuration_with_credentials(port, "dockteur", "secret");
+
+    let result = Nats.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[tokio::test]
+async fn a_failed_authentication_should_be_reported_as_unhealthy() {
+    let nats_container = NatsContainer::default()
+        .with_cmd(&NatsServerCmd::default().with_user("dockteur").with_password("secret"))
+        .start()
+        .await
+        .unwrap();
+
+    let port = nats_container.get_host_port_ipv4(NATS_PORT).await.unwrap();
+    let configuration = a_nats_configuration_with_credentials(port, "dockteur", "wrong");
+
+    let result = Nats.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other(String::from("error response 'Authorization Violation'"))));
+}
+
+#[tokio::test]
+async fn an_unexpected_greeting_should_be_reported_as_unhealthy() {
+    let port = a_server_replying(&["HELLO"]).await;
+    let configuration = a_nats_configuration(port);
+
+    let result = Nats.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other(String::from("unexpected greeting 'HELLO'"))));
+}
+
+#[tokio::test]
+async fn a_server_requiring_tls_should_be_reported_as_unhealthy() {
+    let port = a_server_replying(&[r#"INFO {"server_id":"test","tls_required":true}"#]).await;
+    let configuration = a_nats_configuration(port);
+
+    let result = Nats.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other(String::from("server requires TLS"))));
+}
+
+#[tokio::test]
+async fn a_pong_after_server_pings_should_be_reported_as_healthy() {
+    let port = a_server_replying(&[r#"INFO {"server_id":"test"}"#, "PING", "+OK", "PONG"]).await;
+    let configuration = a_nats_configuration(port);
+
+    let result = Nats.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[tokio::test]
+async fn unreachable_server_should_be_reported_as_error() {
+    let unused_port = TcpListener::bind("localhost:0").unwrap()
+        .local_addr().unwrap()
+        .port();
+    let configuration = a_nats_configuration(unused_port);
+
+    let result = Nats.get_health(&configuration).await;
+
+    assert!(let Err(error) = result);
+    check!(error.message.starts_with("network error"));
+}
+
+/// Starts a server sending the given lines, then waiting for the client to close the connection.
+async fn a_server_replying(lines: &[&str]) -> u16 {
+    let listener = tokio::net::TcpListener::bind("localhost:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let lines: Vec<String> = lines.iter().map(|line| format!("{}\r\n", line)).collect();
+
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut stream = BufReader::new(stream);
+
+        for line in lines {
+            stream.get_mut().write_all(line.as_bytes()).await.unwrap();
+        }
+
+        let mut ignored = String::new();
+        while stream.read_line(&mut ignored).await.unwrap_or(0) > 0 {}
+    });
+
+    port
+}
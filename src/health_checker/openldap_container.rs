@@ -0,0 +1,40 @@
+use std::borrow::Cow;
+use testcontainers_modules::testcontainers::core::wait::LogWaitStrategy;
+use testcontainers_modules::testcontainers::core::ContainerPort::Tcp;
+use testcontainers_modules::testcontainers::core::{ContainerPort, WaitFor};
+use testcontainers_modules::testcontainers::Image;
+
+pub const LDAP_PORT: u16 = 389;
+
+pub const ADMIN_DN: &str = "cn=admin,dc=example,dc=org";
+
+pub const ADMIN_PASSWORD: &str = "admin";
+
+#[derive(Default)]
+pub struct OpenldapContainer;
+
+impl Image for OpenldapContainer {
+    fn name(&self) -> &str {
+        "osixia/openldap"
+    }
+
+    fn tag(&self) -> &str {
+        "1.5.0"
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::log(LogWaitStrategy::stdout("slapd starting"))]
+    }
+
+    fn env_vars(&self) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        [
+            ("LDAP_DOMAIN", "example.org"),
+            ("LDAP_ADMIN_PASSWORD", ADMIN_PASSWORD),
+            ("LDAP_TLS", "false"),
+        ]
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[Tcp(LDAP_PORT)]
+    }
+}
@@ -0,0 +1,89 @@
+use async_trait::async_trait;
+use log::debug;
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::net::TcpStream;
+use crate::configuration::Configuration;
+use crate::health_checker::Reason::Other;
+use crate::health_checker::wire::{network_error, run_with_timeout, LineConnection};
+use crate::health_checker::{tls, HealthCheck, NetworkError, State};
+
+#[cfg(test)]
+#[path = "./pop3_test.rs"]
+mod test;
+
+pub(crate) struct Pop3;
+
+#[async_trait]
+impl HealthCheck for Pop3 {
+    async fn get_health(&self, configuration: &Configuration) -> Result<State, NetworkError> {
+        run_with_timeout(configuration, check(configuration)).await
+    }
+}
+
+/// Reads a response, returning its text when positive and its whole line otherwise.
+async fn read_response<S: AsyncRead + AsyncWrite + Unpin>(connection: &mut LineConnection<S>) -> Result<Result<String, String>, Result<State, NetworkError>> {
+    let line = connection.read_line().await?;
+
+    match line.strip_prefix("+OK") {
+        Some(text) => Ok(Ok(text.trim_start().to_string())),
+        None => Ok(Err(line)),
+    }
+}
+
+async fn command<S: AsyncRead + AsyncWrite + Unpin>(connection: &mut LineConnection<S>, command: &str) -> Result<Result<String, String>, Result<State, NetworkError>> {
+    connection.send(command).await?;
+    read_response(connection).await
+}
+
+async fn check(configuration: &Configuration) -> Result<State, Result<State, NetworkError>> {
+    let port: u16 = configuration.port.into();
+
+    let stream = TcpStream::connect(("localhost", port)).await
+        .map_err(network_error)?;
+
+    if configuration.tls {
+        let stream = tls::connect(stream, configuration, &[b"pop3"]).await
+            .map_err(network_error)?;
+
+        session(LineConnection::new(stream), configuration).await
+    } else {
+        session(LineConnection::new(stream), configuration).await
+    }
+}
+
+async fn session<S: AsyncRead + AsyncWrite + Unpin>(mut connection: LineConnection<S>, configuration: &Configuration) -> Result<State, Result<State, NetworkError>> {
+    match read_response(&mut connection).await? {
+        Ok(text) => debug!("server {}", text),
+        Err(line) => match line.strip_prefix("-ERR") {
+            Some(text) => return Ok(State::Unhealthy(Other(format!("connection refused '{}'", text.trim_start())))),
+            None => return Ok(State::Unhealthy(Other(format!("unexpected greeting '{}'", line)))),
+        },
+    }
+
+    if let Some(username) = &configuration.username {
+        let username = String::from(username.clone());
+        let password = configuration.password.clone()
+            .map(String::from)
+            .unwrap_or_default();
+
+        debug!("logging in as '{}'", username);
+
+        let response = match command(&mut connection, &format!("USER {}", username)).await? {
+            Ok(_) => command(&mut connection, &format!("PASS {}", password)).await?,
+            Err(line) => Err(line),
+        };
+
+        if let Err(line) = response {
+            let text = line.strip_prefix("-ERR").unwrap_or(&line).trim_start();
+            return Ok(State::Unhealthy(Other(format!("login failed '{}'", text))));
+        }
+    }
+
+    match command(&mut connection, "QUIT").await {
+        Ok(Err(line)) => debug!("quit failed: {}", line),
+        Ok(Ok(_)) => {}
+        Err(e) => debug!("quit failed: {:?}", e),
+    }
+
+    Ok(State::Healthy)
+}
@@ -0,0 +1,109 @@
+use crate::health_checker::Reason::Other;
+use crate::health_checker::State::Healthy;
+use crate::health_checker::State::Unhealthy;
+use assert2::{check, assert};
+use rstest::rstest;
+use std::net::TcpListener;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use testcontainers_modules::testcontainers::runners::AsyncRunner;
+use crate::configuration::fixtures::{a_pop3_configuration, a_pop3_configuration_with_credentials};
+use crate::health_checker::dovecot_container::{DovecotContainer, DOVECOT_PASSWORD, POP3_PORT};
+use crate::health_checker::pop3::Pop3;
+use crate::health_checker::HealthCheck;
+
+#[tokio::test]
+async fn a_healthy_server_should_be_reported() {
+    let dovecot_container = DovecotContainer
+        .start()
+        .await
+        .unwrap();
+
+    let port = dovecot_container.get_host_port_ipv4(POP3_PORT).await.unwrap();
+    let configuration = a_pop3_configuration(port);
+
+    let result = Pop3.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[tokio::test]
+async fn a_server_accepting_credentials_should_be_reported_as_healthy() {
+    let dovecot_container = DovecotContainer
+        .start()
+        .await
+        .unwrap();
+
+    let port = dovecot_container.get_host_port_ipv4(POP3_PORT).await.unwrap();
+    let configuration = a_pop3_configuration_with_credentials(port, "dockteur", DOVECOT_PASSWORD);
+
+    let result = Pop3.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[tokio::test]
+async fn a_greeting_server_should_be_reported_as_healthy() {
+    let port = a_server_replying(&["+OK Dovecot ready.", "+OK", "+OK Logged in.", "+OK Logging out."]).await;
+    let configuration = a_pop3_configuration_with_credentials(port, "dockteur", "secret");
+
+    let result = Pop3.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[rstest]
+#[case::refused(&["-ERR [SYS/TEMP] too many connections"], "connection refused '[SYS/TEMP] too many connections'")]
+#[case::not_pop3(&["* OK IMAP4rev1 ready"], "unexpected greeting '* OK IMAP4rev1 ready'")]
+#[case::rejected_user(&["+OK ready", "-ERR unknown user"], "login failed 'unknown user'")]
+#[case::rejected_password(&["+OK ready", "+OK", "-ERR [AUTH] Authentication failed."], "login failed '[AUTH] Authentication failed.'")]
+#[tokio::test]
+async fn a_failing_server_should_be_reported_as_unhealthy(#[case] replies: &[&str], #[case] reason: &str) {
+    let port = a_server_replying(replies).await;
+    let configuration = a_pop3_configuration_with_credentials(port, "dockteur", "secret");
+
+    let result = Pop3.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other(reason.to_string())));
+}
+
+#[tokio::test]
+async fn unreachable_server_should_be_reported_as_error() {
+    let unused_port = TcpListener::bind("localhost:0").unwrap()
+        .local_addr().unwrap()
+        .port();
+    let configuration = a_pop3_configuration(unused_port);
+
+    let result = Pop3.get_health(&configuration).await;
+
+    assert!(let Err(error) = result);
+    check!(error.message.starts_with("network error"));
+}
+
+/// Starts a server sending the first reply as greeting, then each following reply once a command is received.
+async fn a_server_replying(replies: &[&str]) -> u16 {
+    let listener = tokio::net::TcpListener::bind("localhost:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let replies: Vec<String> = replies.iter().map(|reply| format!("{}\r\n", reply)).collect();
+
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut stream = BufReader::new(stream);
+        let mut command = String::new();
+
+        for (index, reply) in replies.iter().enumerate() {
+            if index > 0 && stream.read_line(&mut command).await.unwrap_or(0) == 0 {
+                return;
+            }
+
+            stream.get_mut().write_all(reply.as_bytes()).await.unwrap();
+        }
+
+        while stream.read_line(&mut command).await.unwrap_or(0) > 0 {}
+    });
+
+    port
+}
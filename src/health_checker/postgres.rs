@@ -0,0 +1,114 @@
+use async_trait::async_trait;
+use log::{debug, error, info};
+use tokio_postgres::error::SqlState;
+use tokio_postgres::{NoTls, SimpleQueryMessage};
+use crate::configuration::Configuration;
+use crate::health_checker::Reason::{Other, Timeout};
+use crate::health_checker::{HealthCheck, NetworkError, State};
+use crate::health_checker::sql::check_value;
+
+#[cfg(test)]
+#[path = "./postgres_test.rs"]
+mod test;
+
+const DEFAULT_USERNAME: &str = "postgres";
+
+const DEFAULT_QUERY: &str = "SELECT 1";
+
+pub(crate) struct Postgres;
+
+#[async_trait]
+impl HealthCheck for Postgres {
+    async fn get_health(&self, configuration: &Configuration) -> Result<State, NetworkError> {
+        let timeout: std::time::Duration = configuration.timeout.into();
+
+        let result = match tokio::time::timeout(timeout, query(configuration)).await {
+            Err(_) => Ok(State::Unhealthy(Timeout(timeout))),
+            Ok(result) => result,
+        };
+
+        match &result {
+            Ok(state) => info!("state {}", state),
+            Err(failure) => error!("{}", failure.message),
+        }
+
+        result
+    }
+}
+
+async fn query(configuration: &Configuration) -> Result<State, NetworkError> {
+    let username = configuration.username.clone()
+        .map(String::from)
+        .unwrap_or_else(|| DEFAULT_USERNAME.to_string());
+    let database = configuration.database.clone()
+        .map(String::from)
+        .unwrap_or_else(|| username.clone());
+
+    let mut config = tokio_postgres::Config::new();
+    config
+        .host("localhost")
+        .port(configuration.port.into())
+        .user(&username)
+        .dbname(&database)
+        .application_name("dockteur")
+        .connect_timeout(configuration.timeout.into());
+
+    if let Some(password) = &configuration.password {
+        config.password(String::from(password.clone()));
+    }
+
+    if configuration.sql_query.is_some() {
+        config.options("-c default_transaction_read_only=on");
+    }
+
+    debug!("connecting to database '{}' as '{}'", database, username);
+
+    let (client, connection) = match config.connect(NoTls).await {
+        Ok(connected) => connected,
+        Err(e) => return unhealthy_on_server_error(e),
+    };
+
+    tokio::spawn(async move {
+        if let Err(e) = connection.await {
+            debug!("connection closed: {}", e);
+        }
+    });
+
+    let query = configuration.sql_query.clone()
+        .map(String::from)
+        .unwrap_or_else(|| DEFAULT_QUERY.to_string());
+
+    debug!("running query '{}'", query);
+
+    let messages = match client.simple_query(&query).await {
+        Ok(messages) => messages,
+        Err(e) => return unhealthy_on_server_error(e),
+    };
+
+    let row = messages.iter().find_map(|message| match message {
+        SimpleQueryMessage::Row(row) => Some(row),
+        _ => None,
+    });
+
+    let value = match row.map(|row| row.try_get(0)) {
+        None => None,
+        Some(Ok(value)) => Some(value.map(String::from)),
+        Some(Err(_)) => return Ok(State::Unhealthy(Other(String::from("query returned no column")))),
+    };
+
+    Ok(check_value(configuration.sql_expectation.as_ref(), value))
+}
+
+fn unhealthy_on_server_error(error: tokio_postgres::Error) -> Result<State, NetworkError> {
+    match error.as_db_error() {
+        Some(db_error) if db_error.code() == &SqlState::CANNOT_CONNECT_NOW => {
+            Ok(State::Unhealthy(Other(format!("server is not ready '{}'", db_error.message()))))
+        }
+        Some(db_error) => {
+            Ok(State::Unhealthy(Other(format!("error response '{} {}'", db_error.code().code(), db_error.message()))))
+        }
+        None => Err(NetworkError {
+            message: format!("network error: {}", error),
+        }),
+    }
+}
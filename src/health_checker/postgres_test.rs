@@ -0,0 +1,243 @@
+use crate::health_checker::Reason::{Other, Timeout};
+use crate::health_checker::State::Healthy;
+use crate::health_checker::State::Unhealthy;
+use assert2::{check, assert};
+use std::net::TcpListener;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use testcontainers_modules::postgres::Postgres as PostgresContainer;
+use testcontainers_modules::testcontainers::runners::AsyncRunner;
+use crate::configuration::fixtures::{a_postgres_configuration, a_postgres_configuration_with_query};
+use crate::health_checker::postgres::Postgres;
+use crate::health_checker::HealthCheck;
+
+const POSTGRES_PORT: u16 = 5432;
+
+#[tokio::test]
+async fn a_healthy_postgres_should_be_reported() {
+    let postgres_container = PostgresContainer::default()
+        .start()
+        .await
+        .unwrap();
+
+    let port = postgres_container.get_host_port_ipv4(POSTGRES_PORT).await.unwrap();
+    let configuration = a_postgres_configuration(port, "postgres", "postgres", "postgres");
+
+    let result = Postgres.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[tokio::test]
+async fn a_failed_authentication_should_be_reported_as_unhealthy() {
+    let postgres_container = PostgresContainer::default()
+        .start()
+        .await
+        .unwrap();
+
+    let port = postgres_container.get_host_port_ipv4(POSTGRES_PORT).await.unwrap();
+    let configuration = a_postgres_configuration(port, "postgres", "wrong", "postgres");
+
+    let result = Postgres.get_health(&configuration).await;
+
+    assert!(let Ok(Unhealthy(Other(reason))) = result);
+    check!(reason.starts_with("error response '28P01"));
+}
+
+#[tokio::test]
+async fn an_unknown_database_should_be_reported_as_unhealthy() {
+    let postgres_container = PostgresContainer::default()
+        .start()
+        .await
+        .unwrap();
+
+    let port = postgres_container.get_host_port_ipv4(POSTGRES_PORT).await.unwrap();
+    let configuration = a_postgres_configuration(port, "postgres", "postgres", "unknown");
+
+    let result = Postgres.get_health(&configuration).await;
+
+    assert!(let Ok(Unhealthy(Other(reason))) = result);
+    check!(reason.starts_with("error response '3D000"));
+}
+
+#[tokio::test]
+async fn a_query_returning_the_expected_value_should_be_reported_as_healthy() {
+    let postgres_container = PostgresContainer::default()
+        .start()
+        .await
+        .unwrap();
+
+    let port = postgres_container.get_host_port_ipv4(POSTGRES_PORT).await.unwrap();
+    let configuration = a_postgres_configuration_with_query(port, "SELECT pg_is_in_recovery()", "false");
+
+    let result = Postgres.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[tokio::test]
+async fn a_query_returning_an_unexpected_value_should_be_reported_as_unhealthy() {
+    let postgres_container = PostgresContainer::default()
+        .start()
+        .await
+        .unwrap();
+
+    let port = postgres_container.get_host_port_ipv4(POSTGRES_PORT).await.unwrap();
+    let configuration = a_postgres_configuration_with_query(port, "SELECT 42", "< 10");
+
+    let result = Postgres.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other(String::from("query returned '42', expected '< 10'"))));
+}
+
+#[tokio::test]
+async fn a_query_returning_no_column_should_be_reported_as_unhealthy() {
+    let postgres_container = PostgresContainer::default()
+        .start()
+        .await
+        .unwrap();
+
+    let port = postgres_container.get_host_port_ipv4(POSTGRES_PORT).await.unwrap();
+    let configuration = a_postgres_configuration_with_query(port, "SELECT FROM pg_database", "1");
+
+    let result = Postgres.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other(String::from("query returned no column"))));
+}
+
+#[tokio::test]
+async fn a_row_without_column_should_be_reported_as_unhealthy() {
+    let port = a_server_answering_rows_without_column().await;
+    let configuration = a_postgres_configuration_with_query(port, "SELECT", "1");
+
+    let result = Postgres.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other(String::from("query returned no column"))));
+}
+
+#[tokio::test]
+async fn a_writing_query_should_be_reported_as_unhealthy() {
+    let postgres_container = PostgresContainer::default()
+        .start()
+        .await
+        .unwrap();
+
+    let port = postgres_container.get_host_port_ipv4(POSTGRES_PORT).await.unwrap();
+    let configuration = a_postgres_configuration_with_query(port, "CREATE TABLE dockteur (id INT)", "1");
+
+    let result = Postgres.get_health(&configuration).await;
+
+    assert!(let Ok(Unhealthy(Other(reason))) = result);
+    check!(reason.starts_with("error response '25006"));
+}
+
+#[tokio::test]
+async fn a_slow_query_should_be_reported_as_timed_out() {
+    let postgres_container = PostgresContainer::default()
+        .start()
+        .await
+        .unwrap();
+
+    let port = postgres_container.get_host_port_ipv4(POSTGRES_PORT).await.unwrap();
+    let configuration = a_postgres_configuration_with_query(port, "SELECT pg_sleep(5)", "1");
+
+    let result = Postgres.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Timeout(Duration::from_millis(500))));
+}
+
+#[tokio::test]
+async fn a_starting_postgres_should_be_reported_as_not_ready() {
+    let port = a_server_rejecting_the_startup("57P03", "the database system is starting up").await;
+    let configuration = a_postgres_configuration(port, "postgres", "postgres", "postgres");
+
+    let result = Postgres.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other(String::from("server is not ready 'the database system is starting up'"))));
+}
+
+#[tokio::test]
+async fn unreachable_postgres_should_be_reported_as_error() {
+    let unused_port = TcpListener::bind("localhost:0").unwrap()
+        .local_addr().unwrap()
+        .port();
+    let configuration = a_postgres_configuration(unused_port, "postgres", "postgres", "postgres");
+
+    let result = Postgres.get_health(&configuration).await;
+
+    assert!(let Err(error) = result);
+    check!(error.message.starts_with("network error"));
+}
+
+/// Starts a server answering the startup message with an error response, the way a starting Postgres does.
+async fn a_server_rejecting_the_startup(code: &str, message: &str) -> u16 {
+    let listener = tokio::net::TcpListener::bind("localhost:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    let mut fields = Vec::new();
+    for (field, value) in [(b'S', "FATAL"), (b'V', "FATAL"), (b'C', code), (b'M', message)] {
+        fields.push(field);
+        fields.extend_from_slice(value.as_bytes());
+        fields.push(0);
+    }
+    fields.push(0);
+
+    let mut response = vec![b'E'];
+    response.extend_from_slice(&(fields.len() as u32 + 4).to_be_bytes());
+    response.extend_from_slice(&fields);
+
+    tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+
+        let length = stream.read_u32().await.unwrap();
+        let mut startup = vec![0u8; length as usize - 4];
+        stream.read_exact(&mut startup).await.unwrap();
+
+        stream.write_all(&response).await.unwrap();
+    });
+
+    port
+}
+
+/// Starts a server accepting the startup without authentication, then answering the query with a row of no column.
+async fn a_server_answering_rows_without_column() -> u16 {
+    let listener = tokio::net::TcpListener::bind("localhost:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    let ready = [b'Z', 0, 0, 0, 5, b'I'];
+    let startup_response = [[b'R', 0, 0, 0, 8, 0, 0, 0, 0].as_slice(), &ready].concat();
+    let query_response = [
+        [b'T', 0, 0, 0, 6, 0, 0].as_slice(),
+        &[b'D', 0, 0, 0, 6, 0, 0],
+        &[b'C', 0, 0, 0, 13],
+        b"SELECT 1\0",
+        &ready,
+    ].concat();
+
+    tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+
+        let length = stream.read_u32().await.unwrap();
+        let mut startup = vec![0u8; length as usize - 4];
+        stream.read_exact(&mut startup).await.unwrap();
+        stream.write_all(&startup_response).await.unwrap();
+
+        let _kind = stream.read_u8().await.unwrap();
+        let length = stream.read_u32().await.unwrap();
+        let mut query = vec![0u8; length as usize - 4];
+        stream.read_exact(&mut query).await.unwrap();
+        stream.write_all(&query_response).await.unwrap();
+
+        let mut ignored = Vec::new();
+        let _ = stream.read_to_end(&mut ignored).await;
+    });
+
+    port
+}
@@ -0,0 +1,41 @@
+use std::borrow::Cow;
+use testcontainers_modules::testcontainers::core::wait::LogWaitStrategy;
+use testcontainers_modules::testcontainers::core::ContainerPort::Tcp;
+use testcontainers_modules::testcontainers::core::{ContainerPort, WaitFor};
+use testcontainers_modules::testcontainers::Image;
+
+pub const FTP_PORT: u16 = 21;
+
+pub const FTP_USERNAME: &str = "dockteur";
+
+pub const FTP_PASSWORD: &str = "secret";
+
+#[derive(Default)]
+pub struct PureFtpdContainer;
+
+impl Image for PureFtpdContainer {
+    fn name(&self) -> &str {
+        "stilliard/pure-ftpd"
+    }
+
+    fn tag(&self) -> &str {
+        "hardened"
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::log(LogWaitStrategy::stdout("Starting Pure-FTPd"))]
+    }
+
+    fn env_vars(&self) -> impl IntoIterator<Item = (impl Into<Cow<'_, str>>, impl Into<Cow<'_, str>>)> {
+        [
+            ("PUBLICHOST", "localhost"),
+            ("FTP_USER_NAME", FTP_USERNAME),
+            ("FTP_USER_PASS", FTP_PASSWORD),
+            ("FTP_USER_HOME", "/home/dockteur"),
+        ]
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[Tcp(FTP_PORT)]
+    }
+}
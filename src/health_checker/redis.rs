@@ -1,62 +1,253 @@
+use std::pin::pin;
+use std::time::Duration;
 use async_trait::async_trait;
+use futures_util::StreamExt;
 use log::{debug, error, info};
-use crate::configuration::Configuration;
+use url::Url;
+use redis::aio::{ConnectionLike, MultiplexedConnection};
+use redis::{Client, ErrorKind, RedisError, Value};
+use tokio::time::Instant;
+use crate::configuration::{Configuration, RedisCheck, RedisKeyPrefix, RedisProtocol, RedisReply};
 use crate::health_checker::Reason::{Other, Timeout};
-use crate::health_checker::{HealthCheck, NetworkError, State};
+use crate::health_checker::{unique_token, HealthCheck, NetworkError, State};
 
 #[cfg(test)]
 #[path = "./redis_test.rs"]
 mod test;
 
+const ROUND_TRIP_KEY_TTL_MILLIS: u64 = 30_000;
+
 pub(crate) struct Redis;
 
 #[async_trait]
 impl HealthCheck for Redis {
     async fn get_health(&self, configuration: &Configuration) -> Result<State, NetworkError> {
-        let port: u16 = configuration.port.into();
-        let url = format!("redis://localhost:{}", port);
+        let timeout: Duration = configuration.timeout.into();
 
-        debug!("connecting to {}", url);
+        let mut con = match connect(configuration).await {
+            Ok(con) => con,
+            Err(result) => return report(result),
+        };
 
-        let client = redis::Client::open(url.as_str())
-            .map_err(|e| NetworkError { message: format!("network error: {}", e) })?;
+        let check = match configuration.redis_check {
+            RedisCheck::Command => tokio::time::timeout(timeout, send_command(&mut con, configuration)).await,
+            RedisCheck::RoundTrip => tokio::time::timeout(timeout, round_trip(&mut con, &configuration.redis_key_prefix)).await,
+            RedisCheck::PubSub => Ok(pub_sub(&mut con, configuration).await),
+        };
 
-        let timeout: std::time::Duration = configuration.timeout.into();
+        let result = match check {
+            Err(_) => Ok(State::Unhealthy(Timeout(timeout))),
+            Ok(result) => result,
+        };
 
-        let connection = tokio::time::timeout(timeout, client.get_multiplexed_async_connection())
-            .await;
+        report(result)
+    }
+}
 
-        let mut con = match connection {
-            Err(_) => {
-                let state = State::Unhealthy(Timeout(timeout));
-                info!("state {}", state);
-                return Ok(state);
-            }
-            Ok(result) => result.map_err(|e| NetworkError {
-                message: format!("network error: {}", e),
-            })?,
-        };
+fn client(configuration: &Configuration) -> Result<Client, NetworkError> {
+    let url = connection_url(configuration);
+
+    debug!("connecting to {}", url);
 
-        let ping_result = tokio::time::timeout(
-            timeout,
-            redis::cmd("PING").query_async::<String>(&mut con),
-        )
+    Client::open(url.as_str())
+        .map_err(|e| NetworkError { message: format!("network error: {}", e) })
+}
+
+pub(super) async fn connect(configuration: &Configuration) -> Result<MultiplexedConnection, Result<State, NetworkError>> {
+    let client = client(configuration).map_err(Err)?;
+
+    let timeout: Duration = configuration.timeout.into();
+
+    let connection = tokio::time::timeout(timeout, client.get_multiplexed_async_connection())
         .await;
 
-        let result = match ping_result {
-            Err(_) => Ok(State::Unhealthy(Timeout(timeout))),
-            Ok(Ok(pong)) if pong == "PONG" => Ok(State::Healthy),
-            Ok(Ok(unexpected)) => Ok(State::Unhealthy(Other(format!("unexpected response '{}'", unexpected)))),
-            Ok(Err(e)) => Err(NetworkError {
-                message: format!("network error: {}", e),
-            }),
-        };
+    match connection {
+        Err(_) => Err(Ok(State::Unhealthy(Timeout(timeout)))),
+        Ok(Ok(con)) => Ok(con),
+        Ok(Err(e)) => Err(unhealthy_on_connection_error(e)),
+    }
+}
 
-        match &result {
-            Ok(state) => info!("state {}", state),
-            Err(failure) => error!("{}", failure.message),
+pub(super) fn report(result: Result<State, NetworkError>) -> Result<State, NetworkError> {
+    match &result {
+        Ok(state) => info!("state {}", state),
+        Err(failure) => error!("{}", failure.message),
+    }
+
+    result
+}
+
+fn connection_url(configuration: &Configuration) -> Url {
+    let database: u32 = configuration.redis_database.into();
+    let protocol = match configuration.redis_protocol {
+        RedisProtocol::Resp2 => "resp2",
+        RedisProtocol::Resp3 => "resp3",
+    };
+
+    let mut url = match &configuration.socket_path {
+        None => {
+            let mut url = Url::parse("redis://localhost").unwrap();
+            url.set_port(Some(configuration.port.into())).unwrap();
+            url.set_path(&database.to_string());
+            url
         }
+        Some(socket_path) => {
+            let mut url = Url::parse("redis+unix://").unwrap();
+            url.set_path(&String::from(socket_path.clone()));
+            url.query_pairs_mut().append_pair("db", &database.to_string());
+            url
+        }
+    };
+
+    url.query_pairs_mut().append_pair("protocol", protocol);
+    url
+}
+
+async fn send_command(con: &mut impl ConnectionLike, configuration: &Configuration) -> Result<State, NetworkError> {
+    let mut command = redis::cmd(configuration.redis_command.name());
+    command.arg(configuration.redis_command.args());
 
-        result
+    match command.query_async::<Value>(con).await {
+        Ok(value) => Ok(check_reply(&configuration.redis_reply, &value)),
+        Err(e) => unhealthy_on_error_response(e, "error response"),
     }
 }
+
+async fn round_trip(con: &mut impl ConnectionLike, prefix: &RedisKeyPrefix) -> Result<State, NetworkError> {
+    let token = unique_token();
+    let key = format!("{}{}", String::from(prefix.clone()), token);
+
+    debug!("writing key {}", key);
+
+    let written = redis::cmd("SET")
+        .arg(&key)
+        .arg(&token)
+        .arg("PX")
+        .arg(ROUND_TRIP_KEY_TTL_MILLIS)
+        .query_async::<()>(con)
+        .await;
+
+    if let Err(e) = written {
+        return unhealthy_on_error_response(e, "write rejected");
+    }
+
+    let value = match redis::cmd("GET").arg(&key).query_async::<Option<String>>(con).await {
+        Ok(value) => value,
+        Err(e) => return unhealthy_on_error_response(e, "read rejected"),
+    };
+
+    if let Err(e) = redis::cmd("DEL").arg(&key).query_async::<()>(con).await {
+        return unhealthy_on_error_response(e, "delete rejected");
+    }
+
+    match value {
+        Some(value) if value == token => Ok(State::Healthy),
+        Some(value) => Ok(State::Unhealthy(Other(format!("unexpected value '{}' read from key '{}'", value, key)))),
+        None => Ok(State::Unhealthy(Other(format!("key '{}' not found after being written", key)))),
+    }
+}
+
+async fn pub_sub(con: &mut impl ConnectionLike, configuration: &Configuration) -> Result<State, NetworkError> {
+    let timeout: Duration = configuration.timeout.into();
+    let deadline = Instant::now() + timeout;
+    let token = unique_token();
+    let channel = format!("{}{}", String::from(configuration.redis_key_prefix.clone()), token);
+    let timed_out = |leg: &str| Ok(State::Unhealthy(Other(format!("{} timed out after {:?}", leg, timeout))));
+
+    debug!("subscribing to channel {}", channel);
+
+    let client = client(configuration)?;
+
+    let subscription = tokio::time::timeout_at(deadline, async {
+        let mut pubsub = client.get_async_pubsub().await?;
+        pubsub.subscribe(&channel).await?;
+        Ok::<_, RedisError>(pubsub)
+    })
+    .await;
+
+    let mut pubsub = match subscription {
+        Err(_) => return timed_out("subscribe"),
+        Ok(Err(e)) => return unhealthy_on_error_response(e, "subscribe failed"),
+        Ok(Ok(pubsub)) => pubsub,
+    };
+
+    let published = tokio::time::timeout_at(
+        deadline,
+        redis::cmd("PUBLISH").arg(&channel).arg(&token).query_async::<i64>(con),
+    )
+    .await;
+
+    match published {
+        Err(_) => return timed_out("publish"),
+        Ok(Err(e)) => return unhealthy_on_error_response(e, "publish failed"),
+        Ok(Ok(0)) => return Ok(State::Unhealthy(Other(format!("delivery failed: no subscriber on channel '{}'", channel)))),
+        Ok(Ok(_)) => {}
+    }
+
+    let mut messages = pin!(pubsub.on_message());
+
+    match tokio::time::timeout_at(deadline, messages.next()).await {
+        Err(_) => timed_out("delivery"),
+        Ok(None) => Ok(State::Unhealthy(Other(String::from("delivery failed: subscription closed")))),
+        Ok(Some(message)) if message.get_payload_bytes() == token.as_bytes() => Ok(State::Healthy),
+        Ok(Some(message)) => Ok(State::Unhealthy(Other(format!(
+            "delivery failed: unexpected message '{}'",
+            String::from_utf8_lossy(message.get_payload_bytes()),
+        )))),
+    }
+}
+
+fn check_reply(expected: &RedisReply, value: &Value) -> State {
+    let text = reply_to_string(value);
+
+    let matches = match (expected, &text) {
+        (_, None) => false,
+        (RedisReply::String(expected), Some(text)) => expected == text,
+        (RedisReply::Integer(expected), Some(text)) => text.parse::<i64>().ok() == Some(*expected),
+        (RedisReply::Regex(expected), Some(text)) => expected.is_match(text),
+    };
+
+    match (matches, text) {
+        (true, _) => State::Healthy,
+        (false, Some(text)) => State::Unhealthy(Other(format!("unexpected response '{}'", text))),
+        (false, None) => State::Unhealthy(Other(format!("unexpected response {:?}", value))),
+    }
+}
+
+fn reply_to_string(value: &Value) -> Option<String> {
+    match value {
+        Value::Okay => Some(String::from("OK")),
+        Value::Int(number) => Some(number.to_string()),
+        Value::SimpleString(text) => Some(text.clone()),
+        Value::BulkString(bytes) => Some(String::from_utf8_lossy(bytes).into_owned()),
+        Value::VerbatimString { text, .. } => Some(text.clone()),
+        Value::Double(number) => Some(number.to_string()),
+        Value::Boolean(boolean) => Some(boolean.to_string()),
+        _ => None,
+    }
+}
+
+fn unhealthy_on_connection_error(error: RedisError) -> Result<State, NetworkError> {
+    if error.kind() == ErrorKind::RESP3NotSupported || error.code() == Some("NOPROTO") {
+        let detail = error.detail().unwrap_or_else(|| error.category());
+        return Ok(State::Unhealthy(Other(format!("incompatible protocol '{}'", detail))));
+    }
+
+    unhealthy_on_error_response(error, "connection rejected")
+}
+
+pub(super) fn unhealthy_on_error_response(error: RedisError, description: &str) -> Result<State, NetworkError> {
+    match error_response(&error) {
+        Some(response) => Ok(State::Unhealthy(Other(format!("{} '{}'", description, response)))),
+        None => Err(NetworkError {
+            message: format!("network error: {}", error),
+        }),
+    }
+}
+
+fn error_response(error: &RedisError) -> Option<String> {
+    error.code().map(|code| match error.detail() {
+        Some(detail) => format!("{} {}", code, detail),
+        None => code.to_string(),
+    })
+}
@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+use async_trait::async_trait;
+use log::debug;
+use redis::aio::ConnectionLike;
+use crate::configuration::Configuration;
+use crate::health_checker::Reason::{Other, Timeout};
+use crate::health_checker::redis::{connect, report, unhealthy_on_error_response};
+use crate::health_checker::{HealthCheck, NetworkError, State};
+
+#[cfg(test)]
+#[path = "./redis_cluster_test.rs"]
+mod test;
+
+const CLUSTER_SLOTS: &str = "16384";
+
+pub(crate) struct RedisCluster;
+
+#[async_trait]
+impl HealthCheck for RedisCluster {
+    async fn get_health(&self, configuration: &Configuration) -> Result<State, NetworkError> {
+        let timeout: std::time::Duration = configuration.timeout.into();
+
+        let mut con = match connect(configuration).await {
+            Ok(con) => con,
+            Err(result) => return report(result),
+        };
+
+        let check = tokio::time::timeout(timeout, check_cluster(&mut con, configuration)).await;
+
+        let result = match check {
+            Err(_) => Ok(State::Unhealthy(Timeout(timeout))),
+            Ok(result) => result,
+        };
+
+        report(result)
+    }
+}
+
+async fn check_cluster(con: &mut impl ConnectionLike, configuration: &Configuration) -> Result<State, NetworkError> {
+    let info = match redis::cmd("CLUSTER").arg("INFO").query_async::<String>(con).await {
+        Ok(info) => info,
+        Err(e) => return unhealthy_on_error_response(e, "cluster info rejected"),
+    };
+
+    let info: HashMap<&str, &str> = info.lines()
+        .filter_map(|line| line.trim().split_once(':'))
+        .collect();
+
+    debug!("cluster info {:?}", info);
+
+    let state = info.get("cluster_state").copied().unwrap_or_default();
+
+    if state != "ok" {
+        return Ok(State::Unhealthy(Other(format!("cluster state is '{}'", state))));
+    }
+
+    if configuration.redis_cluster_check_slots {
+        let assigned = info.get("cluster_slots_assigned").copied().unwrap_or_default();
+
+        if assigned != CLUSTER_SLOTS {
+            return Ok(State::Unhealthy(Other(format!("only '{}' of {} slots are assigned", assigned, CLUSTER_SLOTS))));
+        }
+    }
+
+    if configuration.redis_cluster_check_node {
+        let nodes = match redis::cmd("CLUSTER").arg("NODES").query_async::<String>(con).await {
+            Ok(nodes) => nodes,
+            Err(e) => return unhealthy_on_error_response(e, "cluster nodes rejected"),
+        };
+
+        let flags = nodes.lines()
+            .filter_map(|line| line.split_whitespace().nth(2))
+            .find(|flags| flags.split(',').any(|flag| flag == "myself"))
+            .unwrap_or_default();
+
+        debug!("node flags '{}'", flags);
+
+        if flags.split(',').any(|flag| flag == "fail" || flag == "fail?") {
+            return Ok(State::Unhealthy(Other(format!("node is flagged as failing with flags '{}'", flags))));
+        }
+    }
+
+    Ok(State::Healthy)
+}
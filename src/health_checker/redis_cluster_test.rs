@@ -0,0 +1,108 @@
+use crate::health_checker::Reason::Other;
+use crate::health_checker::State::Healthy;
+use crate::health_checker::State::Unhealthy;
+use assert2::{check, assert};
+use std::net::TcpListener;
+use std::time::Duration;
+use testcontainers_modules::redis::REDIS_PORT;
+use testcontainers_modules::testcontainers::core::ImageExt;
+use testcontainers_modules::testcontainers::runners::AsyncRunner;
+use testcontainers_modules::testcontainers::ContainerAsync;
+use crate::configuration::fixtures::{a_redis_cluster_configuration, a_redis_cluster_configuration_with_checks};
+use crate::health_checker::redis_cluster::RedisCluster;
+use crate::health_checker::HealthCheck;
+
+#[tokio::test]
+async fn a_cluster_in_ok_state_should_be_reported_as_healthy() {
+    let redis_container = start_cluster_node(&[]).await;
+    let port = redis_container.get_host_port_ipv4(REDIS_PORT).await.unwrap();
+    assign_slots(port, 0, 16383).await;
+    let configuration = a_redis_cluster_configuration_with_checks(port, true, true);
+
+    let result = RedisCluster.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[tokio::test]
+async fn a_cluster_in_fail_state_should_be_reported_as_unhealthy() {
+    let redis_container = start_cluster_node(&[]).await;
+    let port = redis_container.get_host_port_ipv4(REDIS_PORT).await.unwrap();
+    let configuration = a_redis_cluster_configuration(port);
+
+    let result = RedisCluster.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other("cluster state is 'fail'".to_string())));
+}
+
+#[tokio::test]
+async fn a_cluster_with_unassigned_slots_should_be_reported_as_unhealthy() {
+    let redis_container = start_cluster_node(&["--cluster-require-full-coverage", "no"]).await;
+    let port = redis_container.get_host_port_ipv4(REDIS_PORT).await.unwrap();
+    assign_slots(port, 0, 8191).await;
+    let configuration = a_redis_cluster_configuration_with_checks(port, true, false);
+
+    let result = RedisCluster.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other("only '8192' of 16384 slots are assigned".to_string())));
+}
+
+#[tokio::test]
+async fn a_redis_without_cluster_support_should_be_reported_as_unhealthy() {
+    let redis_container = testcontainers_modules::redis::Redis::default()
+        .with_tag("7")
+        .start()
+        .await
+        .unwrap();
+
+    let port = redis_container.get_host_port_ipv4(REDIS_PORT).await.unwrap();
+    let configuration = a_redis_cluster_configuration(port);
+
+    let result = RedisCluster.get_health(&configuration).await;
+
+    assert!(let Ok(Unhealthy(Other(reason))) = result);
+    check!(reason.starts_with("cluster info rejected"));
+}
+
+#[tokio::test]
+async fn unreachable_cluster_node_should_be_reported_as_error() {
+    let unused_port = TcpListener::bind("localhost:0").unwrap()
+        .local_addr().unwrap()
+        .port();
+    let configuration = a_redis_cluster_configuration(unused_port);
+
+    let result = RedisCluster.get_health(&configuration).await;
+
+    assert!(let Err(error) = result);
+    check!(error.message.starts_with("network error"));
+}
+
+async fn start_cluster_node(args: &[&str]) -> ContainerAsync<testcontainers_modules::redis::Redis> {
+    let mut cmd = vec!["redis-server", "--cluster-enabled", "yes"];
+    cmd.extend_from_slice(args);
+
+    testcontainers_modules::redis::Redis::default()
+        .with_tag("7")
+        .with_cmd(cmd)
+        .start()
+        .await
+        .unwrap()
+}
+
+async fn assign_slots(port: u16, first: u16, last: u16) {
+    let client = redis::Client::open(format!("redis://localhost:{}", port)).unwrap();
+    let mut con = client.get_multiplexed_async_connection().await.unwrap();
+
+    redis::cmd("CLUSTER")
+        .arg("ADDSLOTSRANGE")
+        .arg(first)
+        .arg(last)
+        .query_async::<()>(&mut con)
+        .await
+        .unwrap();
+
+    tokio::time::sleep(Duration::from_secs(1)).await;
+}
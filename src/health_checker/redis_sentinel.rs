@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+use async_trait::async_trait;
+use log::debug;
+use redis::aio::ConnectionLike;
+use crate::configuration::{Configuration, RedisMasterName};
+use crate::health_checker::Reason::{Other, Timeout};
+use crate::health_checker::redis::{connect, report, unhealthy_on_error_response};
+use crate::health_checker::{HealthCheck, NetworkError, State};
+
+#[cfg(test)]
+#[path = "./redis_sentinel_test.rs"]
+mod test;
+
+pub(crate) struct RedisSentinel;
+
+#[async_trait]
+impl HealthCheck for RedisSentinel {
+    async fn get_health(&self, configuration: &Configuration) -> Result<State, NetworkError> {
+        let timeout: std::time::Duration = configuration.timeout.into();
+
+        let mut con = match connect(configuration).await {
+            Ok(con) => con,
+            Err(result) => return report(result),
+        };
+
+        let check = tokio::time::timeout(
+            timeout,
+            check_master(&mut con, &configuration.redis_master_name),
+        )
+        .await;
+
+        let result = match check {
+            Err(_) => Ok(State::Unhealthy(Timeout(timeout))),
+            Ok(result) => result,
+        };
+
+        report(result)
+    }
+}
+
+async fn check_master(con: &mut impl ConnectionLike, master_name: &RedisMasterName) -> Result<State, NetworkError> {
+    let master_name = String::from(master_name.clone());
+
+    match redis::cmd("PING").query_async::<String>(con).await {
+        Ok(pong) if pong == "PONG" => {}
+        Ok(unexpected) => return Ok(State::Unhealthy(Other(format!("unexpected response '{}'", unexpected)))),
+        Err(e) => return unhealthy_on_error_response(e, "error response"),
+    }
+
+    let master = redis::cmd("SENTINEL")
+        .arg("master")
+        .arg(&master_name)
+        .query_async::<HashMap<String, String>>(con)
+        .await;
+
+    let master = match master {
+        Ok(master) => master,
+        Err(e) => return unhealthy_on_error_response(e, "master lookup rejected"),
+    };
+
+    let flags = master.get("flags").map(String::as_str).unwrap_or_default();
+
+    debug!("master '{}' has flags '{}'", master_name, flags);
+
+    if flags.split(',').any(|flag| flag == "s_down" || flag == "o_down") {
+        return Ok(State::Unhealthy(Other(format!("master '{}' is down with flags '{}'", master_name, flags))));
+    }
+
+    let quorum = redis::cmd("SENTINEL")
+        .arg("ckquorum")
+        .arg(&master_name)
+        .query_async::<String>(con)
+        .await;
+
+    match quorum {
+        Ok(_) => Ok(State::Healthy),
+        Err(e) => unhealthy_on_error_response(e, "quorum cannot be reached"),
+    }
+}
@@ -0,0 +1,63 @@
+use std::borrow::Cow;
+use testcontainers_modules::testcontainers::core::wait::LogWaitStrategy;
+use testcontainers_modules::testcontainers::core::ContainerPort::Tcp;
+use testcontainers_modules::testcontainers::core::{ContainerPort, WaitFor};
+use testcontainers_modules::testcontainers::Image;
+
+pub const SENTINEL_PORT: u16 = 26379;
+
+pub const MASTER_NAME: &str = "mymaster";
+
+const MASTER_PORT: u16 = 6379;
+
+pub struct SentinelContainer {
+    script: String,
+}
+
+impl SentinelContainer {
+    pub fn with_master() -> Self {
+        SentinelContainer::monitoring(MASTER_PORT)
+    }
+
+    pub fn with_unreachable_master() -> Self {
+        SentinelContainer::monitoring(MASTER_PORT + 1)
+    }
+
+    fn monitoring(master_port: u16) -> Self {
+        let configuration = [
+            format!("port {}", SENTINEL_PORT),
+            format!("sentinel monitor {} 127.0.0.1 {} 1", MASTER_NAME, master_port),
+            format!("sentinel down-after-milliseconds {} 100", MASTER_NAME),
+        ];
+
+        SentinelContainer {
+            script: format!(
+                "redis-server --port {} --daemonize yes && printf '{}\\n' > /tmp/sentinel.conf && exec redis-sentinel /tmp/sentinel.conf",
+                MASTER_PORT,
+                configuration.join("\\n"),
+            ),
+        }
+    }
+}
+
+impl Image for SentinelContainer {
+    fn name(&self) -> &str {
+        "redis"
+    }
+
+    fn tag(&self) -> &str {
+        "7"
+    }
+
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::log(LogWaitStrategy::stdout("+monitor master"))]
+    }
+
+    fn cmd(&self) -> impl IntoIterator<Item = impl Into<Cow<'_, str>>> {
+        ["sh", "-c", self.script.as_str()]
+    }
+
+    fn expose_ports(&self) -> &[ContainerPort] {
+        &[Tcp(SENTINEL_PORT)]
+    }
+}
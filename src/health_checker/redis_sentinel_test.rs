@@ -0,0 +1,74 @@
+use crate::health_checker::Reason::Other;
+use crate::health_checker::State::Healthy;
+use crate::health_checker::State::Unhealthy;
+use assert2::{check, assert};
+use std::net::TcpListener;
+use std::time::Duration;
+use testcontainers_modules::testcontainers::runners::AsyncRunner;
+use crate::configuration::fixtures::{a_redis_sentinel_configuration, a_redis_sentinel_configuration_with_master_name};
+use crate::health_checker::redis_sentinel::RedisSentinel;
+use crate::health_checker::redis_sentinel_container::{SentinelContainer, MASTER_NAME, SENTINEL_PORT};
+use crate::health_checker::HealthCheck;
+
+#[tokio::test]
+async fn a_sentinel_monitoring_a_healthy_master_should_be_reported() {
+    let sentinel_container = SentinelContainer::with_master()
+        .start()
+        .await
+        .unwrap();
+
+    let port = sentinel_container.get_host_port_ipv4(SENTINEL_PORT).await.unwrap();
+    let configuration = a_redis_sentinel_configuration(port);
+
+    let result = RedisSentinel.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[tokio::test]
+async fn a_sentinel_monitoring_a_master_that_is_down_should_be_reported_as_unhealthy() {
+    let sentinel_container = SentinelContainer::with_unreachable_master()
+        .start()
+        .await
+        .unwrap();
+
+    tokio::time::sleep(Duration::from_secs(1)).await;
+
+    let port = sentinel_container.get_host_port_ipv4(SENTINEL_PORT).await.unwrap();
+    let configuration = a_redis_sentinel_configuration(port);
+
+    let result = RedisSentinel.get_health(&configuration).await;
+
+    assert!(let Ok(Unhealthy(Other(reason))) = result);
+    check!(reason.starts_with(&format!("master '{}' is down", MASTER_NAME)));
+}
+
+#[tokio::test]
+async fn a_sentinel_not_monitoring_the_master_should_be_reported_as_unhealthy() {
+    let sentinel_container = SentinelContainer::with_master()
+        .start()
+        .await
+        .unwrap();
+
+    let port = sentinel_container.get_host_port_ipv4(SENTINEL_PORT).await.unwrap();
+    let configuration = a_redis_sentinel_configuration_with_master_name(port, "unknown");
+
+    let result = RedisSentinel.get_health(&configuration).await;
+
+    assert!(let Ok(Unhealthy(Other(reason))) = result);
+    check!(reason.starts_with("master lookup rejected"));
+}
+
+#[tokio::test]
+async fn unreachable_sentinel_should_be_reported_as_error() {
+    let unused_port = TcpListener::bind("localhost:0").unwrap()
+        .local_addr().unwrap()
+        .port();
+    let configuration = a_redis_sentinel_configuration(unused_port);
+
+    let result = RedisSentinel.get_health(&configuration).await;
+
+    assert!(let Err(error) = result);
+    check!(error.message.starts_with("network error"));
+}
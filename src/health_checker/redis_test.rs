@@ -1,13 +1,17 @@
-use crate::health_checker::Reason::Timeout;
+use crate::health_checker::Reason::{Other, Timeout};
 use crate::health_checker::State::Healthy;
 use crate::health_checker::State::Unhealthy;
 use assert2::{check, assert};
 use std::net::TcpListener;
 use std::time::Duration;
-use testcontainers_modules::testcontainers::core::ImageExt;
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use testcontainers_modules::testcontainers::core::{ImageExt, Mount};
 use testcontainers_modules::testcontainers::runners::AsyncRunner;
+use rstest::rstest;
 use testcontainers_modules::redis::REDIS_PORT;
-use crate::configuration::fixtures::{a_redis_configuration, a_redis_configuration_with_timeout};
+use crate::configuration::fixtures::{a_redis_configuration, a_redis_configuration_with_check, a_redis_configuration_with_command, a_redis_configuration_with_database, a_redis_configuration_with_protocol, a_redis_configuration_with_socket_path, a_redis_configuration_with_timeout};
+use crate::configuration::{RedisCheck, RedisProtocol};
 use crate::health_checker::redis::Redis;
 use crate::health_checker::HealthCheck;
 use crate::health_checker::toxiproxy::{ToxiProxyContainer, PROXY_PORT};
@@ -71,3 +75,265 @@ async fn redis_responding_slowly_should_be_reported_as_unhealthy() {
     assert!(let Ok(state) = result);
     check!(state == Unhealthy(Timeout(Duration::from_millis(1))));
 }
+
+#[rstest]
+#[case::string("ECHO hello", "hello")]
+#[case::integer("DBSIZE", "integer:0")]
+#[case::regex("INFO replication", "regex:role:master")]
+#[tokio::test]
+async fn a_custom_command_with_the_expected_reply_should_be_reported_as_healthy(#[case] command: &str, #[case] reply: &str) {
+    let redis_container = testcontainers_modules::redis::Redis::default()
+        .start()
+        .await
+        .unwrap();
+
+    let port = redis_container.get_host_port_ipv4(REDIS_PORT).await.unwrap();
+    let configuration = a_redis_configuration_with_command(port, command, reply);
+
+    let result = Redis.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[tokio::test]
+async fn a_custom_command_with_an_unexpected_reply_should_be_reported_as_unhealthy() {
+    let redis_container = testcontainers_modules::redis::Redis::default()
+        .start()
+        .await
+        .unwrap();
+
+    let port = redis_container.get_host_port_ipv4(REDIS_PORT).await.unwrap();
+    let configuration = a_redis_configuration_with_command(port, "ECHO hello", "world");
+
+    let result = Redis.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other("unexpected response 'hello'".to_string())));
+}
+
+#[tokio::test]
+async fn an_error_response_should_be_reported_as_unhealthy() {
+    let redis_container = testcontainers_modules::redis::Redis::default()
+        .start()
+        .await
+        .unwrap();
+
+    let port = redis_container.get_host_port_ipv4(REDIS_PORT).await.unwrap();
+    let configuration = a_redis_configuration_with_command(port, "NOSUCHCOMMAND", "OK");
+
+    let result = Redis.get_health(&configuration).await;
+
+    assert!(let Ok(Unhealthy(Other(reason))) = result);
+    check!(reason.starts_with("error response 'ERR unknown command"));
+}
+
+#[tokio::test]
+async fn a_successful_round_trip_should_be_reported_as_healthy() {
+    let redis_container = testcontainers_modules::redis::Redis::default()
+        .start()
+        .await
+        .unwrap();
+
+    let port = redis_container.get_host_port_ipv4(REDIS_PORT).await.unwrap();
+    let configuration = a_redis_configuration_with_check(port, RedisCheck::RoundTrip);
+
+    let result = Redis.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+    check!(count_keys(port).await == 0);
+}
+
+#[tokio::test]
+async fn a_write_rejected_for_lack_of_memory_should_be_reported_as_unhealthy() {
+    let redis_container = testcontainers_modules::redis::Redis::default()
+        .with_cmd(["redis-server", "--maxmemory", "1", "--maxmemory-policy", "noeviction"])
+        .start()
+        .await
+        .unwrap();
+
+    let port = redis_container.get_host_port_ipv4(REDIS_PORT).await.unwrap();
+    let configuration = a_redis_configuration_with_check(port, RedisCheck::RoundTrip);
+
+    let result = Redis.get_health(&configuration).await;
+
+    assert!(let Ok(Unhealthy(Other(reason))) = result);
+    check!(reason.starts_with("write rejected 'OOM"));
+}
+
+#[tokio::test]
+async fn a_write_rejected_by_a_read_only_replica_should_be_reported_as_unhealthy() {
+    let redis_container = testcontainers_modules::redis::Redis::default()
+        .with_cmd(["redis-server", "--replicaof", "localhost", "6380"])
+        .start()
+        .await
+        .unwrap();
+
+    let port = redis_container.get_host_port_ipv4(REDIS_PORT).await.unwrap();
+    let configuration = a_redis_configuration_with_check(port, RedisCheck::RoundTrip);
+
+    let result = Redis.get_health(&configuration).await;
+
+    assert!(let Ok(Unhealthy(Other(reason))) = result);
+    check!(reason.starts_with("write rejected 'READONLY"));
+}
+
+#[tokio::test]
+async fn the_configured_database_should_be_selected() {
+    let redis_container = testcontainers_modules::redis::Redis::default()
+        .start()
+        .await
+        .unwrap();
+
+    let port = redis_container.get_host_port_ipv4(REDIS_PORT).await.unwrap();
+    let client = redis::Client::open(format!("redis://localhost:{}/1", port)).unwrap();
+    let mut con = client.get_multiplexed_async_connection().await.unwrap();
+    redis::cmd("SET").arg("marker").arg("1").query_async::<()>(&mut con).await.unwrap();
+    let configuration = a_redis_configuration_with_database(port, 1, "EXISTS marker", "integer:1");
+
+    let result = Redis.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[tokio::test]
+async fn a_database_out_of_range_should_be_reported_as_unhealthy() {
+    let redis_container = testcontainers_modules::redis::Redis::default()
+        .start()
+        .await
+        .unwrap();
+
+    let port = redis_container.get_host_port_ipv4(REDIS_PORT).await.unwrap();
+    let configuration = a_redis_configuration_with_database(port, 99, "PING", "PONG");
+
+    let result = Redis.get_health(&configuration).await;
+
+    assert!(let Ok(Unhealthy(Other(reason))) = result);
+    check!(reason.starts_with("connection rejected"));
+}
+
+#[tokio::test]
+async fn a_redis_negotiating_resp3_should_be_reported_as_healthy() {
+    let redis_container = testcontainers_modules::redis::Redis::default()
+        .with_tag("7")
+        .start()
+        .await
+        .unwrap();
+
+    let port = redis_container.get_host_port_ipv4(REDIS_PORT).await.unwrap();
+    let configuration = a_redis_configuration_with_protocol(port, RedisProtocol::Resp3);
+
+    let result = Redis.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[tokio::test]
+async fn a_redis_not_supporting_resp3_should_be_reported_as_unhealthy() {
+    let redis_container = testcontainers_modules::redis::Redis::default()
+        .with_tag("5.0")
+        .start()
+        .await
+        .unwrap();
+
+    let port = redis_container.get_host_port_ipv4(REDIS_PORT).await.unwrap();
+    let configuration = a_redis_configuration_with_protocol(port, RedisProtocol::Resp3);
+
+    let result = Redis.get_health(&configuration).await;
+
+    assert!(let Ok(Unhealthy(Other(reason))) = result);
+    check!(reason.starts_with("incompatible protocol"));
+}
+
+#[tokio::test]
+async fn a_healthy_redis_listening_on_a_unix_socket_should_be_reported() {
+    let socket_dir = std::env::temp_dir().join(format!("dockteur-redis-{}", std::process::id()));
+    fs::create_dir_all(&socket_dir).unwrap();
+    fs::set_permissions(&socket_dir, fs::Permissions::from_mode(0o777)).unwrap();
+
+    let _redis_container = testcontainers_modules::redis::Redis::default()
+        .with_mount(Mount::bind_mount(socket_dir.to_str().unwrap(), "/run/redis"))
+        .with_cmd(["redis-server", "--port", "0", "--unixsocket", "/run/redis/redis.sock", "--unixsocketperm", "777"])
+        .start()
+        .await
+        .unwrap();
+
+    let socket_path = socket_dir.join("redis.sock");
+    let configuration = a_redis_configuration_with_socket_path(socket_path.to_str().unwrap());
+
+    let result = Redis.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[tokio::test]
+async fn a_missing_unix_socket_should_be_reported_as_error() {
+    let socket_path = std::env::temp_dir().join(format!("dockteur-missing-{}.sock", std::process::id()));
+    let configuration = a_redis_configuration_with_socket_path(socket_path.to_str().unwrap());
+
+    let result = Redis.get_health(&configuration).await;
+
+    assert!(let Err(error) = result);
+    check!(error.message.starts_with("network error"));
+}
+
+#[tokio::test]
+async fn a_successful_pub_sub_round_trip_should_be_reported_as_healthy() {
+    let redis_container = testcontainers_modules::redis::Redis::default()
+        .start()
+        .await
+        .unwrap();
+
+    let port = redis_container.get_host_port_ipv4(REDIS_PORT).await.unwrap();
+    let configuration = a_redis_configuration_with_check(port, RedisCheck::PubSub);
+
+    let result = Redis.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[tokio::test]
+async fn a_rejected_publish_should_be_reported_as_unhealthy() {
+    let redis_container = testcontainers_modules::redis::Redis::default()
+        .with_cmd(["redis-server", "--rename-command", "PUBLISH", "DISABLED_PUBLISH"])
+        .start()
+        .await
+        .unwrap();
+
+    let port = redis_container.get_host_port_ipv4(REDIS_PORT).await.unwrap();
+    let configuration = a_redis_configuration_with_check(port, RedisCheck::PubSub);
+
+    let result = Redis.get_health(&configuration).await;
+
+    assert!(let Ok(Unhealthy(Other(reason))) = result);
+    check!(reason.starts_with("publish failed 'ERR unknown command"));
+}
+
+#[tokio::test]
+async fn a_rejected_subscription_should_be_reported_as_unhealthy() {
+    let redis_container = testcontainers_modules::redis::Redis::default()
+        .with_cmd(["redis-server", "--rename-command", "SUBSCRIBE", "DISABLED_SUBSCRIBE"])
+        .start()
+        .await
+        .unwrap();
+
+    let port = redis_container.get_host_port_ipv4(REDIS_PORT).await.unwrap();
+    let configuration = a_redis_configuration_with_check(port, RedisCheck::PubSub);
+
+    let result = Redis.get_health(&configuration).await;
+
+    assert!(let Ok(Unhealthy(Other(reason))) = result);
+    check!(reason.starts_with("subscribe failed"));
+}
+
+async fn count_keys(port: u16) -> i64 {
+    let client = redis::Client::open(format!("redis://localhost:{}", port)).unwrap();
+    let mut con = client.get_multiplexed_async_connection().await.unwrap();
+
+    redis::cmd("DBSIZE").query_async(&mut con).await.unwrap()
+}
@@ -0,0 +1,117 @@
+use async_trait::async_trait;
+use log::debug;
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::net::TcpStream;
+use crate::configuration::Configuration;
+use crate::health_checker::Reason::Other;
+use crate::health_checker::wire::{network_error, run_with_timeout, LineConnection};
+use crate::health_checker::{tls, HealthCheck, NetworkError, State};
+
+#[cfg(test)]
+#[path = "./smtp_test.rs"]
+mod test;
+
+const SERVICE_READY: u16 = 220;
+const CLOSING: u16 = 221;
+const COMPLETED: u16 = 250;
+
+pub(crate) struct Smtp;
+
+#[async_trait]
+impl HealthCheck for Smtp {
+    async fn get_health(&self, configuration: &Configuration) -> Result<State, NetworkError> {
+        run_with_timeout(configuration, check(configuration)).await
+    }
+}
+
+/// Reads a reply, made of lines sharing the same code and all but the last one followed by a dash, and returns the
+/// text of its lines when it has the expected code.
+async fn expect<S: AsyncRead + AsyncWrite + Unpin>(connection: &mut LineConnection<S>, expected: u16) -> Result<Vec<String>, Result<State, NetworkError>> {
+    let mut code = 0;
+    let mut lines = Vec::new();
+
+    loop {
+        let line = connection.read_line().await?;
+
+        let line_code = match line.get(..3).and_then(|digits| digits.parse::<u16>().ok()) {
+            Some(line_code) if lines.is_empty() || line_code == code => line_code,
+            _ => return Err(Ok(State::Unhealthy(Other(format!("unexpected reply '{}'", line))))),
+        };
+
+        code = line_code;
+        lines.push(line[3..].trim_start_matches(['-', ' ']).to_string());
+
+        if !line[3..].starts_with('-') {
+            break;
+        }
+    }
+
+    match code {
+        code if code == expected => Ok(lines),
+        code if code >= 400 => Err(Ok(State::Unhealthy(Other(format!("error response '{} {}'", code, lines.join(" ")))))),
+        code => Err(Ok(State::Unhealthy(Other(format!("unexpected reply '{} {}'", code, lines.join(" ")))))),
+    }
+}
+
+async fn hello<S: AsyncRead + AsyncWrite + Unpin>(connection: &mut LineConnection<S>) -> Result<Vec<String>, Result<State, NetworkError>> {
+    connection.send("EHLO dockteur").await?;
+
+    let mut lines = expect(connection, COMPLETED).await?;
+
+    if !lines.is_empty() {
+        debug!("server {}", lines.remove(0));
+    }
+
+    Ok(lines)
+}
+
+async fn quit<S: AsyncRead + AsyncWrite + Unpin>(connection: &mut LineConnection<S>) -> Result<State, Result<State, NetworkError>> {
+    connection.send("QUIT").await?;
+
+    if let Err(e) = expect(connection, CLOSING).await {
+        debug!("quit failed: {:?}", e);
+    }
+
+    Ok(State::Healthy)
+}
+
+async fn check(configuration: &Configuration) -> Result<State, Result<State, NetworkError>> {
+    let port: u16 = configuration.port.into();
+
+    let stream = TcpStream::connect(("localhost", port)).await
+        .map_err(network_error)?;
+
+    if configuration.tls {
+        let stream = tls::connect(stream, configuration, &[]).await
+            .map_err(network_error)?;
+
+        let mut connection = LineConnection::new(stream);
+        expect(&mut connection, SERVICE_READY).await?;
+        hello(&mut connection).await?;
+        return quit(&mut connection).await;
+    }
+
+    let mut connection = LineConnection::new(stream);
+    expect(&mut connection, SERVICE_READY).await?;
+    let extensions = hello(&mut connection).await?;
+
+    if !configuration.smtp_starttls {
+        return quit(&mut connection).await;
+    }
+
+    if !extensions.iter().any(|extension| extension.eq_ignore_ascii_case("STARTTLS")) {
+        return Ok(State::Unhealthy(Other(String::from("STARTTLS is not supported by the server"))));
+    }
+
+    connection.send("STARTTLS").await?;
+    expect(&mut connection, SERVICE_READY).await?;
+
+    debug!("upgrading connection to TLS");
+
+    let stream = tls::connect(connection.into_inner(), configuration, &[]).await
+        .map_err(network_error)?;
+
+    let mut connection = LineConnection::new(stream);
+    hello(&mut connection).await?;
+    quit(&mut connection).await
+}
@@ -0,0 +1,125 @@
+use crate::configuration::{Configuration, TlsServerName};
+use crate::health_checker::Reason::Other;
+use crate::health_checker::State::Healthy;
+use crate::health_checker::State::Unhealthy;
+use assert2::{check, assert};
+use rstest::rstest;
+use std::net::TcpListener;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use testcontainers_modules::testcontainers::runners::AsyncRunner;
+use crate::configuration::fixtures::an_smtp_configuration;
+use crate::health_checker::mailpit_container::{MailpitContainer, SMTP_PORT};
+use crate::health_checker::smtp::Smtp;
+use crate::health_checker::HealthCheck;
+
+#[rstest]
+#[case::plain(false)]
+#[case::starttls(true)]
+#[tokio::test]
+async fn a_healthy_server_should_be_reported(#[case] starttls: bool) {
+    let mailpit_container = MailpitContainer
+        .start()
+        .await
+        .unwrap();
+
+    let port = mailpit_container.get_host_port_ipv4(SMTP_PORT).await.unwrap();
+    let configuration = Configuration {
+        tls_skip_verify: true,
+        ..an_smtp_configuration(port, starttls)
+    };
+
+    let result = Smtp.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[tokio::test]
+async fn a_server_greeting_and_accepting_hello_should_be_reported_as_healthy() {
+    let port = a_server_replying(&[
+        "220 mail.example.com ESMTP",
+        "250-mail.example.com\r\n250-PIPELINING\r\n250 8BITMIME",
+        "221 Bye",
+    ]).await;
+    let configuration = an_smtp_configuration(port, false);
+
+    let result = Smtp.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Healthy);
+}
+
+#[rstest]
+#[case::busy(&["421 too busy"], "error response '421 too busy'")]
+#[case::rejected_greeting(&["554 no SMTP service here"], "error response '554 no SMTP service here'")]
+#[case::rejected_hello(&["220 ready", "550-access denied\r\n550 go away"], "error response '550 access denied go away'")]
+#[case::not_smtp(&["HTTP/1.1 400 Bad Request"], "unexpected reply 'HTTP/1.1 400 Bad Request'")]
+#[case::unexpected_code(&["220 ready", "354 start mail input"], "unexpected reply '354 start mail input'")]
+#[case::starttls_unsupported(&["220 ready", "250-mail.example.com\r\n250 PIPELINING"], "STARTTLS is not supported by the server")]
+#[tokio::test]
+async fn a_failing_server_should_be_reported_as_unhealthy(#[case] replies: &[&str], #[case] reason: &str) {
+    let port = a_server_replying(replies).await;
+    let configuration = an_smtp_configuration(port, true);
+
+    let result = Smtp.get_health(&configuration).await;
+
+    assert!(let Ok(state) = result);
+    check!(state == Unhealthy(Other(reason.to_string())));
+}
+
+#[tokio::test]
+async fn a_failed_tls_upgrade_should_be_reported_as_error() {
+    let port = a_server_replying(&[
+        "220 ready",
+        "250-mail.example.com\r\n250 STARTTLS",
+        "220 go ahead",
+        "not a TLS handshake",
+    ]).await;
+    let configuration = Configuration {
+        tls_server_name: TlsServerName::from("mail.example.com"),
+        ..an_smtp_configuration(port, true)
+    };
+
+    let result = Smtp.get_health(&configuration).await;
+
+    assert!(let Err(error) = result);
+    check!(error.message.starts_with("network error"));
+}
+
+#[tokio::test]
+async fn unreachable_server_should_be_reported_as_error() {
+    let unused_port = TcpListener::bind("localhost:0").unwrap()
+        .local_addr().unwrap()
+        .port();
+    let configuration = an_smtp_configuration(unused_port, false);
+
+    let result = Smtp.get_health(&configuration).await;
+
+    assert!(let Err(error) = result);
+    check!(error.message.starts_with("network error"));
+}
+
+/// Starts a server sending the first reply as greeting, then each following reply once a command is received.
+async fn a_server_replying(replies: &[&str]) -> u16 {
+    let listener = tokio::net::TcpListener::bind("localhost:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let replies: Vec<String> = replies.iter().map(|reply| format!("{}\r\n", reply)).collect();
+
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut stream = BufReader::new(stream);
+        let mut command = String::new();
+
+        for (index, reply) in replies.iter().enumerate() {
+            if index > 0 && stream.read_line(&mut command).await.unwrap_or(0) == 0 {
+                return;
+            }
+
+            stream.get_mut().write_all(reply.as_bytes()).await.unwrap();
+        }
+
+        while stream.read_line(&mut command).await.unwrap_or(0) > 0 {}
+    });
+
+    port
+}
@@ -0,0 +1,81 @@
+use crate::configuration::SqlExpectation;
+use crate::health_checker::Reason::Other;
+use crate::health_checker::State;
+
+#[cfg(test)]
+#[path = "./sql_test.rs"]
+mod test;
+
+const NULL: &str = "NULL";
+
+pub(super) fn check_value(expectation: Option<&SqlExpectation>, value: Option<Option<String>>) -> State {
+    let value = match value {
+        None => return State::Unhealthy(Other(String::from("query returned no rows"))),
+        Some(value) => value,
+    };
+
+    let expectation = match expectation {
+        None => return State::Healthy,
+        Some(expectation) => expectation,
+    };
+
+    let number = value.as_deref().and_then(|value| value.trim().parse::<f64>().ok());
+
+    let matches = match expectation {
+        SqlExpectation::Equal(expected) => equals(value.as_deref(), expected),
+        SqlExpectation::NotEqual(expected) => !equals(value.as_deref(), expected),
+        SqlExpectation::LessThan(expected) => number.is_some_and(|number| number < *expected),
+        SqlExpectation::LessOrEqual(expected) => number.is_some_and(|number| number <= *expected),
+        SqlExpectation::GreaterThan(expected) => number.is_some_and(|number| number > *expected),
+        SqlExpectation::GreaterOrEqual(expected) => number.is_some_and(|number| number >= *expected),
+    };
+
+    if matches {
+        State::Healthy
+    } else {
+        State::Unhealthy(Other(format!(
+            "query returned '{}', expected '{}'",
+            value.as_deref().unwrap_or(NULL),
+            describe(expectation),
+        )))
+    }
+}
+
+fn equals(value: Option<&str>, expected: &str) -> bool {
+    let value = match value {
+        None => return expected.eq_ignore_ascii_case(NULL),
+        Some(value) => value,
+    };
+
+    if value == expected {
+        return true;
+    }
+
+    if let (Some(value), Some(expected)) = (boolean(value), boolean(expected)) {
+        return value == expected;
+    }
+
+    match (value.trim().parse::<f64>(), expected.parse::<f64>()) {
+        (Ok(value), Ok(expected)) => value == expected,
+        _ => false,
+    }
+}
+
+fn boolean(value: &str) -> Option<bool> {
+    match value.to_lowercase().as_str() {
+        "t" | "true" => Some(true),
+        "f" | "false" => Some(false),
+        _ => None,
+    }
+}
+
+fn describe(expectation: &SqlExpectation) -> String {
+    match expectation {
+        SqlExpectation::Equal(expected) => format!("= {}", expected),
+        SqlExpectation::NotEqual(expected) => format!("!= {}", expected),
+        SqlExpectation::LessThan(expected) => format!("< {}", expected),
+        SqlExpectation::LessOrEqual(expected) => format!("<= {}", expected),
+        SqlExpectation::GreaterThan(expected) => format!("> {}", expected),
+        SqlExpectation::GreaterOrEqual(expected) => format!(">= {}", expected),
+    }
+}
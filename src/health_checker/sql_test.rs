@@ -0,0 +1,71 @@
+use crate::configuration::SqlExpectation;
+use crate::health_checker::Reason::Other;
+use crate::health_checker::State::{Healthy, Unhealthy};
+use crate::health_checker::sql::check_value;
+use assert2::check;
+use rstest::rstest;
+
+fn a_row(value: &str) -> Option<Option<String>> {
+    Some(Some(value.to_string()))
+}
+
+#[test]
+fn any_value_should_be_healthy_without_expectation() {
+    let result = check_value(None, a_row("whatever"));
+
+    check!(result == Healthy);
+}
+
+#[test]
+fn no_rows_should_be_unhealthy() {
+    let result = check_value(None, None);
+
+    check!(result == Unhealthy(Other(String::from("query returned no rows"))));
+}
+
+#[rstest]
+#[case::same_text(SqlExpectation::Equal(String::from("replica")), "replica")]
+#[case::postgres_boolean(SqlExpectation::Equal(String::from("false")), "f")]
+#[case::boolean_case(SqlExpectation::Equal(String::from("TRUE")), "t")]
+#[case::number(SqlExpectation::Equal(String::from("1")), "1.0")]
+#[case::not_equal(SqlExpectation::NotEqual(String::from("true")), "f")]
+#[case::less_than(SqlExpectation::LessThan(10.0), "2.5")]
+#[case::less_or_equal(SqlExpectation::LessOrEqual(10.0), "10")]
+#[case::greater_than(SqlExpectation::GreaterThan(0.0), "3")]
+#[case::greater_or_equal(SqlExpectation::GreaterOrEqual(3.0), "3")]
+fn matching_value_should_be_healthy(#[case] expectation: SqlExpectation, #[case] value: &str) {
+    let result = check_value(Some(&expectation), a_row(value));
+
+    check!(result == Healthy);
+}
+
+#[rstest]
+#[case::different_text(SqlExpectation::Equal(String::from("primary")), "replica", "query returned 'replica', expected '= primary'")]
+#[case::postgres_boolean(SqlExpectation::Equal(String::from("false")), "t", "query returned 't', expected '= false'")]
+#[case::not_equal(SqlExpectation::NotEqual(String::from("0")), "0", "query returned '0', expected '!= 0'")]
+#[case::less_than(SqlExpectation::LessThan(10.0), "12.5", "query returned '12.5', expected '< 10'")]
+#[case::greater_or_equal(SqlExpectation::GreaterOrEqual(3.0), "2", "query returned '2', expected '>= 3'")]
+#[case::not_a_number(SqlExpectation::GreaterThan(0.0), "abc", "query returned 'abc', expected '> 0'")]
+fn mismatching_value_should_be_unhealthy(#[case] expectation: SqlExpectation, #[case] value: &str, #[case] reason: &str) {
+    let result = check_value(Some(&expectation), a_row(value));
+
+    check!(result == Unhealthy(Other(reason.to_string())));
+}
+
+#[test]
+fn null_should_be_compared_as_null() {
+    let expectation = SqlExpectation::Equal(String::from("null"));
+
+    let result = check_value(Some(&expectation), Some(None));
+
+    check!(result == Healthy);
+}
+
+#[test]
+fn null_should_not_satisfy_a_numeric_comparison() {
+    let expectation = SqlExpectation::LessThan(10.0);
+
+    let result = check_value(Some(&expectation), Some(None));
+
+    check!(result == Unhealthy(Other(String::from("query returned 'NULL', expected '< 10'"))));
+}
@@ -0,0 +1,63 @@
+use std::io;
+use std::sync::Arc;
+use log::debug;
+use tokio::net::TcpStream;
+use tokio_rustls::client::TlsStream;
+use tokio_rustls::rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use tokio_rustls::rustls::crypto::{ring, verify_tls12_signature, verify_tls13_signature, CryptoProvider};
+use tokio_rustls::rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+use tokio_rustls::rustls::{ClientConfig, DigitallySignedStruct, Error, RootCertStore, SignatureScheme};
+use tokio_rustls::TlsConnector;
+use crate::configuration::Configuration;
+
+/// Starts a TLS session over a connected stream, offering the given application protocols.
+pub(super) async fn connect(stream: TcpStream, configuration: &Configuration, protocols: &[&[u8]]) -> io::Result<TlsStream<TcpStream>> {
+    let provider = Arc::new(ring::default_provider());
+    let builder = ClientConfig::builder_with_provider(provider.clone())
+        .with_safe_default_protocol_versions()
+        .map_err(io::Error::other)?;
+
+    let mut config = if configuration.tls_skip_verify {
+        builder.dangerous()
+            .with_custom_certificate_verifier(Arc::new(SkipVerification(provider)))
+            .with_no_client_auth()
+    } else {
+        let roots = RootCertStore { roots: webpki_roots::TLS_SERVER_ROOTS.to_vec() };
+        builder.with_root_certificates(roots)
+            .with_no_client_auth()
+    };
+
+    config.alpn_protocols = protocols.iter().map(|protocol| protocol.to_vec()).collect();
+
+    let server_name = ServerName::try_from(String::from(configuration.tls_server_name.clone()))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+    debug!("starting TLS session with {}", server_name.to_str());
+
+    TlsConnector::from(Arc::new(config))
+        .connect(server_name, stream)
+        .await
+}
+
+/// Accepts any server certificate, while still checking the handshake signatures.
+#[derive(Debug)]
+struct SkipVerification(Arc<CryptoProvider>);
+
+impl ServerCertVerifier for SkipVerification {
+
+    fn verify_server_cert(&self, _: &CertificateDer<'_>, _: &[CertificateDer<'_>], _: &ServerName<'_>, _: &[u8], _: UnixTime) -> Result<ServerCertVerified, Error> {
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(&self, message: &[u8], certificate: &CertificateDer<'_>, signature: &DigitallySignedStruct) -> Result<HandshakeSignatureValid, Error> {
+        verify_tls12_signature(message, certificate, signature, &self.0.signature_verification_algorithms)
+    }
+
+    fn verify_tls13_signature(&self, message: &[u8], certificate: &CertificateDer<'_>, signature: &DigitallySignedStruct) -> Result<HandshakeSignatureValid, Error> {
+        verify_tls13_signature(message, certificate, signature, &self.0.signature_verification_algorithms)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.0.signature_verification_algorithms.supported_schemes()
+    }
+}
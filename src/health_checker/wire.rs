@@ -0,0 +1,156 @@
+use std::fmt::Display;
+use std::future::Future;
+use log::{error, info};
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader};
+use crate::configuration::Configuration;
+use crate::health_checker::Reason::Timeout;
+use crate::health_checker::{NetworkError, State};
+
+#[cfg(test)]
+#[path = "./wire_test.rs"]
+mod test;
+
+/// Runs a check within the configured timeout and logs its outcome, the check returning the unhealthy states and
+/// the network errors ending it early as errors.
+pub(super) async fn run_with_timeout(configuration: &Configuration, check: impl Future<Output = Result<State, Result<State, NetworkError>>>) -> Result<State, NetworkError> {
+    let timeout: std::time::Duration = configuration.timeout.into();
+
+    let result = match tokio::time::timeout(timeout, check).await {
+        Err(_) => Ok(State::Unhealthy(Timeout(timeout))),
+        Ok(Ok(state)) => Ok(state),
+        Ok(Err(result)) => result,
+    };
+
+    match &result {
+        Ok(state) => info!("state {}", state),
+        Err(failure) => error!("{}", failure.message),
+    }
+
+    result
+}
+
+/// Reports a response, frame or packet the protocol does not allow.
+pub(super) fn malformed(kind: &str) -> Result<State, NetworkError> {
+    Err(NetworkError {
+        message: format!("network error: malformed {}", kind),
+    })
+}
+
+pub(super) fn network_error(error: impl Display) -> Result<State, NetworkError> {
+    Err(NetworkError {
+        message: format!("network error: {}", error),
+    })
+}
+
+/// Reads the fields of a message, big-endian unless stated otherwise, reporting the message as malformed when it ends
+/// too early.
+pub(super) struct Reader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+    kind: &'static str,
+}
+
+impl<'a> Reader<'a> {
+
+    pub(super) fn new(bytes: &'a [u8], kind: &'static str) -> Self {
+        Reader { bytes, offset: 0, kind }
+    }
+
+    pub(super) fn malformed(&self) -> Result<State, NetworkError> {
+        malformed(self.kind)
+    }
+
+    pub(super) fn bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    pub(super) fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub(super) fn seek(&mut self, offset: usize) {
+        self.offset = offset;
+    }
+
+    pub(super) fn remaining(&self) -> &'a [u8] {
+        self.bytes.get(self.offset..).unwrap_or_default()
+    }
+
+    pub(super) fn is_empty(&self) -> bool {
+        self.remaining().is_empty()
+    }
+
+    pub(super) fn take(&mut self, length: usize) -> Result<&'a [u8], Result<State, NetworkError>> {
+        let end = self.offset.checked_add(length).ok_or_else(|| self.malformed())?;
+        let taken = self.bytes.get(self.offset..end).ok_or_else(|| self.malformed())?;
+        self.offset = end;
+        Ok(taken)
+    }
+
+    pub(super) fn u8(&mut self) -> Result<u8, Result<State, NetworkError>> {
+        Ok(self.take(1)?[0])
+    }
+
+    pub(super) fn u16(&mut self) -> Result<u16, Result<State, NetworkError>> {
+        Ok(u16::from_be_bytes(self.array()?))
+    }
+
+    pub(super) fn u32(&mut self) -> Result<u32, Result<State, NetworkError>> {
+        Ok(u32::from_be_bytes(self.array()?))
+    }
+
+    pub(super) fn i16(&mut self) -> Result<i16, Result<State, NetworkError>> {
+        Ok(i16::from_be_bytes(self.array()?))
+    }
+
+    pub(super) fn i32(&mut self) -> Result<i32, Result<State, NetworkError>> {
+        Ok(i32::from_be_bytes(self.array()?))
+    }
+
+    pub(super) fn i32_le(&mut self) -> Result<i32, Result<State, NetworkError>> {
+        Ok(i32::from_le_bytes(self.array()?))
+    }
+
+    pub(super) fn array<const N: usize>(&mut self) -> Result<[u8; N], Result<State, NetworkError>> {
+        let bytes = self.take(N)?;
+        bytes.try_into().map_err(|_| self.malformed())
+    }
+
+    /// Reads a string of the given length in bytes, replacing what is not UTF-8.
+    pub(super) fn string(&mut self, length: usize) -> Result<String, Result<State, NetworkError>> {
+        Ok(String::from_utf8_lossy(self.take(length)?).into_owned())
+    }
+}
+
+/// Exchanges the lines of a text protocol, each terminated by CRLF.
+pub(super) struct LineConnection<S> {
+    stream: BufReader<S>,
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin> LineConnection<S> {
+
+    pub(super) fn new(stream: S) -> Self {
+        LineConnection { stream: BufReader::new(stream) }
+    }
+
+    pub(super) async fn send(&mut self, line: &str) -> Result<(), Result<State, NetworkError>> {
+        self.stream.get_mut().write_all(format!("{}\r\n", line).as_bytes()).await
+            .map_err(network_error)
+    }
+
+    /// Reads a line without its terminator, reporting the server closing the connection as a network error.
+    pub(super) async fn read_line(&mut self) -> Result<String, Result<State, NetworkError>> {
+        let mut line = String::new();
+
+        match self.stream.read_line(&mut line).await {
+            Ok(0) => Err(Err(NetworkError { message: String::from("network error: connection closed by the server") })),
+            Ok(_) => Ok(line.trim_end_matches(['\r', '\n']).to_string()),
+            Err(e) => Err(network_error(e)),
+        }
+    }
+
+    /// Returns the stream, for example to start TLS over it once no data is left to read.
+    pub(super) fn into_inner(self) -> S {
+        self.stream.into_inner()
+    }
+}
@@ -0,0 +1,105 @@
+use crate::health_checker::Reason::{Other, Timeout};
+use crate::health_checker::State::{Healthy, Unhealthy};
+use crate::health_checker::wire::{network_error, run_with_timeout, LineConnection, Reader};
+use crate::health_checker::State;
+use assert2::{check, assert};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use crate::configuration::fixtures::a_configuration_with_timeout;
+
+#[test]
+fn fields_should_be_read_in_order() {
+    let bytes = [0x01, 0x00, 0x02, 0xFF, 0xFE, 0x03, 0x00, 0x00, 0x00, b'o', b'k'];
+    let mut reader = Reader::new(&bytes, "response");
+
+    check!(reader.u8() == Ok(1));
+    check!(reader.u16() == Ok(2));
+    check!(reader.i16() == Ok(-2));
+    check!(reader.i32_le() == Ok(3));
+    check!(reader.string(2) == Ok(String::from("ok")));
+    check!(reader.is_empty());
+}
+
+#[test]
+fn reading_past_the_end_should_be_reported_as_malformed() {
+    let bytes = [0x00, 0x01];
+    let mut reader = Reader::new(&bytes, "frame");
+
+    let result = reader.u32();
+
+    assert!(let Err(Err(error)) = result);
+    check!(error.message == "network error: malformed frame");
+    check!(reader.offset() == 0);
+}
+
+#[test]
+fn an_overflowing_length_should_be_reported_as_malformed() {
+    let bytes = [0x00];
+    let mut reader = Reader::new(&bytes, "packet");
+    reader.u8().unwrap();
+
+    let result = reader.take(usize::MAX);
+
+    assert!(let Err(Err(error)) = result);
+    check!(error.message == "network error: malformed packet");
+}
+
+#[tokio::test]
+async fn a_completed_check_should_report_its_state() {
+    let configuration = a_configuration_with_timeout(80, 1000);
+
+    let result = run_with_timeout(&configuration, async { Ok(Healthy) }).await;
+
+    check!(result == Ok(Healthy));
+}
+
+#[tokio::test]
+async fn a_check_ended_early_should_report_its_outcome() {
+    let configuration = a_configuration_with_timeout(80, 1000);
+
+    let unhealthy = run_with_timeout(&configuration, async { Err(Ok(Unhealthy(Other(String::from("refused"))))) }).await;
+    let failed = run_with_timeout(&configuration, async { Err(network_error("connection reset")) }).await;
+
+    check!(unhealthy == Ok(Unhealthy(Other(String::from("refused")))));
+    assert!(let Err(error) = failed);
+    check!(error.message == "network error: connection reset");
+}
+
+#[tokio::test]
+async fn a_check_exceeding_the_timeout_should_be_unhealthy() {
+    let configuration = a_configuration_with_timeout(80, 1);
+
+    let result = run_with_timeout(&configuration, async {
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        Ok::<State, Result<State, _>>(Healthy)
+    }).await;
+
+    check!(result == Ok(Unhealthy(Timeout(Duration::from_millis(1)))));
+}
+
+#[tokio::test]
+async fn lines_should_be_exchanged_without_their_terminator() {
+    let (client, mut server) = tokio::io::duplex(64);
+    let mut connection = LineConnection::new(client);
+    server.write_all(b"+OK ready\r\n").await.unwrap();
+
+    connection.send("PING").await.unwrap();
+    let line = connection.read_line().await;
+
+    let mut sent = [0u8; 6];
+    server.read_exact(&mut sent).await.unwrap();
+    check!(&sent == b"PING\r\n");
+    check!(line == Ok(String::from("+OK ready")));
+}
+
+#[tokio::test]
+async fn a_connection_closed_by_the_server_should_be_reported_as_error() {
+    let (client, server) = tokio::io::duplex(64);
+    let mut connection = LineConnection::new(client);
+    drop(server);
+
+    let result = connection.read_line().await;
+
+    assert!(let Err(Err(error)) = result);
+    check!(error.message == "network error: connection closed by the server");
+}
@@ -31,9 +31,49 @@ impl Display for InvalidConfiguration {
         match self {
             InvalidConfiguration::Protocol(value) => write!(f, "invalid protocol '{value}'"),
             InvalidConfiguration::Port(value) => write!(f, "invalid port '{value}'"),
+            InvalidConfiguration::PortWithSocketPath(value) => write!(f, "port '{value}' cannot be used together with a socket path"),
+            InvalidConfiguration::SocketPath(value) => write!(f, "invalid socket path '{value}'"),
+            InvalidConfiguration::UnsupportedSocketPath(value) => write!(f, "socket path '{value}' is not supported by the protocol"),
             InvalidConfiguration::Timeout(value) => write!(f, "invalid timeout '{value}'"),
+            InvalidConfiguration::UsernameFile(value) => write!(f, "unreadable username file '{value}'"),
+            InvalidConfiguration::PasswordFile(value) => write!(f, "unreadable password file '{value}'"),
+            InvalidConfiguration::TokenFile(value) => write!(f, "unreadable token file '{value}'"),
+            InvalidConfiguration::Tls(value) => write!(f, "invalid tls flag '{value}'"),
+            InvalidConfiguration::TlsServerName(value) => write!(f, "invalid tls server name '{value}'"),
+            InvalidConfiguration::TlsSkipVerify(value) => write!(f, "invalid tls verification skip flag '{value}'"),
             InvalidConfiguration::StatusCode(value) => write!(f, "invalid status code '{value}'"),
             InvalidConfiguration::Method(value) =>  write!(f, "invalid method '{value}'"),
+            InvalidConfiguration::RedisReply(value) => write!(f, "invalid redis reply '{value}'"),
+            InvalidConfiguration::RedisCheck(value) => write!(f, "invalid redis check '{value}'"),
+            InvalidConfiguration::RedisDatabase(value) => write!(f, "invalid redis database '{value}'"),
+            InvalidConfiguration::UnsupportedRedisDatabase(value) => write!(f, "redis database '{value}' is not supported by the protocol"),
+            InvalidConfiguration::RedisProtocol(value) => write!(f, "invalid redis protocol '{value}'"),
+            InvalidConfiguration::RedisClusterCheckSlots(value) => write!(f, "invalid redis cluster slots check flag '{value}'"),
+            InvalidConfiguration::RedisClusterCheckNode(value) => write!(f, "invalid redis cluster node check flag '{value}'"),
+            InvalidConfiguration::UnsupportedSqlQuery(value) => write!(f, "sql query '{value}' is not supported by the protocol"),
+            InvalidConfiguration::SqlExpectation(value) => write!(f, "invalid sql expectation '{value}'"),
+            InvalidConfiguration::SqlExpectationWithoutQuery(value) => write!(f, "sql expectation '{value}' requires a sql query"),
+            InvalidConfiguration::MongodbState(value) => write!(f, "invalid mongodb state '{value}'"),
+            InvalidConfiguration::MemcachedCheck(value) => write!(f, "invalid memcached check '{value}'"),
+            InvalidConfiguration::MemcachedKeyPrefix(value) => write!(f, "invalid memcached key prefix '{value}'"),
+            InvalidConfiguration::AmqpVhost(value) => write!(f, "invalid amqp vhost '{value}'"),
+            InvalidConfiguration::KafkaCheckMetadata(value) => write!(f, "invalid kafka metadata check flag '{value}'"),
+            InvalidConfiguration::KafkaBrokerId(value) => write!(f, "invalid kafka broker id '{value}'"),
+            InvalidConfiguration::KafkaMetadataWithoutBrokerId(value) => write!(f, "kafka metadata check '{value}' requires a kafka broker id"),
+            InvalidConfiguration::KafkaTopics(value) => write!(f, "invalid kafka topics '{value}'"),
+            InvalidConfiguration::MqttVersion(value) => write!(f, "invalid mqtt version '{value}'"),
+            InvalidConfiguration::MqttCheckDelivery(value) => write!(f, "invalid mqtt delivery check flag '{value}'"),
+            InvalidConfiguration::ElasticsearchMinimumStatus(value) => write!(f, "invalid elasticsearch minimum status '{value}'"),
+            InvalidConfiguration::ElasticsearchWaitForStatus(value) => write!(f, "invalid elasticsearch status to wait for '{value}'"),
+            InvalidConfiguration::SmtpStarttls(value) => write!(f, "invalid smtp starttls flag '{value}'"),
+            InvalidConfiguration::FtpLogin(value) => write!(f, "invalid ftp login flag '{value}'"),
+            InvalidConfiguration::DnsName(value) => write!(f, "invalid dns name '{value}'"),
+            InvalidConfiguration::DnsRecordType(value) => write!(f, "invalid dns record type '{value}'"),
+            InvalidConfiguration::DnsTransport(value) => write!(f, "invalid dns transport '{value}'"),
+            InvalidConfiguration::LdapBindWithoutPassword(value) => write!(f, "ldap bind dn '{value}' requires a password"),
+            InvalidConfiguration::CqlQuery(value) => write!(f, "invalid cql query flag '{value}'"),
+            InvalidConfiguration::ClickhouseInterface(value) => write!(f, "invalid clickhouse interface '{value}'"),
+            InvalidConfiguration::ClickhouseQuery(value) => write!(f, "invalid clickhouse query flag '{value}'"),
         }
     }
 }
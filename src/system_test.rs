@@ -66,3 +66,363 @@ fn invalid_protocol_message() {
 
     assert_eq!("invalid protocol 'ftp'", result)
 }
+
+#[test]
+fn invalid_redis_reply_message() {
+    let err = InvalidConfiguration::RedisReply(String::from("integer:one"));
+
+    let result = format!("{err}");
+
+    assert_eq!("invalid redis reply 'integer:one'", result)
+}
+
+#[test]
+fn invalid_redis_check_message() {
+    let err = InvalidConfiguration::RedisCheck(String::from("roundtrip"));
+
+    let result = format!("{err}");
+
+    assert_eq!("invalid redis check 'roundtrip'", result)
+}
+
+#[test]
+fn invalid_redis_database_message() {
+    let err = InvalidConfiguration::RedisDatabase(String::from("-1"));
+
+    let result = format!("{err}");
+
+    assert_eq!("invalid redis database '-1'", result)
+}
+
+#[test]
+fn unsupported_redis_database_message() {
+    let err = InvalidConfiguration::UnsupportedRedisDatabase(String::from("2"));
+
+    let result = format!("{err}");
+
+    assert_eq!("redis database '2' is not supported by the protocol", result)
+}
+
+#[test]
+fn invalid_redis_protocol_message() {
+    let err = InvalidConfiguration::RedisProtocol(String::from("resp4"));
+
+    let result = format!("{err}");
+
+    assert_eq!("invalid redis protocol 'resp4'", result)
+}
+
+#[test]
+fn port_with_socket_path_message() {
+    let err = InvalidConfiguration::PortWithSocketPath(String::from("6379"));
+
+    let result = format!("{err}");
+
+    assert_eq!("port '6379' cannot be used together with a socket path", result)
+}
+
+#[test]
+fn invalid_socket_path_message() {
+    let err = InvalidConfiguration::SocketPath(String::from("redis.sock"));
+
+    let result = format!("{err}");
+
+    assert_eq!("invalid socket path 'redis.sock'", result)
+}
+
+#[test]
+fn unsupported_socket_path_message() {
+    let err = InvalidConfiguration::UnsupportedSocketPath(String::from("/run/http.sock"));
+
+    let result = format!("{err}");
+
+    assert_eq!("socket path '/run/http.sock' is not supported by the protocol", result)
+}
+
+#[test]
+fn invalid_redis_cluster_slots_check_message() {
+    let err = InvalidConfiguration::RedisClusterCheckSlots(String::from("yes"));
+
+    let result = format!("{err}");
+
+    assert_eq!("invalid redis cluster slots check flag 'yes'", result)
+}
+
+#[test]
+fn invalid_redis_cluster_node_check_message() {
+    let err = InvalidConfiguration::RedisClusterCheckNode(String::from("1"));
+
+    let result = format!("{err}");
+
+    assert_eq!("invalid redis cluster node check flag '1'", result)
+}
+
+#[test]
+fn unreadable_password_file_message() {
+    let err = InvalidConfiguration::PasswordFile(String::from("/run/secrets/password"));
+
+    let result = format!("{err}");
+
+    assert_eq!("unreadable password file '/run/secrets/password'", result)
+}
+
+#[test]
+fn unreadable_username_file_message() {
+    let err = InvalidConfiguration::UsernameFile(String::from("/run/secrets/username"));
+
+    let result = format!("{err}");
+
+    assert_eq!("unreadable username file '/run/secrets/username'", result)
+}
+
+#[test]
+fn invalid_sql_expectation_message() {
+    let err = InvalidConfiguration::SqlExpectation(String::from("< ten"));
+
+    let result = format!("{err}");
+
+    assert_eq!("invalid sql expectation '< ten'", result)
+}
+
+#[test]
+fn unsupported_sql_query_message() {
+    let err = InvalidConfiguration::UnsupportedSqlQuery(String::from("SELECT 1"));
+
+    let result = format!("{err}");
+
+    assert_eq!("sql query 'SELECT 1' is not supported by the protocol", result)
+}
+
+#[test]
+fn sql_expectation_without_query_message() {
+    let err = InvalidConfiguration::SqlExpectationWithoutQuery(String::from("> 0"));
+
+    let result = format!("{err}");
+
+    assert_eq!("sql expectation '> 0' requires a sql query", result)
+}
+
+#[test]
+fn invalid_mongodb_state_message() {
+    let err = InvalidConfiguration::MongodbState(String::from("arbiter"));
+
+    let result = format!("{err}");
+
+    assert_eq!("invalid mongodb state 'arbiter'", result)
+}
+
+#[test]
+fn invalid_memcached_check_message() {
+    let err = InvalidConfiguration::MemcachedCheck(String::from("stats"));
+
+    let result = format!("{err}");
+
+    assert_eq!("invalid memcached check 'stats'", result)
+}
+
+#[test]
+fn invalid_memcached_key_prefix_message() {
+    let err = InvalidConfiguration::MemcachedKeyPrefix(String::from("health check:"));
+
+    let result = format!("{err}");
+
+    assert_eq!("invalid memcached key prefix 'health check:'", result)
+}
+
+#[test]
+fn invalid_amqp_vhost_message() {
+    let err = InvalidConfiguration::AmqpVhost(String::from("orders"));
+
+    let result = format!("{err}");
+
+    assert_eq!("invalid amqp vhost 'orders'", result)
+}
+
+#[test]
+fn invalid_kafka_metadata_check_message() {
+    let err = InvalidConfiguration::KafkaCheckMetadata(String::from("yes"));
+
+    let result = format!("{err}");
+
+    assert_eq!("invalid kafka metadata check flag 'yes'", result)
+}
+
+#[test]
+fn invalid_kafka_broker_id_message() {
+    let err = InvalidConfiguration::KafkaBrokerId(String::from("-1"));
+
+    let result = format!("{err}");
+
+    assert_eq!("invalid kafka broker id '-1'", result)
+}
+
+#[test]
+fn kafka_metadata_without_broker_id_message() {
+    let err = InvalidConfiguration::KafkaMetadataWithoutBrokerId(String::from("true"));
+
+    let result = format!("{err}");
+
+    assert_eq!("kafka metadata check 'true' requires a kafka broker id", result)
+}
+
+#[test]
+fn invalid_kafka_topics_message() {
+    let err = InvalidConfiguration::KafkaTopics(String::from("pay ments"));
+
+    let result = format!("{err}");
+
+    assert_eq!("invalid kafka topics 'pay ments'", result)
+}
+
+#[test]
+fn unreadable_token_file_message() {
+    let err = InvalidConfiguration::TokenFile(String::from("/run/secrets/token"));
+
+    let result = format!("{err}");
+
+    assert_eq!("unreadable token file '/run/secrets/token'", result)
+}
+
+#[test]
+fn invalid_mqtt_version_message() {
+    let err = InvalidConfiguration::MqttVersion(String::from("3.1"));
+
+    let result = format!("{err}");
+
+    assert_eq!("invalid mqtt version '3.1'", result)
+}
+
+#[test]
+fn invalid_mqtt_delivery_check_message() {
+    let err = InvalidConfiguration::MqttCheckDelivery(String::from("1"));
+
+    let result = format!("{err}");
+
+    assert_eq!("invalid mqtt delivery check flag '1'", result)
+}
+
+#[test]
+fn invalid_tls_message() {
+    let err = InvalidConfiguration::Tls(String::from("yes"));
+
+    let result = format!("{err}");
+
+    assert_eq!("invalid tls flag 'yes'", result)
+}
+
+#[test]
+fn invalid_tls_server_name_message() {
+    let err = InvalidConfiguration::TlsServerName(String::from("not a host"));
+
+    let result = format!("{err}");
+
+    assert_eq!("invalid tls server name 'not a host'", result)
+}
+
+#[test]
+fn invalid_tls_skip_verify_message() {
+    let err = InvalidConfiguration::TlsSkipVerify(String::from("no"));
+
+    let result = format!("{err}");
+
+    assert_eq!("invalid tls verification skip flag 'no'", result)
+}
+
+#[test]
+fn invalid_elasticsearch_minimum_status_message() {
+    let err = InvalidConfiguration::ElasticsearchMinimumStatus(String::from("red"));
+
+    let result = format!("{err}");
+
+    assert_eq!("invalid elasticsearch minimum status 'red'", result)
+}
+
+#[test]
+fn invalid_elasticsearch_wait_for_status_message() {
+    let err = InvalidConfiguration::ElasticsearchWaitForStatus(String::from("blue"));
+
+    let result = format!("{err}");
+
+    assert_eq!("invalid elasticsearch status to wait for 'blue'", result)
+}
+
+#[test]
+fn invalid_smtp_starttls_message() {
+    let err = InvalidConfiguration::SmtpStarttls(String::from("on"));
+
+    let result = format!("{err}");
+
+    assert_eq!("invalid smtp starttls flag 'on'", result)
+}
+
+#[test]
+fn invalid_ftp_login_message() {
+    let err = InvalidConfiguration::FtpLogin(String::from("yes"));
+
+    let result = format!("{err}");
+
+    assert_eq!("invalid ftp login flag 'yes'", result)
+}
+
+#[test]
+fn invalid_dns_name_message() {
+    let err = InvalidConfiguration::DnsName(String::from("db..internal"));
+
+    let result = format!("{err}");
+
+    assert_eq!("invalid dns name 'db..internal'", result)
+}
+
+#[test]
+fn invalid_dns_record_type_message() {
+    let err = InvalidConfiguration::DnsRecordType(String::from("ANY"));
+
+    let result = format!("{err}");
+
+    assert_eq!("invalid dns record type 'ANY'", result)
+}
+
+#[test]
+fn invalid_dns_transport_message() {
+    let err = InvalidConfiguration::DnsTransport(String::from("quic"));
+
+    let result = format!("{err}");
+
+    assert_eq!("invalid dns transport 'quic'", result)
+}
+
+#[test]
+fn ldap_bind_without_password_message() {
+    let err = InvalidConfiguration::LdapBindWithoutPassword(String::from("cn=admin,dc=example,dc=org"));
+
+    let result = format!("{err}");
+
+    assert_eq!("ldap bind dn 'cn=admin,dc=example,dc=org' requires a password", result)
+}
+
+#[test]
+fn invalid_cql_query_message() {
+    let err = InvalidConfiguration::CqlQuery(String::from("yes"));
+
+    let result = format!("{err}");
+
+    assert_eq!("invalid cql query flag 'yes'", result)
+}
+
+#[test]
+fn invalid_clickhouse_interface_message() {
+    let err = InvalidConfiguration::ClickhouseInterface(String::from("grpc"));
+
+    let result = format!("{err}");
+
+    assert_eq!("invalid clickhouse interface 'grpc'", result)
+}
+
+#[test]
+fn invalid_clickhouse_query_message() {
+    let err = InvalidConfiguration::ClickhouseQuery(String::from("yes"));
+
+    let result = format!("{err}");
+
+    assert_eq!("invalid clickhouse query flag 'yes'", result)
+}